@@ -4,15 +4,16 @@
 
 extern crate alloc;
 
-use contract::{
-    contract_api::{
-        runtime,
-        storage::{self, create_contract_package_at_hash},
-    },
-    unwrap_or_revert::UnwrapOrRevert,
+use contract::contract_api::runtime;
+use libs::{
+    access::AccessControl,
+    introspection::Introspection,
+    upgrade::{install, installed_package_hash, upgrade},
+    utils::get_optional_named_arg,
 };
-use libs::access::AccessControl;
-use types::{contracts::NamedKeys, EntryPoints, Key, U256};
+use types::{contracts::NamedKeys, EntryPoints, Key};
+
+const CONTRACT_NAME: &str = "AccessControl";
 
 /// # Purpose
 /// * Returns the `has_role` property.
@@ -38,6 +39,56 @@ pub extern "C" fn grant_role() {
     AccessControl::grant_role();
 }
 
+/// # Purpose
+/// * Grant role to given addresses
+/// # Arguments
+/// * `role` - `U256` -> Role.
+/// * `accounts` - `Vec<Key>` -> Addresses of the accounts.
+#[no_mangle]
+pub extern "C" fn grant_roles() {
+    AccessControl::grant_roles();
+}
+
+/// # Purpose
+/// * Revoke role from given addresses
+/// # Arguments
+/// * `role` - `U256` -> Role.
+/// * `accounts` - `Vec<Key>` -> Addresses of the accounts.
+#[no_mangle]
+pub extern "C" fn revoke_roles() {
+    AccessControl::revoke_roles();
+}
+
+/// # Purpose
+/// * Grant roles to addresses
+/// # Arguments
+/// * `grants` - `Vec<(U256, Key)>` -> Roles and addresses of the accounts.
+#[no_mangle]
+pub extern "C" fn grant_role_batch() {
+    AccessControl::grant_role_batch();
+}
+
+/// # Purpose
+/// * Returns the time at which a role grant expires, `0` if it does not.
+/// # Arguments
+/// * `role` - `U256` -> Role.
+/// * `account` - `Key` -> Address of the account.
+#[no_mangle]
+pub extern "C" fn get_role_expiration() {
+    AccessControl::ret_role_expiration()
+}
+
+/// # Purpose
+/// * Grant role to given address until the given time
+/// # Arguments
+/// * `role` - `U256` -> Role.
+/// * `account` - `Key` -> Address of the account.
+/// * `expires_at` - `u64` -> Time at which the role expires, in milliseconds.
+#[no_mangle]
+pub extern "C" fn grant_role_until() {
+    AccessControl::grant_role_until();
+}
+
 /// # Purpose
 /// * Revoke role
 /// # Arguments
@@ -58,6 +109,22 @@ pub extern "C" fn renounce_role() {
     AccessControl::renounce_role();
 }
 
+/// # Purpose
+/// * Returns the name of every role defined by the contract.
+#[no_mangle]
+pub extern "C" fn get_role_names() {
+    AccessControl::ret_role_names()
+}
+
+/// # Purpose
+/// * Returns the name a role was defined with.
+/// # Arguments
+/// * `role` - `U256` -> Role.
+#[no_mangle]
+pub extern "C" fn get_role_name() {
+    AccessControl::ret_role_name()
+}
+
 /// # Purpose
 /// * Set admin role
 /// # Arguments
@@ -65,56 +132,35 @@ pub extern "C" fn renounce_role() {
 /// * `admin_role` - `U256` -> Admin role.
 #[no_mangle]
 pub extern "C" fn set_role_admin() {
-    let role: U256 = runtime::get_named_arg("role");
-    let admin_role: U256 = runtime::get_named_arg("admin_role");
+    AccessControl::set_role_admin();
+}
 
-    AccessControl::check_only_role(role);
-    AccessControl::_set_role_admin(role, admin_role);
+/// # Purpose
+/// * Returns whether the contract supports the given interface.
+/// # Arguments
+/// * `interface_id` - `u32` -> Id of the interface.
+#[no_mangle]
+pub extern "C" fn supports_interface() {
+    Introspection::ret_supports_interface()
 }
 
 #[no_mangle]
 pub extern "C" fn call() {
-    let default_admin: Key = runtime::get_named_arg("default_admin");
-
     let mut entry_points = EntryPoints::new();
 
     AccessControl::set_entry_points(&mut entry_points);
+    Introspection::set_entry_points(&mut entry_points);
+
+    if installed_package_hash(CONTRACT_NAME).is_some() {
+        upgrade(CONTRACT_NAME, entry_points, NamedKeys::new(), true);
+        return;
+    }
+
+    let default_admin: Key = runtime::get_named_arg("default_admin");
+
+    let role_names: Vec<String> = get_optional_named_arg("role_names").unwrap_or_default();
+
+    let named_keys = AccessControl::install_named_keys(default_admin, role_names);
 
-    let role_admin_seed_uref =
-        storage::new_dictionary(AccessControl::ACCESS_ROLE_ADMIN_KEY).unwrap_or_revert();
-    let role_members_seed_uref =
-        storage::new_dictionary(AccessControl::ACCESS_ROLE_MEMBER_KEY).unwrap_or_revert();
-
-    let mut named_keys = NamedKeys::new();
-
-    let (contract_package_hash, access_uref) = create_contract_package_at_hash();
-    named_keys.insert(
-        AccessControl::ACCESS_ROLE_ADMIN_KEY.to_string(),
-        role_admin_seed_uref.into(),
-    );
-    named_keys.insert(
-        AccessControl::ACCESS_ROLE_MEMBER_KEY.to_string(),
-        role_members_seed_uref.into(),
-    );
-    named_keys.insert(
-        "contract_package_hash".to_string(),
-        storage::new_uref(contract_package_hash).into(),
-    );
-
-    storage::dictionary_put(
-        role_members_seed_uref,
-        &AccessControl::get_role_members_key(AccessControl::DEFAULT_ADMIN_ROLE, default_admin),
-        true,
-    );
-
-    // Add new version to the package.
-    let (contract_hash, _) =
-        storage::add_contract_version(contract_package_hash, entry_points, named_keys);
-    runtime::put_key(&"AccessControl", contract_hash.into());
-    runtime::put_key(
-        &"AccessControl_hash",
-        storage::new_uref(contract_hash).into(),
-    );
-    runtime::put_key(&"AccessControl_package_hash", contract_package_hash.into());
-    runtime::put_key(&"AccessControl_access_token", access_uref.into());
+    install(CONTRACT_NAME, entry_points, named_keys);
 }
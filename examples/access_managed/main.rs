@@ -0,0 +1,70 @@
+#![allow(unused_parens)]
+#![allow(non_snake_case)]
+#![no_main]
+
+extern crate alloc;
+
+use contract::contract_api::{
+    runtime,
+    storage::{self},
+};
+use libs::{
+    access::AccessManaged,
+    upgrade::{install, installed_package_hash, upgrade},
+    utils::{endpoint, get_key, set_key},
+};
+use types::{contracts::NamedKeys, CLType, EntryPoints, Key};
+
+const CONTRACT_NAME: &str = "AccessManaged";
+const COUNTER_KEY: &str = "counter";
+
+/// # Purpose
+/// * Returns the address of the access manager.
+#[no_mangle]
+pub extern "C" fn authority() {
+    AccessManaged::ret_authority()
+}
+
+/// # Purpose
+/// * Increments the counter. Restricted by the access manager.
+#[no_mangle]
+pub extern "C" fn increment() {
+    AccessManaged::check_can_call("increment");
+
+    let counter: u64 = get_key(COUNTER_KEY);
+    set_key(COUNTER_KEY, counter + 1);
+}
+
+/// # Purpose
+/// * Resets the counter. Restricted by the access manager.
+#[no_mangle]
+pub extern "C" fn reset() {
+    AccessManaged::check_can_call("reset");
+
+    set_key(COUNTER_KEY, 0u64);
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+
+    AccessManaged::set_entry_points(&mut entry_points);
+    entry_points.add_entry_point(endpoint("increment", vec![], CLType::Unit));
+    entry_points.add_entry_point(endpoint("reset", vec![], CLType::Unit));
+
+    if installed_package_hash(CONTRACT_NAME).is_some() {
+        upgrade(CONTRACT_NAME, entry_points, NamedKeys::new(), true);
+        return;
+    }
+
+    let authority: Key = runtime::get_named_arg("authority");
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        AccessManaged::AUTHORITY_KEY.to_string(),
+        storage::new_uref(authority).into(),
+    );
+    named_keys.insert(COUNTER_KEY.to_string(), storage::new_uref(0u64).into());
+
+    install(CONTRACT_NAME, entry_points, named_keys);
+}
@@ -0,0 +1,239 @@
+#![allow(unused_parens)]
+#![allow(non_snake_case)]
+#![no_main]
+
+extern crate alloc;
+
+use contract::contract_api::runtime;
+use libs::{
+    access::{AccessControl, AccessManager},
+    upgrade::{install, installed_package_hash, upgrade},
+    utils::get_optional_named_arg,
+};
+use types::{contracts::NamedKeys, EntryPoints, Key};
+
+const CONTRACT_NAME: &str = "AccessManager";
+
+/// # Purpose
+/// * Returns the `has_role` property.
+#[no_mangle]
+pub extern "C" fn has_role() {
+    AccessControl::ret_has_role()
+}
+
+/// # Purpose
+/// * Returns the `get_role_admin` property.
+#[no_mangle]
+pub extern "C" fn get_role_admin() {
+    AccessControl::ret_role_admin()
+}
+
+/// # Purpose
+/// * Grant role to given address
+/// # Arguments
+/// * `role` - `U256` -> Role.
+/// * `account` - `Key` -> Address of the account.
+#[no_mangle]
+pub extern "C" fn grant_role() {
+    AccessControl::grant_role();
+}
+
+/// # Purpose
+/// * Grant role to given addresses
+/// # Arguments
+/// * `role` - `U256` -> Role.
+/// * `accounts` - `Vec<Key>` -> Addresses of the accounts.
+#[no_mangle]
+pub extern "C" fn grant_roles() {
+    AccessControl::grant_roles();
+}
+
+/// # Purpose
+/// * Revoke role from given addresses
+/// # Arguments
+/// * `role` - `U256` -> Role.
+/// * `accounts` - `Vec<Key>` -> Addresses of the accounts.
+#[no_mangle]
+pub extern "C" fn revoke_roles() {
+    AccessControl::revoke_roles();
+}
+
+/// # Purpose
+/// * Grant roles to addresses
+/// # Arguments
+/// * `grants` - `Vec<(U256, Key)>` -> Roles and addresses of the accounts.
+#[no_mangle]
+pub extern "C" fn grant_role_batch() {
+    AccessControl::grant_role_batch();
+}
+
+/// # Purpose
+/// * Returns the time at which a role grant expires, `0` if it does not.
+/// # Arguments
+/// * `role` - `U256` -> Role.
+/// * `account` - `Key` -> Address of the account.
+#[no_mangle]
+pub extern "C" fn get_role_expiration() {
+    AccessControl::ret_role_expiration()
+}
+
+/// # Purpose
+/// * Grant role to given address until the given time
+/// # Arguments
+/// * `role` - `U256` -> Role.
+/// * `account` - `Key` -> Address of the account.
+/// * `expires_at` - `u64` -> Time at which the role expires, in milliseconds.
+#[no_mangle]
+pub extern "C" fn grant_role_until() {
+    AccessControl::grant_role_until();
+}
+
+/// # Purpose
+/// * Revoke role
+/// # Arguments
+/// * `role` - `U256` -> Role.
+/// * `account` - `Key` -> Address of the account.
+#[no_mangle]
+pub extern "C" fn revoke_role() {
+    AccessControl::revoke_role();
+}
+
+/// # Purpose
+/// * Renounce role
+/// # Arguments
+/// * `role` - `U256` -> Role.
+/// * `account` - `Key` -> Address of the account.
+#[no_mangle]
+pub extern "C" fn renounce_role() {
+    AccessControl::renounce_role();
+}
+
+/// # Purpose
+/// * Returns the name of every role defined by the contract.
+#[no_mangle]
+pub extern "C" fn get_role_names() {
+    AccessControl::ret_role_names()
+}
+
+/// # Purpose
+/// * Returns the name a role was defined with.
+/// # Arguments
+/// * `role` - `U256` -> Role.
+#[no_mangle]
+pub extern "C" fn get_role_name() {
+    AccessControl::ret_role_name()
+}
+
+/// # Purpose
+/// * Set admin role
+/// # Arguments
+/// * `role` - `U256` -> Role.
+/// * `admin_role` - `U256` -> Admin role.
+#[no_mangle]
+pub extern "C" fn set_role_admin() {
+    AccessControl::set_role_admin();
+}
+
+/// # Purpose
+/// * Returns the role required to call an entry point of a target contract.
+/// # Arguments
+/// * `target` - `Key` -> Package hash of the target contract.
+/// * `selector` - `String` -> Name of the entry point.
+#[no_mangle]
+pub extern "C" fn get_target_function_role() {
+    AccessManager::ret_target_function_role()
+}
+
+/// # Purpose
+/// * Sets the role required to call an entry point of a target contract.
+/// # Arguments
+/// * `target` - `Key` -> Package hash of the target contract.
+/// * `selector` - `String` -> Name of the entry point.
+/// * `role` - `U256` -> Required role.
+#[no_mangle]
+pub extern "C" fn set_target_function_role() {
+    AccessManager::set_target_function_role();
+}
+
+/// # Purpose
+/// * Returns the execution delay of a role.
+/// # Arguments
+/// * `role` - `U256` -> Role.
+#[no_mangle]
+pub extern "C" fn get_role_execution_delay() {
+    AccessManager::ret_role_execution_delay()
+}
+
+/// # Purpose
+/// * Sets the execution delay of a role.
+/// # Arguments
+/// * `role` - `U256` -> Role.
+/// * `delay` - `u64` -> Delay between scheduling a call and making it, in milliseconds.
+#[no_mangle]
+pub extern "C" fn set_role_execution_delay() {
+    AccessManager::set_role_execution_delay();
+}
+
+/// # Purpose
+/// * Returns whether a caller can call an entry point right away, and the delay it has to
+/// schedule the call with otherwise.
+/// # Arguments
+/// * `caller` - `Key` -> Address of the caller.
+/// * `target` - `Key` -> Package hash of the target contract.
+/// * `selector` - `String` -> Name of the entry point.
+#[no_mangle]
+pub extern "C" fn can_call() {
+    AccessManager::ret_can_call()
+}
+
+/// # Purpose
+/// * Returns the time from which a scheduled call can be made.
+/// # Arguments
+/// * `caller` - `Key` -> Address of the caller.
+/// * `target` - `Key` -> Package hash of the target contract.
+/// * `selector` - `String` -> Name of the entry point.
+#[no_mangle]
+pub extern "C" fn get_schedule() {
+    AccessManager::ret_schedule()
+}
+
+/// # Purpose
+/// * Schedules a call of the caller to an entry point of a target contract.
+/// # Arguments
+/// * `target` - `Key` -> Package hash of the target contract.
+/// * `selector` - `String` -> Name of the entry point.
+#[no_mangle]
+pub extern "C" fn schedule() {
+    AccessManager::schedule();
+}
+
+/// # Purpose
+/// * Consumes a call scheduled on the calling contract.
+/// # Arguments
+/// * `caller` - `Key` -> Address of the caller.
+/// * `selector` - `String` -> Name of the entry point.
+#[no_mangle]
+pub extern "C" fn consume_scheduled_op() {
+    AccessManager::consume_scheduled_op();
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+
+    AccessControl::set_entry_points(&mut entry_points);
+    AccessManager::set_entry_points(&mut entry_points);
+
+    if installed_package_hash(CONTRACT_NAME).is_some() {
+        upgrade(CONTRACT_NAME, entry_points, NamedKeys::new(), true);
+        return;
+    }
+
+    let default_admin: Key = runtime::get_named_arg("default_admin");
+
+    let role_names: Vec<String> = get_optional_named_arg("role_names").unwrap_or_default();
+
+    let named_keys = AccessControl::install_named_keys(default_admin, role_names);
+
+    install(CONTRACT_NAME, entry_points, named_keys);
+}
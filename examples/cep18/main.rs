@@ -0,0 +1,193 @@
+#![allow(unused_parens)]
+#![allow(non_snake_case)]
+#![no_main]
+
+extern crate alloc;
+
+use contract::contract_api::runtime;
+use libs::{
+    introspection::Introspection,
+    token::erc20::{CEP18, ERC20},
+    upgrade::{install, installed_package_hash, upgrade},
+    utils::get_optional_named_arg,
+};
+use types::{contracts::NamedKeys, EntryPoints, Key, U256};
+
+const CONTRACT_NAME: &str = "Cep18";
+
+/// # Purpose
+/// * Returns the `name` property.
+#[no_mangle]
+pub extern "C" fn name() {
+    ERC20::ret_name()
+}
+
+/// # Purpose
+/// * Returns the `symbol` property.
+#[no_mangle]
+pub extern "C" fn symbol() {
+    ERC20::ret_symbol()
+}
+
+/// # Purpose
+/// * Returns the `decimals` property.
+#[no_mangle]
+pub extern "C" fn decimals() {
+    ERC20::ret_decimals()
+}
+
+/// # Purpose
+/// * Returns the `total_supply` of the token.
+#[no_mangle]
+pub extern "C" fn total_supply() {
+    ERC20::ret_total_supply()
+}
+
+/// # Purpose
+/// * Returns how much tokens the given `address` owns.
+/// # Arguments
+/// * `address` - `Key` -> Address that we are looking for it's token balance.
+/// # Returns
+/// * `balance` - `U256` -> The given `address`'s balance.
+#[no_mangle]
+pub extern "C" fn balance_of() {
+    CEP18::ret_balance_of()
+}
+
+/// # Purpose
+/// * Returns how much allowance the `owner` has given to the `spender`.
+/// # Arguments
+/// * `owner` - `Key` -> Address of the owner.
+/// * `spender` - `Key` -> Address of the spender.
+/// # Returns
+/// * `amount` - `U256` -> Amount of the allowance.
+#[no_mangle]
+pub extern "C" fn allowance() {
+    ERC20::ret_allowance()
+}
+
+/// # Purpose
+/// * Grants an address the liberty to spend an amount of the caller's tokens.
+/// # Arguments
+/// * `spender` - `Key` -> Address of the spender.
+/// * `amount` - `U256` -> Amount of the allowance.
+#[no_mangle]
+pub extern "C" fn approve() {
+    CEP18::approve();
+}
+
+/// # Purpose
+/// * Increase allowance from current allowance
+/// # Arguments
+/// * `spender` - `Key` -> Address of the spender.
+/// * `amount` - `U256` -> Amount of the allowance.
+#[no_mangle]
+pub extern "C" fn increase_allowance() {
+    CEP18::increase_allowance();
+}
+
+/// # Purpose
+/// * Decrease allowance from current allowance, down to zero.
+/// # Arguments
+/// * `spender` - `Key` -> Address of the spender.
+/// * `amount` - `U256` -> Amount of the allowance.
+#[no_mangle]
+pub extern "C" fn decrease_allowance() {
+    CEP18::decrease_allowance();
+}
+
+/// # Purpose
+/// * Transfers an amount of the caller's tokens to the given address.
+/// # Arguments
+/// * `recipient` - `Key` -> Address of the recipient.
+/// * `amount` - `U256` -> Amount of the tokens to be sent.
+#[no_mangle]
+pub extern "C" fn transfer() {
+    CEP18::transfer();
+}
+
+/// # Purpose
+/// * Transfers an `amount` of tokens from `owner` to `recipient`.
+/// # Arguments
+/// * `owner` - `Key` -> Address of the owner.
+/// * `recipient` - `Key` -> Address of the recipient.
+/// * `amount` - `U256` -> Amount of the tokens to be sent.
+#[no_mangle]
+pub extern "C" fn transfer_from() {
+    CEP18::transfer_from();
+}
+
+/// # Purpose
+/// * Mints tokens to the given address. Only callable by admins and minters.
+/// # Arguments
+/// * `owner` - `Key` -> Address of the recipient.
+/// * `amount` - `U256` -> Amount of the tokens to be minted.
+#[no_mangle]
+pub extern "C" fn mint() {
+    CEP18::mint();
+}
+
+/// # Purpose
+/// * Burns tokens of the caller.
+/// # Arguments
+/// * `owner` - `Key` -> Address of the caller.
+/// * `amount` - `U256` -> Amount of the tokens to be burned.
+#[no_mangle]
+pub extern "C" fn burn() {
+    CEP18::burn();
+}
+
+/// # Purpose
+/// * Changes the security badges of accounts. Only callable by admins.
+/// # Arguments
+/// * `admin_list` - `Vec<Key>` -> Addresses becoming admins.
+/// * `minter_list` - `Vec<Key>` -> Addresses becoming minters.
+/// * `none_list` - `Vec<Key>` -> Addresses losing their badge.
+#[no_mangle]
+pub extern "C" fn change_security() {
+    CEP18::change_security();
+}
+
+/// # Purpose
+/// * Returns whether the contract supports the given interface.
+/// # Arguments
+/// * `interface_id` - `u32` -> Id of the interface.
+#[no_mangle]
+pub extern "C" fn supports_interface() {
+    Introspection::ret_supports_interface()
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+
+    CEP18::set_entry_points(&mut entry_points);
+    Introspection::set_entry_points(&mut entry_points);
+
+    if installed_package_hash(CONTRACT_NAME).is_some() {
+        upgrade(CONTRACT_NAME, entry_points, NamedKeys::new(), true);
+        return;
+    }
+
+    let token_name: String = runtime::get_named_arg("name");
+    let token_symbol: String = runtime::get_named_arg("symbol");
+    let token_decimals: u8 = runtime::get_named_arg("decimals");
+    let token_total_supply: U256 = runtime::get_named_arg("total_supply");
+    // Minting and burning are disabled unless asked for, as in CEP-18.
+    let enable_mint_burn: u8 = get_optional_named_arg("enable_mint_burn").unwrap_or_default();
+    let admin_list: Vec<Key> = get_optional_named_arg("admin_list").unwrap_or_default();
+    let minter_list: Vec<Key> = get_optional_named_arg("minter_list").unwrap_or_default();
+
+    let named_keys = CEP18::install_named_keys(
+        token_name,
+        token_symbol,
+        token_decimals,
+        token_total_supply,
+        enable_mint_burn == 1,
+        Key::Account(runtime::get_caller()),
+        admin_list,
+        minter_list,
+    );
+
+    install(CONTRACT_NAME, entry_points, named_keys);
+}
@@ -0,0 +1,212 @@
+#![allow(unused_parens)]
+#![allow(non_snake_case)]
+#![no_main]
+
+extern crate alloc;
+
+use contract::contract_api::{
+    runtime,
+    storage::{self},
+};
+use libs::{
+    access::{AccessControl, AccessControlDefaultAdminRules},
+    upgrade::{install, installed_package_hash, upgrade},
+    utils::get_optional_named_arg,
+};
+use types::{contracts::NamedKeys, EntryPoints, Key};
+
+const CONTRACT_NAME: &str = "DefaultAdminRules";
+
+/// # Purpose
+/// * Returns the `has_role` property.
+#[no_mangle]
+pub extern "C" fn has_role() {
+    AccessControl::ret_has_role()
+}
+
+/// # Purpose
+/// * Returns the `get_role_admin` property.
+#[no_mangle]
+pub extern "C" fn get_role_admin() {
+    AccessControl::ret_role_admin()
+}
+
+/// # Purpose
+/// * Grant role to given address
+/// # Arguments
+/// * `role` - `U256` -> Role.
+/// * `account` - `Key` -> Address of the account.
+#[no_mangle]
+pub extern "C" fn grant_role() {
+    AccessControlDefaultAdminRules::grant_role();
+}
+
+/// # Purpose
+/// * Grant role to given addresses
+/// # Arguments
+/// * `role` - `U256` -> Role.
+/// * `accounts` - `Vec<Key>` -> Addresses of the accounts.
+#[no_mangle]
+pub extern "C" fn grant_roles() {
+    AccessControlDefaultAdminRules::grant_roles();
+}
+
+/// # Purpose
+/// * Revoke role from given addresses
+/// # Arguments
+/// * `role` - `U256` -> Role.
+/// * `accounts` - `Vec<Key>` -> Addresses of the accounts.
+#[no_mangle]
+pub extern "C" fn revoke_roles() {
+    AccessControlDefaultAdminRules::revoke_roles();
+}
+
+/// # Purpose
+/// * Grant roles to addresses
+/// # Arguments
+/// * `grants` - `Vec<(U256, Key)>` -> Roles and addresses of the accounts.
+#[no_mangle]
+pub extern "C" fn grant_role_batch() {
+    AccessControlDefaultAdminRules::grant_role_batch();
+}
+
+/// # Purpose
+/// * Returns the time at which a role grant expires, `0` if it does not.
+/// # Arguments
+/// * `role` - `U256` -> Role.
+/// * `account` - `Key` -> Address of the account.
+#[no_mangle]
+pub extern "C" fn get_role_expiration() {
+    AccessControl::ret_role_expiration()
+}
+
+/// # Purpose
+/// * Grant role to given address until the given time
+/// # Arguments
+/// * `role` - `U256` -> Role.
+/// * `account` - `Key` -> Address of the account.
+/// * `expires_at` - `u64` -> Time at which the role expires, in milliseconds.
+#[no_mangle]
+pub extern "C" fn grant_role_until() {
+    AccessControlDefaultAdminRules::grant_role_until();
+}
+
+/// # Purpose
+/// * Revoke role
+/// # Arguments
+/// * `role` - `U256` -> Role.
+/// * `account` - `Key` -> Address of the account.
+#[no_mangle]
+pub extern "C" fn revoke_role() {
+    AccessControlDefaultAdminRules::revoke_role();
+}
+
+/// # Purpose
+/// * Renounce role
+/// # Arguments
+/// * `role` - `U256` -> Role.
+/// * `account` - `Key` -> Address of the account.
+#[no_mangle]
+pub extern "C" fn renounce_role() {
+    AccessControlDefaultAdminRules::renounce_role();
+}
+
+/// # Purpose
+/// * Returns the name of every role defined by the contract.
+#[no_mangle]
+pub extern "C" fn get_role_names() {
+    AccessControl::ret_role_names()
+}
+
+/// # Purpose
+/// * Returns the name a role was defined with.
+/// # Arguments
+/// * `role` - `U256` -> Role.
+#[no_mangle]
+pub extern "C" fn get_role_name() {
+    AccessControl::ret_role_name()
+}
+
+/// # Purpose
+/// * Set admin role
+/// # Arguments
+/// * `role` - `U256` -> Role.
+/// * `admin_role` - `U256` -> Admin role.
+#[no_mangle]
+pub extern "C" fn set_role_admin() {
+    AccessControlDefaultAdminRules::set_role_admin();
+}
+
+/// # Purpose
+/// * Returns the single holder of the default admin role.
+#[no_mangle]
+pub extern "C" fn default_admin() {
+    AccessControlDefaultAdminRules::ret_default_admin()
+}
+
+/// # Purpose
+/// * Returns the pending default admin and the time from which it can accept the transfer.
+#[no_mangle]
+pub extern "C" fn pending_default_admin() {
+    AccessControlDefaultAdminRules::ret_pending_default_admin()
+}
+
+/// # Purpose
+/// * Returns the delay between the start of a default admin transfer and its acceptance.
+#[no_mangle]
+pub extern "C" fn default_admin_delay() {
+    AccessControlDefaultAdminRules::ret_default_admin_delay()
+}
+
+/// # Purpose
+/// * Starts transferring the default admin role. Only callable by the default admin.
+/// # Arguments
+/// * `new_admin` - `Key` -> Address of the new default admin.
+#[no_mangle]
+pub extern "C" fn begin_default_admin_transfer() {
+    AccessControlDefaultAdminRules::begin_default_admin_transfer();
+}
+
+/// # Purpose
+/// * Accepts the pending default admin transfer. Only callable by the pending default admin.
+#[no_mangle]
+pub extern "C" fn accept_default_admin_transfer() {
+    AccessControlDefaultAdminRules::accept_default_admin_transfer();
+}
+
+/// # Purpose
+/// * Cancels the pending default admin transfer. Only callable by the default admin.
+#[no_mangle]
+pub extern "C" fn cancel_default_admin_transfer() {
+    AccessControlDefaultAdminRules::cancel_default_admin_transfer();
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+
+    AccessControl::set_entry_points(&mut entry_points);
+    AccessControlDefaultAdminRules::set_entry_points(&mut entry_points);
+
+    if installed_package_hash(CONTRACT_NAME).is_some() {
+        upgrade(CONTRACT_NAME, entry_points, NamedKeys::new(), true);
+        return;
+    }
+
+    let default_admin: Key = runtime::get_named_arg("default_admin");
+    let default_admin_delay: u64 = runtime::get_named_arg("default_admin_delay");
+
+    let role_names: Vec<String> = get_optional_named_arg("role_names").unwrap_or_default();
+
+    let mut named_keys = AccessControl::install_named_keys(default_admin, role_names);
+    named_keys.insert(
+        AccessControlDefaultAdminRules::DEFAULT_ADMIN_KEY.to_string(),
+        storage::new_uref(default_admin).into(),
+    );
+    named_keys.insert(
+        AccessControlDefaultAdminRules::DEFAULT_ADMIN_DELAY_KEY.to_string(),
+        storage::new_uref(default_admin_delay).into(),
+    );
+
+    install(CONTRACT_NAME, entry_points, named_keys);
+}
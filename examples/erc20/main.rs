@@ -7,12 +7,20 @@ extern crate alloc;
 use contract::{
     contract_api::{
         runtime,
-        storage::{self, create_contract_package_at_hash},
+        storage::{self},
     },
     unwrap_or_revert::UnwrapOrRevert,
 };
-use libs::{token::erc20::ERC20, utils::CONTRACT_PACKAGE_HASH_KEY};
-use types::{contracts::NamedKeys, EntryPoints, Key, U256};
+use libs::{
+    access::Ownable,
+    introspection::Introspection,
+    token::erc20::ERC20,
+    upgrade::{install, installed_package_hash, upgrade},
+    utils::{get_optional_named_arg, CallerIdentity, CALLER_IDENTITY_KEY, TRUSTED_FORWARDERS_KEY},
+};
+use types::{contracts::NamedKeys, ApiError, EntryPoints, Key, U256};
+
+const CONTRACT_NAME: &str = "Erc20";
 
 /// # Purpose
 /// * Returns the `name` property.
@@ -75,6 +83,17 @@ pub extern "C" fn approve() {
     ERC20::approve();
 }
 
+/// # Purpose
+/// * Replaces the allowance of an address, provided it did not change in the meantime.
+/// # Arguments
+/// * `spender` - `Key` -> Address of the spender.
+/// * `current_amount` - `U256` -> Expected amount of the current allowance.
+/// * `new_amount` - `U256` -> Amount of the new allowance.
+#[no_mangle]
+pub extern "C" fn approve_from() {
+    ERC20::approve_from();
+}
+
 /// # Purpose
 /// * Transfers an amount of the caller's tokens to the given address.
 /// # Arguments
@@ -116,17 +135,76 @@ pub extern "C" fn transfer_from() {
     ERC20::transfer_from();
 }
 
+/// # Purpose
+/// * Transfers amounts of the caller's tokens to several addresses.
+/// # Arguments
+/// * `recipients` - `Vec<Key>` -> Addresses of the recipients.
+/// * `amounts` - `Vec<U256>` -> Amount of the tokens to be sent to each recipient.
+#[no_mangle]
+pub extern "C" fn transfer_batch() {
+    ERC20::transfer_batch();
+}
+
+/// # Purpose
+/// * Mints amounts of tokens to several addresses. Only callable by the owner.
+/// # Arguments
+/// * `recipients` - `Vec<Key>` -> Addresses of the recipients.
+/// * `amounts` - `Vec<U256>` -> Amount of the tokens to be minted to each recipient.
+#[no_mangle]
+pub extern "C" fn mint_batch() {
+    ERC20::mint_batch();
+}
+
+/// # Purpose
+/// * Returns the `owner` property.
+#[no_mangle]
+pub extern "C" fn owner() {
+    Ownable::ret_owner()
+}
+
+/// # Purpose
+/// * Transfers the ownership of the token, and with it the right to mint, to the given address.
+/// # Arguments
+/// * `new_owner` - `Key` -> Address of the new owner.
+#[no_mangle]
+pub extern "C" fn transfer_ownership() {
+    Ownable::transfer_ownership();
+}
+
+/// # Purpose
+/// * Renounce ownership of the token, after which no more tokens can be minted.
+#[no_mangle]
+pub extern "C" fn renounce_ownership() {
+    Ownable::renounce_ownership();
+}
+
+/// # Purpose
+/// * Returns whether the contract supports the given interface.
+/// # Arguments
+/// * `interface_id` - `u32` -> Id of the interface.
+#[no_mangle]
+pub extern "C" fn supports_interface() {
+    Introspection::ret_supports_interface()
+}
+
 #[no_mangle]
 pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+
+    ERC20::set_entry_points(&mut entry_points);
+    Ownable::set_entry_points(&mut entry_points);
+    Introspection::set_entry_points(&mut entry_points);
+
+    if installed_package_hash(CONTRACT_NAME).is_some() {
+        upgrade(CONTRACT_NAME, entry_points, NamedKeys::new(), true);
+        return;
+    }
+
     let token_name: String = runtime::get_named_arg("name");
     let token_symbol: String = runtime::get_named_arg("symbol");
     let token_decimals: u8 = runtime::get_named_arg("decimals");
     let token_total_supply: U256 = runtime::get_named_arg("total_supply");
 
-    let mut entry_points = EntryPoints::new();
-
-    ERC20::set_entry_points(&mut entry_points);
-
     let balances_seed_uref = storage::new_dictionary(ERC20::ERC20_BALANCE_KEY).unwrap_or_revert();
 
     let allowances_seed_uref =
@@ -157,20 +235,28 @@ pub extern "C" fn call() {
         ERC20::ERC20_ALLOWANCE_KEY.to_string(),
         allowances_seed_uref.into(),
     );
+    // The deployer owns the token and is the only one able to mint more.
+    named_keys.insert(
+        "owner".to_string(),
+        storage::new_uref(Key::Account(runtime::get_caller())).into(),
+    );
 
-    let (contract_package_hash, access_uref) = create_contract_package_at_hash();
+    // Entry points authorize the immediate caller unless told otherwise.
+    let caller_identity: u8 =
+        get_optional_named_arg("caller_identity").unwrap_or(CallerIdentity::ImmediateCaller as u8);
+    CallerIdentity::from_u8(caller_identity).unwrap_or_revert_with(ApiError::InvalidArgument);
+    named_keys.insert(
+        CALLER_IDENTITY_KEY.to_string(),
+        storage::new_uref(caller_identity).into(),
+    );
+    let trusted_forwarders: Vec<Key> =
+        get_optional_named_arg("trusted_forwarders").unwrap_or_default();
     named_keys.insert(
-        CONTRACT_PACKAGE_HASH_KEY.to_string(),
-        storage::new_uref(contract_package_hash).into(),
+        TRUSTED_FORWARDERS_KEY.to_string(),
+        storage::new_uref(trusted_forwarders).into(),
     );
 
-    // Add new version to the package.
-    let (contract_hash, _) =
-        storage::add_contract_version(contract_package_hash, entry_points, named_keys);
-    runtime::put_key(&"Erc20", contract_hash.into());
-    runtime::put_key(&"Erc20_hash", storage::new_uref(contract_hash).into());
-    runtime::put_key(&"Erc20_package_hash", contract_package_hash.into());
-    runtime::put_key(&"Erc20_access_token", access_uref.into());
+    install(CONTRACT_NAME, entry_points, named_keys);
 
     ERC20::_mint(Key::Account(runtime::get_caller()), token_total_supply);
 }
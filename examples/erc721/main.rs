@@ -0,0 +1,166 @@
+#![allow(unused_parens)]
+#![allow(non_snake_case)]
+#![no_main]
+
+extern crate alloc;
+
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use libs::{
+    introspection::Introspection,
+    token::erc721::{
+        erc721::{ERC721Config, NFTIdentifierMode},
+        error::Erc721Error,
+        ERC721,
+    },
+    upgrade::{install, installed_package_hash, upgrade},
+};
+use types::{contracts::NamedKeys, EntryPoints, Key};
+
+const CONTRACT_NAME: &str = "Erc721";
+
+/// # Purpose
+/// * Returns how many tokens the given `token_owner` owns.
+/// # Arguments
+/// * `token_owner` - `Key` -> Address of the owner.
+/// # Returns
+/// * `balance` - `u64` -> Number of tokens owned.
+#[no_mangle]
+pub extern "C" fn balance_of() {
+    ERC721::ret_balance_of()
+}
+
+/// # Purpose
+/// * Returns the owner of a token.
+/// # Arguments
+/// * `token_id` - `u64` or `token_hash` - `String` -> Identifier of the token.
+/// # Returns
+/// * `owner` - `Key` -> Address of the owner.
+#[no_mangle]
+pub extern "C" fn owner_of() {
+    ERC721::ret_owner_of()
+}
+
+/// # Purpose
+/// * Returns the address approved to transfer a token, if any.
+/// # Arguments
+/// * `token_id` - `u64` or `token_hash` - `String` -> Identifier of the token.
+/// # Returns
+/// * `operator` - `Option<Key>` -> Approved address.
+#[no_mangle]
+pub extern "C" fn get_approved() {
+    ERC721::ret_get_approved()
+}
+
+/// # Purpose
+/// * Returns whether `operator` can transfer every token of `token_owner`.
+/// # Arguments
+/// * `token_owner` - `Key` -> Address of the owner.
+/// * `operator` - `Key` -> Address of the operator.
+#[no_mangle]
+pub extern "C" fn is_approved_for_all() {
+    ERC721::ret_is_approved_for_all()
+}
+
+/// # Purpose
+/// * Returns the metadata of a token.
+/// # Arguments
+/// * `token_id` - `u64` or `token_hash` - `String` -> Identifier of the token.
+/// # Returns
+/// * `metadata` - `String` -> Metadata of the token.
+#[no_mangle]
+pub extern "C" fn metadata() {
+    ERC721::ret_metadata()
+}
+
+/// # Purpose
+/// * Mints a token.
+/// # Arguments
+/// * `token_owner` - `Key` -> Address of the owner.
+/// * `token_meta_data` - `String` -> Metadata of the token.
+#[no_mangle]
+pub extern "C" fn mint() {
+    ERC721::mint();
+}
+
+/// # Purpose
+/// * Burns a token of the caller.
+/// # Arguments
+/// * `token_id` - `u64` or `token_hash` - `String` -> Identifier of the token.
+#[no_mangle]
+pub extern "C" fn burn() {
+    ERC721::burn();
+}
+
+/// # Purpose
+/// * Transfers a token.
+/// # Arguments
+/// * `token_id` - `u64` or `token_hash` - `String` -> Identifier of the token.
+/// * `source_key` - `Key` -> Address of the owner.
+/// * `target_key` - `Key` -> Address of the recipient.
+#[no_mangle]
+pub extern "C" fn transfer() {
+    ERC721::transfer();
+}
+
+/// # Purpose
+/// * Approves an address to transfer a token.
+/// # Arguments
+/// * `token_id` - `u64` or `token_hash` - `String` -> Identifier of the token.
+/// * `operator` - `Key` -> Address to approve.
+#[no_mangle]
+pub extern "C" fn approve() {
+    ERC721::approve();
+}
+
+/// # Purpose
+/// * Approves or revokes an operator of every token of the caller.
+/// # Arguments
+/// * `approve_all` - `bool` -> Whether the operator is approved.
+/// * `operator` - `Key` -> Address of the operator.
+#[no_mangle]
+pub extern "C" fn set_approval_for_all() {
+    ERC721::set_approval_for_all();
+}
+
+/// # Purpose
+/// * Replaces the metadata of a token of the caller.
+/// # Arguments
+/// * `token_id` - `u64` or `token_hash` - `String` -> Identifier of the token.
+/// * `token_meta_data` - `String` -> New metadata of the token.
+#[no_mangle]
+pub extern "C" fn set_token_metadata() {
+    ERC721::set_token_metadata();
+}
+
+/// # Purpose
+/// * Returns whether the contract supports the given interface.
+/// # Arguments
+/// * `interface_id` - `u32` -> Id of the interface.
+#[no_mangle]
+pub extern "C" fn supports_interface() {
+    Introspection::ret_supports_interface()
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+
+    // Entry points taking a token declare its identifier, so upgrades must be given the
+    // `identifier_mode` the collection was installed with.
+    let identifier_mode = NFTIdentifierMode::from_u8(runtime::get_named_arg("identifier_mode"))
+        .unwrap_or_revert_with(Erc721Error::InvalidConfiguration);
+    ERC721::set_entry_points(&mut entry_points, identifier_mode);
+    Introspection::set_entry_points(&mut entry_points);
+
+    if installed_package_hash(CONTRACT_NAME).is_some() {
+        upgrade(CONTRACT_NAME, entry_points, NamedKeys::new(), true);
+        return;
+    }
+
+    let named_keys = ERC721::install_named_keys(
+        ERC721Config::from_named_args(),
+        Key::Account(runtime::get_caller()),
+    );
+
+    install(CONTRACT_NAME, entry_points, named_keys);
+}
@@ -0,0 +1,113 @@
+#![allow(unused_parens)]
+#![allow(non_snake_case)]
+#![no_main]
+
+extern crate alloc;
+
+use contract::{
+    contract_api::{
+        runtime,
+        storage::{self},
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use libs::{
+    access::Ownable,
+    introspection::Introspection,
+    upgrade::{install, installed_package_hash, upgrade},
+    utils::{endpoint, get_optional_named_arg, set_key},
+};
+use types::{
+    contracts::NamedKeys, runtime_args, CLType, ContractHash, EntryPoints, Key, Parameter,
+    RuntimeArgs,
+};
+
+const CONTRACT_NAME: &str = "InterfaceChecker";
+const SUPPORTED_KEY: &str = "supported";
+
+/// # Purpose
+/// * Calls the `supports_interface` entry point of the target contract and records the result
+///   under the `supported` named key.
+/// # Arguments
+/// * `target` - `Key` -> Hash of the contract to query.
+/// * `interface_id` - `u32` -> Id of the interface.
+#[no_mangle]
+pub extern "C" fn check_interface() {
+    let target: Key = runtime::get_named_arg("target");
+    let interface_id: u32 = runtime::get_named_arg("interface_id");
+
+    let target = ContractHash::new(target.into_hash().unwrap_or_revert());
+    let supported: bool = runtime::call_contract(
+        target,
+        "supports_interface",
+        runtime_args! {
+            "interface_id" => interface_id
+        },
+    );
+    set_key(SUPPORTED_KEY, supported);
+}
+
+/// # Purpose
+/// * Returns the `owner` property.
+#[no_mangle]
+pub extern "C" fn owner() {
+    Ownable::ret_owner()
+}
+
+/// # Purpose
+/// * Transfers the ownership of the contract to the given address.
+/// # Arguments
+/// * `new_owner` - `Key` -> Address of the new owner.
+#[no_mangle]
+pub extern "C" fn transfer_ownership() {
+    Ownable::transfer_ownership();
+}
+
+/// # Purpose
+/// * Renounce ownership of contract.
+#[no_mangle]
+pub extern "C" fn renounce_ownership() {
+    Ownable::renounce_ownership();
+}
+
+/// # Purpose
+/// * Returns whether the contract supports the given interface.
+/// # Arguments
+/// * `interface_id` - `u32` -> Id of the interface.
+#[no_mangle]
+pub extern "C" fn supports_interface() {
+    Introspection::ret_supports_interface()
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(endpoint(
+        "check_interface",
+        vec![
+            Parameter::new("target", CLType::Key),
+            Parameter::new("interface_id", CLType::U32),
+        ],
+        CLType::Unit,
+    ));
+    Introspection::set_entry_points(&mut entry_points);
+
+    // The Ownable interface is dropped by deploying the contract with `ownable` set to false.
+    if get_optional_named_arg("ownable").unwrap_or(true) {
+        Ownable::set_entry_points(&mut entry_points);
+    }
+
+    if installed_package_hash(CONTRACT_NAME).is_some() {
+        upgrade(CONTRACT_NAME, entry_points, NamedKeys::new(), true);
+        return;
+    }
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        "owner".to_string(),
+        storage::new_uref(Key::Account(runtime::get_caller())).into(),
+    );
+    named_keys.insert(SUPPORTED_KEY.to_string(), storage::new_uref(false).into());
+
+    install(CONTRACT_NAME, entry_points, named_keys);
+}
@@ -0,0 +1,80 @@
+#![allow(unused_parens)]
+#![allow(non_snake_case)]
+#![no_main]
+
+extern crate alloc;
+
+use contract::contract_api::{
+    runtime,
+    storage::{self},
+};
+use libs::{
+    merkle::Hash,
+    token::merkle_airdrop::MerkleAirdrop,
+    upgrade::{install, installed_package_hash, upgrade},
+};
+use types::{contracts::NamedKeys, EntryPoints, Key};
+
+const CONTRACT_NAME: &str = "MerkleAirdrop";
+
+/// # Purpose
+/// * Returns the address of the airdropped ERC20 token.
+#[no_mangle]
+pub extern "C" fn token() {
+    MerkleAirdrop::ret_token()
+}
+
+/// # Purpose
+/// * Returns the Merkle root of the airdrop.
+#[no_mangle]
+pub extern "C" fn merkle_root() {
+    MerkleAirdrop::ret_merkle_root()
+}
+
+/// # Purpose
+/// * Returns whether the airdrop of the given index was already claimed.
+/// # Arguments
+/// * `index` - `u64` -> Index of the airdrop in the Merkle tree.
+#[no_mangle]
+pub extern "C" fn is_claimed() {
+    MerkleAirdrop::ret_is_claimed()
+}
+
+/// # Purpose
+/// * Transfers the airdropped tokens to the given address.
+/// # Arguments
+/// * `index` - `u64` -> Index of the airdrop in the Merkle tree.
+/// * `account` - `Key` -> Address of the recipient.
+/// * `amount` - `U256` -> Amount of the tokens airdropped to the recipient.
+/// * `proof` - `Vec<[u8; 32]>` -> Sibling hashes from the leaf up to the Merkle root.
+#[no_mangle]
+pub extern "C" fn claim() {
+    MerkleAirdrop::claim();
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+
+    MerkleAirdrop::set_entry_points(&mut entry_points);
+
+    if installed_package_hash(CONTRACT_NAME).is_some() {
+        upgrade(CONTRACT_NAME, entry_points, NamedKeys::new(), true);
+        return;
+    }
+
+    let token: Key = runtime::get_named_arg("token");
+    let merkle_root: Hash = runtime::get_named_arg("merkle_root");
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        MerkleAirdrop::TOKEN_KEY.to_string(),
+        storage::new_uref(token).into(),
+    );
+    named_keys.insert(
+        MerkleAirdrop::MERKLE_ROOT_KEY.to_string(),
+        storage::new_uref(merkle_root).into(),
+    );
+
+    install(CONTRACT_NAME, entry_points, named_keys);
+}
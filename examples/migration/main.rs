@@ -0,0 +1,113 @@
+#![allow(unused_parens)]
+#![allow(non_snake_case)]
+#![no_main]
+
+extern crate alloc;
+
+use contract::contract_api::{
+    runtime,
+    storage::{self},
+};
+use libs::{
+    access::Ownable,
+    migration::{Migrations, STORAGE_LAYOUT_VERSION_KEY},
+    upgrade::{install, installed_package_hash, upgrade},
+    utils::{endpoint, get_key, set_key},
+};
+use types::{contracts::NamedKeys, runtime_args, CLType, EntryPoints, Key, Parameter, RuntimeArgs};
+
+const CONTRACT_NAME: &str = "Migration";
+const COUNTER_KEY: &str = "counter";
+
+/// Storage layout `1`: the counter is doubled.
+fn double_counter() {
+    let counter: u64 = get_key(COUNTER_KEY);
+    set_key(COUNTER_KEY, counter * 2);
+}
+
+/// Storage layout `2`: the counter is offset by ten.
+fn offset_counter() {
+    let counter: u64 = get_key(COUNTER_KEY);
+    set_key(COUNTER_KEY, counter + 10);
+}
+
+fn migrations() -> Migrations {
+    Migrations::new()
+        .register(1, double_counter)
+        .register(2, offset_counter)
+}
+
+/// # Purpose
+/// * Migrates the storage layout to the latest version, only callable by the owner.
+#[no_mangle]
+pub extern "C" fn migrate() {
+    Ownable::check_only_owner();
+    migrations().run();
+}
+
+/// # Purpose
+/// * Migrates the storage layout to the given version, only callable by the owner.
+/// # Arguments
+/// * `target_version` - `u32` -> Storage layout version to migrate to.
+#[no_mangle]
+pub extern "C" fn migrate_to() {
+    Ownable::check_only_owner();
+    let target_version: u32 = runtime::get_named_arg("target_version");
+
+    migrations().run_to(target_version);
+}
+
+/// # Purpose
+/// * Returns the `owner` property.
+#[no_mangle]
+pub extern "C" fn owner() {
+    Ownable::ret_owner()
+}
+
+/// # Purpose
+/// * Transfers the ownership of the contract to the given address.
+/// # Arguments
+/// * `new_owner` - `Key` -> Address of the new owner.
+#[no_mangle]
+pub extern "C" fn transfer_ownership() {
+    Ownable::transfer_ownership();
+}
+
+/// # Purpose
+/// * Renounce ownership of contract.
+#[no_mangle]
+pub extern "C" fn renounce_ownership() {
+    Ownable::renounce_ownership();
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(endpoint("migrate", vec![], CLType::Unit));
+    entry_points.add_entry_point(endpoint(
+        "migrate_to",
+        vec![Parameter::new("target_version", CLType::U32)],
+        CLType::Unit,
+    ));
+    Ownable::set_entry_points(&mut entry_points);
+
+    if installed_package_hash(CONTRACT_NAME).is_some() {
+        let contract_hash = upgrade(CONTRACT_NAME, entry_points, NamedKeys::new(), true);
+        runtime::call_contract::<()>(contract_hash, "migrate", runtime_args! {});
+        return;
+    }
+
+    // The contract is installed with the storage layout of its first version.
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        "owner".to_string(),
+        storage::new_uref(Key::Account(runtime::get_caller())).into(),
+    );
+    named_keys.insert(COUNTER_KEY.to_string(), storage::new_uref(1u64).into());
+    named_keys.insert(
+        STORAGE_LAYOUT_VERSION_KEY.to_string(),
+        storage::new_uref(0u32).into(),
+    );
+
+    install(CONTRACT_NAME, entry_points, named_keys);
+}
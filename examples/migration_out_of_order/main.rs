@@ -0,0 +1,27 @@
+#![allow(unused_parens)]
+#![allow(non_snake_case)]
+#![no_main]
+
+extern crate alloc;
+
+use libs::{migration::Migrations, upgrade::install, utils::endpoint};
+use types::{contracts::NamedKeys, CLType, EntryPoints};
+
+const CONTRACT_NAME: &str = "MigrationOutOfOrder";
+
+fn noop() {}
+
+/// # Purpose
+/// * Registers the migration steps in the wrong order.
+#[no_mangle]
+pub extern "C" fn register_out_of_order() {
+    Migrations::new().register(2, noop).register(1, noop).run();
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(endpoint("register_out_of_order", vec![], CLType::Unit));
+
+    install(CONTRACT_NAME, entry_points, NamedKeys::new());
+}
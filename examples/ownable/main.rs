@@ -4,12 +4,22 @@
 
 extern crate alloc;
 
-use contract::contract_api::{
-    runtime,
-    storage::{self, create_contract_package_at_hash},
+use contract::{
+    contract_api::{
+        runtime,
+        storage::{self},
+    },
+    unwrap_or_revert::UnwrapOrRevert,
 };
-use libs::access::Ownable;
-use types::{contracts::NamedKeys, EntryPoints, Key};
+use libs::{
+    access::Ownable,
+    introspection::Introspection,
+    upgrade::{install, installed_package_hash, upgrade},
+    utils::{get_optional_named_arg, CallerIdentity, CALLER_IDENTITY_KEY, TRUSTED_FORWARDERS_KEY},
+};
+use types::{contracts::NamedKeys, ApiError, EntryPoints, Key};
+
+const CONTRACT_NAME: &str = "Ownable";
 
 /// # Purpose
 /// * Returns the `owner` property.
@@ -35,28 +45,46 @@ pub extern "C" fn renounce_ownership() {
     Ownable::renounce_ownership();
 }
 
+/// # Purpose
+/// * Returns whether the contract supports the given interface.
+/// # Arguments
+/// * `interface_id` - `u32` -> Id of the interface.
 #[no_mangle]
-pub extern "C" fn call() {
-    let owner: Key = runtime::get_named_arg("owner");
+pub extern "C" fn supports_interface() {
+    Introspection::ret_supports_interface()
+}
 
+#[no_mangle]
+pub extern "C" fn call() {
     let mut entry_points = EntryPoints::new();
 
     Ownable::set_entry_points(&mut entry_points);
+    Introspection::set_entry_points(&mut entry_points);
 
-    let mut named_keys = NamedKeys::new();
+    if installed_package_hash(CONTRACT_NAME).is_some() {
+        upgrade(CONTRACT_NAME, entry_points, NamedKeys::new(), true);
+        return;
+    }
+
+    let owner: Key = runtime::get_named_arg("owner");
 
-    let (contract_package_hash, access_uref) = create_contract_package_at_hash();
+    let mut named_keys = NamedKeys::new();
     named_keys.insert("owner".to_string(), storage::new_uref(owner.clone()).into());
+
+    // Entry points authorize the immediate caller unless told otherwise.
+    let caller_identity: u8 =
+        get_optional_named_arg("caller_identity").unwrap_or(CallerIdentity::ImmediateCaller as u8);
+    CallerIdentity::from_u8(caller_identity).unwrap_or_revert_with(ApiError::InvalidArgument);
+    named_keys.insert(
+        CALLER_IDENTITY_KEY.to_string(),
+        storage::new_uref(caller_identity).into(),
+    );
+    let trusted_forwarders: Vec<Key> =
+        get_optional_named_arg("trusted_forwarders").unwrap_or_default();
     named_keys.insert(
-        "contract_package_hash".to_string(),
-        storage::new_uref(contract_package_hash).into(),
+        TRUSTED_FORWARDERS_KEY.to_string(),
+        storage::new_uref(trusted_forwarders).into(),
     );
 
-    // Add new version to the package.
-    let (contract_hash, _) =
-        storage::add_contract_version(contract_package_hash, entry_points, named_keys);
-    runtime::put_key(&"Ownable", contract_hash.into());
-    runtime::put_key(&"Ownable_hash", storage::new_uref(contract_hash).into());
-    runtime::put_key(&"Ownable_package_hash", contract_package_hash.into());
-    runtime::put_key(&"Ownable_access_token", access_uref.into());
+    install(CONTRACT_NAME, entry_points, named_keys);
 }
@@ -0,0 +1,103 @@
+#![allow(unused_parens)]
+#![allow(non_snake_case)]
+#![no_main]
+
+extern crate alloc;
+
+use contract::contract_api::runtime;
+use libs::{
+    finance::PaymentSplitter,
+    upgrade::{install, installed_package_hash, upgrade},
+};
+use types::{contracts::NamedKeys, EntryPoints, Key, U256};
+
+const CONTRACT_NAME: &str = "PaymentSplitter";
+
+/// # Purpose
+/// * Returns the addresses sharing the payments.
+#[no_mangle]
+pub extern "C" fn payees() {
+    PaymentSplitter::ret_payees()
+}
+
+/// # Purpose
+/// * Returns the shares of the given address.
+/// # Arguments
+/// * `account` - `Key` -> Address of the payee.
+#[no_mangle]
+pub extern "C" fn shares() {
+    PaymentSplitter::ret_shares()
+}
+
+/// # Purpose
+/// * Returns the sum of the shares of every payee.
+#[no_mangle]
+pub extern "C" fn total_shares() {
+    PaymentSplitter::ret_total_shares()
+}
+
+/// # Purpose
+/// * Returns the amount of CSPR already released to the given address.
+/// # Arguments
+/// * `account` - `Key` -> Address of the payee.
+#[no_mangle]
+pub extern "C" fn released() {
+    PaymentSplitter::ret_released()
+}
+
+/// # Purpose
+/// * Returns the amount of CSPR already released to every payee.
+#[no_mangle]
+pub extern "C" fn total_released() {
+    PaymentSplitter::ret_total_released()
+}
+
+/// # Purpose
+/// * Returns the amount of an ERC20 token already released to the given address.
+/// # Arguments
+/// * `token` - `Key` -> Address of the ERC20 token.
+/// * `account` - `Key` -> Address of the payee.
+#[no_mangle]
+pub extern "C" fn token_released() {
+    PaymentSplitter::ret_token_released()
+}
+
+/// # Purpose
+/// * Transfers the CSPR the given account is due to it.
+/// # Arguments
+/// * `account` - `Key` -> Address of the payee.
+#[no_mangle]
+pub extern "C" fn release() {
+    PaymentSplitter::release();
+}
+
+/// # Purpose
+/// * Transfers the amount of an ERC20 token the given address is due to it.
+/// # Arguments
+/// * `token` - `Key` -> Address of the ERC20 token.
+/// * `account` - `Key` -> Address of the payee.
+#[no_mangle]
+pub extern "C" fn release_token() {
+    PaymentSplitter::release_token();
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+
+    PaymentSplitter::set_entry_points(&mut entry_points);
+
+    if installed_package_hash(CONTRACT_NAME).is_some() {
+        upgrade(CONTRACT_NAME, entry_points, NamedKeys::new(), true);
+        return;
+    }
+
+    let payees: Vec<Key> = runtime::get_named_arg("payees");
+    let shares: Vec<U256> = runtime::get_named_arg("shares");
+
+    install(
+        CONTRACT_NAME,
+        entry_points,
+        PaymentSplitter::install_named_keys(payees, shares),
+    );
+}
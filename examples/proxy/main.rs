@@ -0,0 +1,77 @@
+#![allow(unused_parens)]
+#![allow(non_snake_case)]
+#![no_main]
+
+extern crate alloc;
+
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use libs::{
+    upgrade::install,
+    utils::{endpoint, set_key},
+};
+use types::{
+    bytesrepr::{self, Bytes},
+    contracts::NamedKeys,
+    ApiError, CLType, ContractHash, EntryPoints, Key, Parameter, RuntimeArgs,
+};
+
+const RESULT_KEY: &str = "result";
+
+/// Returns the target contract, entry point and arguments of the call to forward.
+fn forwarded_call() -> (ContractHash, String, RuntimeArgs) {
+    let target: Key = runtime::get_named_arg("target");
+    let entry_point: String = runtime::get_named_arg("entry_point");
+    let args: Bytes = runtime::get_named_arg("args");
+
+    let target = ContractHash::new(target.into_hash().unwrap_or_revert());
+    let args: RuntimeArgs =
+        bytesrepr::deserialize(args.to_vec()).unwrap_or_revert_with(ApiError::InvalidArgument);
+    (target, entry_point, args)
+}
+
+fn forward_parameters() -> Vec<Parameter> {
+    vec![
+        Parameter::new("target", CLType::Key),
+        Parameter::new("entry_point", CLType::String),
+        Parameter::new("args", CLType::List(Box::new(CLType::U8))),
+    ]
+}
+
+/// # Purpose
+/// * Forwards a call to an entry point of another contract.
+/// # Arguments
+/// * `target` - `Key` -> Hash of the contract to call.
+/// * `entry_point` - `String` -> Entry point of the target contract to call.
+/// * `args` - `Bytes` -> Serialized `RuntimeArgs` of the call.
+#[no_mangle]
+pub extern "C" fn forward() {
+    let (target, entry_point, args) = forwarded_call();
+    runtime::call_contract::<()>(target, &entry_point, args);
+}
+
+/// # Purpose
+/// * Forwards a call to an entry point of another contract returning a `bool`, recording the
+///   answer under the `result` named key.
+/// # Arguments
+/// * `target` - `Key` -> Hash of the contract to call.
+/// * `entry_point` - `String` -> Entry point of the target contract to call.
+/// * `args` - `Bytes` -> Serialized `RuntimeArgs` of the call.
+#[no_mangle]
+pub extern "C" fn forward_query() {
+    let (target, entry_point, args) = forwarded_call();
+    let result: bool = runtime::call_contract(target, &entry_point, args);
+    set_key(RESULT_KEY, result);
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(endpoint("forward", forward_parameters(), CLType::Unit));
+    entry_points.add_entry_point(endpoint(
+        "forward_query",
+        forward_parameters(),
+        CLType::Unit,
+    ));
+
+    install("Proxy", entry_points, NamedKeys::new());
+}
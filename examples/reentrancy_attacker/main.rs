@@ -0,0 +1,50 @@
+#![allow(unused_parens)]
+#![allow(non_snake_case)]
+#![no_main]
+
+extern crate alloc;
+
+use contract::{
+    contract_api::{
+        runtime,
+        storage::{self},
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use libs::{
+    upgrade::install,
+    utils::{endpoint, get_optional_key},
+};
+use types::{contracts::NamedKeys, CLType, ContractHash, EntryPoints, Key, Parameter, RuntimeArgs};
+
+const TARGET_KEY: &str = "target";
+
+/// # Purpose
+/// * Calls back into the target contract.
+/// # Arguments
+/// * `entry_point` - `String` -> Entry point of the target contract to call.
+#[no_mangle]
+pub extern "C" fn attack() {
+    let entry_point: String = runtime::get_named_arg("entry_point");
+    let target: Key = get_optional_key(TARGET_KEY).unwrap_or_revert();
+
+    let target = ContractHash::new(target.into_hash().unwrap_or_revert());
+    runtime::call_contract::<()>(target, &entry_point, RuntimeArgs::new());
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let target: Key = runtime::get_named_arg("target");
+
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(endpoint(
+        "attack",
+        vec![Parameter::new("entry_point", CLType::String)],
+        CLType::Unit,
+    ));
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(TARGET_KEY.to_string(), storage::new_uref(target).into());
+
+    install("ReentrancyAttacker", entry_points, named_keys);
+}
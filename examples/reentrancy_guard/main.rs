@@ -0,0 +1,91 @@
+#![allow(unused_parens)]
+#![allow(non_snake_case)]
+#![no_main]
+
+extern crate alloc;
+
+use contract::{
+    contract_api::{
+        runtime,
+        storage::{self},
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use libs::{
+    security::ReentrancyGuard,
+    upgrade::install,
+    utils::{endpoint, get_key, set_key},
+};
+use types::{
+    contracts::NamedKeys, runtime_args, CLType, ContractHash, EntryPoints, Key, Parameter,
+    RuntimeArgs,
+};
+
+const COUNTER_KEY: &str = "counter";
+
+fn increment_counter() {
+    let counter: u64 = get_key(COUNTER_KEY);
+    set_key(COUNTER_KEY, counter + 1);
+}
+
+/// # Purpose
+/// * Increments the counter without any reentrancy protection.
+#[no_mangle]
+pub extern "C" fn increment() {
+    increment_counter();
+}
+
+/// # Purpose
+/// * Increments the counter while holding the reentrancy lock.
+#[no_mangle]
+pub extern "C" fn guarded_increment() {
+    let _lock = ReentrancyGuard::lock();
+    increment_counter();
+}
+
+/// # Purpose
+/// * Increments the counter while holding the reentrancy lock, then calls back the given contract.
+/// # Arguments
+/// * `callback` - `Key` -> Hash of the contract to call back.
+/// * `entry_point` - `String` -> Entry point of this contract the callback contract should call.
+#[no_mangle]
+pub extern "C" fn guarded_callback() {
+    let callback: Key = runtime::get_named_arg("callback");
+    let entry_point: String = runtime::get_named_arg("entry_point");
+
+    let _lock = ReentrancyGuard::lock();
+    increment_counter();
+
+    let callback = ContractHash::new(callback.into_hash().unwrap_or_revert());
+    runtime::call_contract::<()>(
+        callback,
+        "attack",
+        runtime_args! {
+            "entry_point" => entry_point
+        },
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(endpoint("increment", vec![], CLType::Unit));
+    entry_points.add_entry_point(endpoint("guarded_increment", vec![], CLType::Unit));
+    entry_points.add_entry_point(endpoint(
+        "guarded_callback",
+        vec![
+            Parameter::new("callback", CLType::Key),
+            Parameter::new("entry_point", CLType::String),
+        ],
+        CLType::Unit,
+    ));
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(COUNTER_KEY.to_string(), storage::new_uref(0u64).into());
+    named_keys.insert(
+        ReentrancyGuard::REENTRANCY_GUARD_KEY.to_string(),
+        storage::new_uref(false).into(),
+    );
+
+    install("ReentrancyGuard", entry_points, named_keys);
+}
@@ -0,0 +1,96 @@
+#![allow(unused_parens)]
+#![allow(non_snake_case)]
+#![no_main]
+
+extern crate alloc;
+
+use contract::contract_api::runtime;
+use libs::{
+    storage::{Mapping, Variable},
+    upgrade::install,
+    utils::endpoint,
+};
+use types::{contracts::NamedKeys, CLType, EntryPoints, Parameter};
+
+const CONTRACT_NAME: &str = "Storage";
+const VALUE_KEY: &str = "value";
+const ENTRIES_KEY: &str = "entries";
+const LAST_READ_KEY: &str = "last_read";
+
+fn value() -> Variable<u64> {
+    Variable::new(VALUE_KEY)
+}
+
+fn entries() -> Mapping<String, u64> {
+    Mapping::new(ENTRIES_KEY)
+}
+
+/// Records the result of the latest read under the `last_read` named key.
+fn record_read(result: Option<u64>) {
+    Variable::<Option<u64>>::new(LAST_READ_KEY).set(result);
+}
+
+/// # Purpose
+/// * Stores the `value` variable.
+/// # Arguments
+/// * `value` - `u64` -> Value to store.
+#[no_mangle]
+pub extern "C" fn set_value() {
+    let new_value: u64 = runtime::get_named_arg("value");
+    value().set(new_value);
+}
+
+/// # Purpose
+/// * Reads the `value` variable into the `last_read` named key.
+#[no_mangle]
+pub extern "C" fn read_value() {
+    record_read(value().get());
+}
+
+/// # Purpose
+/// * Stores an entry of the `entries` mapping.
+/// # Arguments
+/// * `key` - `String` -> Key of the entry.
+/// * `value` - `u64` -> Value to store.
+#[no_mangle]
+pub extern "C" fn set_entry() {
+    let key: String = runtime::get_named_arg("key");
+    let new_value: u64 = runtime::get_named_arg("value");
+    entries().set(&key, new_value);
+}
+
+/// # Purpose
+/// * Reads an entry of the `entries` mapping into the `last_read` named key.
+/// # Arguments
+/// * `key` - `String` -> Key of the entry.
+#[no_mangle]
+pub extern "C" fn read_entry() {
+    let key: String = runtime::get_named_arg("key");
+    record_read(entries().get(&key));
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(endpoint(
+        "set_value",
+        vec![Parameter::new("value", CLType::U64)],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(endpoint("read_value", vec![], CLType::Unit));
+    entry_points.add_entry_point(endpoint(
+        "set_entry",
+        vec![
+            Parameter::new("key", CLType::String),
+            Parameter::new("value", CLType::U64),
+        ],
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(endpoint(
+        "read_entry",
+        vec![Parameter::new("key", CLType::String)],
+        CLType::Unit,
+    ));
+
+    install(CONTRACT_NAME, entry_points, NamedKeys::new());
+}
@@ -0,0 +1,94 @@
+#![allow(unused_parens)]
+#![allow(non_snake_case)]
+#![no_main]
+
+extern crate alloc;
+
+use contract::contract_api::{
+    runtime,
+    storage::{self},
+};
+use libs::{
+    finance::TokenTimelock,
+    upgrade::{install, installed_package_hash, upgrade},
+};
+use types::{contracts::NamedKeys, EntryPoints, Key};
+
+const CONTRACT_NAME: &str = "TokenTimelock";
+
+/// # Purpose
+/// * Returns the address of the locked ERC20 token.
+#[no_mangle]
+pub extern "C" fn token() {
+    TokenTimelock::ret_token()
+}
+
+/// # Purpose
+/// * Returns the number of locks ever created.
+#[no_mangle]
+pub extern "C" fn lock_count() {
+    TokenTimelock::ret_lock_count()
+}
+
+/// # Purpose
+/// * Returns the number of locks created for the given address.
+/// # Arguments
+/// * `beneficiary` - `Key` -> Address of the beneficiary.
+#[no_mangle]
+pub extern "C" fn beneficiary_lock_count() {
+    TokenTimelock::ret_beneficiary_lock_count()
+}
+
+/// # Purpose
+/// * Returns the id of a lock created for the given address.
+/// # Arguments
+/// * `beneficiary` - `Key` -> Address of the beneficiary.
+/// * `index` - `u64` -> Index of the lock among the beneficiary's locks.
+#[no_mangle]
+pub extern "C" fn beneficiary_lock() {
+    TokenTimelock::ret_beneficiary_lock()
+}
+
+/// # Purpose
+/// * Locks an amount of the caller's tokens for the given address until the release time.
+/// # Arguments
+/// * `beneficiary` - `Key` -> Address receiving the tokens once released.
+/// * `amount` - `U256` -> Amount of the tokens to be locked.
+/// * `release_time` - `u64` -> Timestamp from which the tokens can be released, in milliseconds.
+/// # Returns
+/// * `lock_id` - `u64` -> Id of the lock.
+#[no_mangle]
+pub extern "C" fn lock() {
+    TokenTimelock::lock();
+}
+
+/// # Purpose
+/// * Transfers the tokens of a lock to its beneficiary once released.
+/// # Arguments
+/// * `lock_id` - `u64` -> Id of the lock.
+#[no_mangle]
+pub extern "C" fn release() {
+    TokenTimelock::release();
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+
+    TokenTimelock::set_entry_points(&mut entry_points);
+
+    if installed_package_hash(CONTRACT_NAME).is_some() {
+        upgrade(CONTRACT_NAME, entry_points, NamedKeys::new(), true);
+        return;
+    }
+
+    let token: Key = runtime::get_named_arg("token");
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        TokenTimelock::TOKEN_KEY.to_string(),
+        storage::new_uref(token).into(),
+    );
+
+    install(CONTRACT_NAME, entry_points, named_keys);
+}
@@ -0,0 +1,159 @@
+#![allow(unused_parens)]
+#![allow(non_snake_case)]
+#![no_main]
+
+extern crate alloc;
+
+use contract::contract_api::{
+    runtime,
+    storage::{self},
+};
+use libs::{
+    access::Ownable,
+    finance::VestingWallet,
+    upgrade::{install, installed_package_hash, upgrade},
+};
+use types::{contracts::NamedKeys, EntryPoints, Key};
+
+const CONTRACT_NAME: &str = "VestingWallet";
+
+/// # Purpose
+/// * Returns the address of the vested ERC20 token.
+#[no_mangle]
+pub extern "C" fn token() {
+    VestingWallet::ret_token()
+}
+
+/// # Purpose
+/// * Returns the address receiving the vested tokens.
+#[no_mangle]
+pub extern "C" fn beneficiary() {
+    VestingWallet::ret_beneficiary()
+}
+
+/// # Purpose
+/// * Returns the timestamp at which vesting starts, in milliseconds.
+#[no_mangle]
+pub extern "C" fn start() {
+    VestingWallet::ret_start()
+}
+
+/// # Purpose
+/// * Returns the time after `start` before which nothing vests, in milliseconds.
+#[no_mangle]
+pub extern "C" fn cliff() {
+    VestingWallet::ret_cliff()
+}
+
+/// # Purpose
+/// * Returns the time after `start` at which everything is vested, in milliseconds.
+#[no_mangle]
+pub extern "C" fn duration() {
+    VestingWallet::ret_duration()
+}
+
+/// # Purpose
+/// * Returns the amount of tokens already released to the beneficiary.
+#[no_mangle]
+pub extern "C" fn released() {
+    VestingWallet::ret_released()
+}
+
+/// # Purpose
+/// * Returns the amount of tokens that can be released to the beneficiary now.
+#[no_mangle]
+pub extern "C" fn releasable() {
+    VestingWallet::ret_releasable()
+}
+
+/// # Purpose
+/// * Transfers the releasable tokens to the beneficiary.
+#[no_mangle]
+pub extern "C" fn release() {
+    VestingWallet::release();
+}
+
+/// # Purpose
+/// * Refunds the tokens not vested yet to the owner. Only callable by the owner.
+#[no_mangle]
+pub extern "C" fn revoke() {
+    VestingWallet::revoke();
+}
+
+/// # Purpose
+/// * Returns the `owner` property.
+#[no_mangle]
+pub extern "C" fn owner() {
+    Ownable::ret_owner()
+}
+
+/// # Purpose
+/// * Transfers the ownership of the contract to the given address.
+/// # Arguments
+/// * `new_owner` - `Key` -> Address of the new owner.
+#[no_mangle]
+pub extern "C" fn transfer_ownership() {
+    Ownable::transfer_ownership();
+}
+
+/// # Purpose
+/// * Renounce ownership of contract.
+#[no_mangle]
+pub extern "C" fn renounce_ownership() {
+    Ownable::renounce_ownership();
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+
+    VestingWallet::set_entry_points(&mut entry_points);
+    Ownable::set_entry_points(&mut entry_points);
+
+    if installed_package_hash(CONTRACT_NAME).is_some() {
+        upgrade(CONTRACT_NAME, entry_points, NamedKeys::new(), true);
+        return;
+    }
+
+    let token: Key = runtime::get_named_arg("token");
+    let beneficiary: Key = runtime::get_named_arg("beneficiary");
+    let start: u64 = runtime::get_named_arg("start");
+    let cliff: u64 = runtime::get_named_arg("cliff");
+    let duration: u64 = runtime::get_named_arg("duration");
+    let revocable: bool = runtime::get_named_arg("revocable");
+
+    VestingWallet::check_schedule(cliff, duration);
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        VestingWallet::TOKEN_KEY.to_string(),
+        storage::new_uref(token).into(),
+    );
+    named_keys.insert(
+        VestingWallet::BENEFICIARY_KEY.to_string(),
+        storage::new_uref(beneficiary).into(),
+    );
+    named_keys.insert(
+        VestingWallet::START_KEY.to_string(),
+        storage::new_uref(start).into(),
+    );
+    named_keys.insert(
+        VestingWallet::CLIFF_KEY.to_string(),
+        storage::new_uref(cliff).into(),
+    );
+    named_keys.insert(
+        VestingWallet::DURATION_KEY.to_string(),
+        storage::new_uref(duration).into(),
+    );
+    named_keys.insert(
+        VestingWallet::REVOCABLE_KEY.to_string(),
+        storage::new_uref(revocable).into(),
+    );
+    // The deployer owns the wallet and is the one revoking it.
+    named_keys.insert(
+        "owner".to_string(),
+        storage::new_uref(Key::Account(runtime::get_caller())).into(),
+    );
+
+    install(CONTRACT_NAME, entry_points, named_keys);
+}
@@ -1,14 +1,22 @@
-use contract::contract_api::{
-    runtime,
-    storage::{self},
+use contract::{
+    contract_api::{
+        runtime,
+        storage::{self},
+    },
+    unwrap_or_revert::UnwrapOrRevert,
 };
 use std::collections::BTreeMap;
-use types::{CLType, EntryPoint, EntryPoints, Key, Parameter, URef, U256};
+use types::{contracts::NamedKeys, CLType, EntryPoint, EntryPoints, Key, Parameter, URef, U256};
 
 use crate::{
-    access::AccessControl,
-    error::Error,
-    utils::{contract_package_hash, endpoint, get, get_caller, key_to_str, ret, set},
+    access::{error::AccessControlError, AccessControl},
+    hash::blake2b256,
+    introspection::{interface_id, register_interface},
+    storage::{Mapping, Variable},
+    utils::{
+        contract_package_hash, endpoint, get_caller, make_dictionary_item_key, require_holder_key,
+        ret,
+    },
 };
 
 struct AccessControlEntryPoints {}
@@ -70,19 +78,138 @@ impl AccessControlEntryPoints {
             CLType::Unit,
         )
     }
+
+    /// Returns the `grant_roles` entry point.
+    pub fn grant_roles() -> EntryPoint {
+        endpoint(
+            "grant_roles",
+            vec![
+                Parameter::new("role", CLType::U256),
+                Parameter::new("accounts", CLType::List(Box::new(CLType::Key))),
+            ],
+            CLType::Unit,
+        )
+    }
+
+    /// Returns the `revoke_roles` entry point.
+    pub fn revoke_roles() -> EntryPoint {
+        endpoint(
+            "revoke_roles",
+            vec![
+                Parameter::new("role", CLType::U256),
+                Parameter::new("accounts", CLType::List(Box::new(CLType::Key))),
+            ],
+            CLType::Unit,
+        )
+    }
+
+    /// Returns the `grant_role_batch` entry point.
+    pub fn grant_role_batch() -> EntryPoint {
+        endpoint(
+            "grant_role_batch",
+            vec![Parameter::new(
+                "grants",
+                CLType::List(Box::new(CLType::Tuple2([
+                    Box::new(CLType::U256),
+                    Box::new(CLType::Key),
+                ]))),
+            )],
+            CLType::Unit,
+        )
+    }
+
+    /// Returns the `get_role_expiration` entry point.
+    pub fn get_role_expiration() -> EntryPoint {
+        endpoint(
+            "get_role_expiration",
+            vec![
+                Parameter::new("role", CLType::U256),
+                Parameter::new("account", CLType::Key),
+            ],
+            CLType::U64,
+        )
+    }
+
+    /// Returns the `grant_role_until` entry point.
+    pub fn grant_role_until() -> EntryPoint {
+        endpoint(
+            "grant_role_until",
+            vec![
+                Parameter::new("role", CLType::U256),
+                Parameter::new("account", CLType::Key),
+                Parameter::new("expires_at", CLType::U64),
+            ],
+            CLType::Unit,
+        )
+    }
+
+    /// Returns the `get_role_names` entry point.
+    pub fn get_role_names() -> EntryPoint {
+        endpoint(
+            "get_role_names",
+            vec![],
+            CLType::List(Box::new(CLType::String)),
+        )
+    }
+
+    /// Returns the `get_role_name` entry point.
+    pub fn get_role_name() -> EntryPoint {
+        endpoint(
+            "get_role_name",
+            vec![Parameter::new("role", CLType::U256)],
+            CLType::Option(Box::new(CLType::String)),
+        )
+    }
+
+    /// Returns the `set_role_admin` entry point.
+    pub fn set_role_admin() -> EntryPoint {
+        endpoint(
+            "set_role_admin",
+            vec![
+                Parameter::new("role", CLType::U256),
+                Parameter::new("admin_role", CLType::U256),
+            ],
+            CLType::Unit,
+        )
+    }
 }
 
 pub enum AccessControlEvent {
-    OwnershipTransferred { old_owner: Key, new_owner: Key },
+    RoleGranted {
+        role: U256,
+        account: Key,
+        sender: Key,
+    },
+    RoleRevoked {
+        role: U256,
+        account: Key,
+        sender: Key,
+    },
+    RoleAdminChanged {
+        role: U256,
+        previous_admin_role: U256,
+        new_admin_role: U256,
+    },
 }
 
 impl AccessControlEvent {
     pub fn type_name(&self) -> String {
         match self {
-            AccessControlEvent::OwnershipTransferred {
-                old_owner: _,
-                new_owner: _,
-            } => "ownership_transferred",
+            AccessControlEvent::RoleGranted {
+                role: _,
+                account: _,
+                sender: _,
+            } => "role_granted",
+            AccessControlEvent::RoleRevoked {
+                role: _,
+                account: _,
+                sender: _,
+            } => "role_revoked",
+            AccessControlEvent::RoleAdminChanged {
+                role: _,
+                previous_admin_role: _,
+                new_admin_role: _,
+            } => "role_admin_changed",
         }
         .to_string()
     }
@@ -92,30 +219,89 @@ impl AccessControl {
     pub const DEFAULT_ADMIN_ROLE: U256 = U256::zero();
     pub const ACCESS_ROLE_MEMBER_KEY: &'static str = "_access_control_members";
     pub const ACCESS_ROLE_ADMIN_KEY: &'static str = "_access_control_admin";
+    pub const ACCESS_ROLE_EXPIRATION_KEY: &'static str = "_access_control_expirations";
+    pub const ACCESS_ROLE_NAMES_KEY: &'static str = "_access_control_role_names";
+    pub const ACCESS_ROLE_NAME_LIST_KEY: &'static str = "_access_control_role_name_list";
+    pub const DEFAULT_ADMIN_ROLE_NAME: &'static str = "DEFAULT_ADMIN_ROLE";
 
     pub fn set_entry_points(current_entry_points: &mut EntryPoints) -> &EntryPoints {
-        current_entry_points.add_entry_point(AccessControlEntryPoints::has_role());
-        current_entry_points.add_entry_point(AccessControlEntryPoints::get_role_admin());
-        current_entry_points.add_entry_point(AccessControlEntryPoints::grant_role());
-        current_entry_points.add_entry_point(AccessControlEntryPoints::revoke_role());
-        current_entry_points.add_entry_point(AccessControlEntryPoints::renounce_role());
+        let entry_points = AccessControl::entry_points();
+        register_interface(&AccessControl::interface_entry_points());
+        for entry_point in entry_points {
+            current_entry_points.add_entry_point(entry_point);
+        }
 
         current_entry_points
     }
 
-    pub fn emit(ownable_event: &AccessControlEvent) {
+    /// Returns the entry points of the AccessControl module.
+    pub fn entry_points() -> Vec<EntryPoint> {
+        vec![
+            AccessControlEntryPoints::has_role(),
+            AccessControlEntryPoints::get_role_admin(),
+            AccessControlEntryPoints::grant_role(),
+            AccessControlEntryPoints::revoke_role(),
+            AccessControlEntryPoints::renounce_role(),
+            AccessControlEntryPoints::grant_roles(),
+            AccessControlEntryPoints::revoke_roles(),
+            AccessControlEntryPoints::grant_role_batch(),
+            AccessControlEntryPoints::get_role_expiration(),
+            AccessControlEntryPoints::grant_role_until(),
+            AccessControlEntryPoints::get_role_names(),
+            AccessControlEntryPoints::get_role_name(),
+            AccessControlEntryPoints::set_role_admin(),
+        ]
+    }
+
+    /// Returns the standard entry points making up the AccessControl interface, without the
+    /// extensions of the module such as role batches, expirations and names.
+    pub fn interface_entry_points() -> Vec<EntryPoint> {
+        vec![
+            AccessControlEntryPoints::has_role(),
+            AccessControlEntryPoints::get_role_admin(),
+            AccessControlEntryPoints::grant_role(),
+            AccessControlEntryPoints::revoke_role(),
+            AccessControlEntryPoints::renounce_role(),
+        ]
+    }
+
+    pub fn interface_id() -> u32 {
+        interface_id(&AccessControl::interface_entry_points())
+    }
+
+    pub fn emit(access_control_event: &AccessControlEvent) {
         let mut events = Vec::new();
         let package = contract_package_hash();
-        match ownable_event {
-            AccessControlEvent::OwnershipTransferred {
-                old_owner,
-                new_owner,
+        match access_control_event {
+            AccessControlEvent::RoleGranted {
+                role,
+                account,
+                sender,
+            }
+            | AccessControlEvent::RoleRevoked {
+                role,
+                account,
+                sender,
+            } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_string());
+                event.insert("event_type", access_control_event.type_name());
+                event.insert("role", role.to_string());
+                event.insert("account", account.to_string());
+                event.insert("sender", sender.to_string());
+                events.push(event);
+            }
+            AccessControlEvent::RoleAdminChanged {
+                role,
+                previous_admin_role,
+                new_admin_role,
             } => {
                 let mut event = BTreeMap::new();
                 event.insert("contract_package_hash", package.to_string());
-                event.insert("event_type", ownable_event.type_name());
-                event.insert("old_owner", old_owner.to_string());
-                event.insert("new_owner", new_owner.to_string());
+                event.insert("event_type", access_control_event.type_name());
+                event.insert("role", role.to_string());
+                event.insert("previous_admin_role", previous_admin_role.to_string());
+                event.insert("new_admin_role", new_admin_role.to_string());
                 events.push(event);
             }
         };
@@ -124,11 +310,134 @@ impl AccessControl {
         }
     }
 
+    fn members() -> Mapping<(U256, Key), bool> {
+        Mapping::new(AccessControl::ACCESS_ROLE_MEMBER_KEY)
+    }
+
+    fn admins() -> Mapping<U256, U256> {
+        Mapping::new(AccessControl::ACCESS_ROLE_ADMIN_KEY)
+    }
+
+    /// Expiry timestamp of the role grants, in milliseconds, indexed by `(role, account)`.
+    fn expirations() -> Mapping<(U256, Key), u64> {
+        Mapping::new(AccessControl::ACCESS_ROLE_EXPIRATION_KEY)
+    }
+
+    /// Name of the roles defined by the contract, indexed by role.
+    fn role_names() -> Mapping<U256, String> {
+        Mapping::new(AccessControl::ACCESS_ROLE_NAMES_KEY)
+    }
+
+    /// Returns the role named `name`: the big-endian number made of the blake2b hash of the
+    /// UTF-8 bytes of the name, or `DEFAULT_ADMIN_ROLE` for its own name.
+    ///
+    /// Doesn't call the host, so role ids can be derived off-chain as well.
+    pub fn role_id(name: &str) -> U256 {
+        if name == AccessControl::DEFAULT_ADMIN_ROLE_NAME {
+            return AccessControl::DEFAULT_ADMIN_ROLE;
+        }
+        U256::from_big_endian(&blake2b256(name.as_bytes()))
+    }
+
+    /// Returns the name of every role defined by the contract, in registration order.
+    pub fn get_role_names() -> Vec<String> {
+        Variable::new(AccessControl::ACCESS_ROLE_NAME_LIST_KEY).get_or_default()
+    }
+
+    pub fn ret_role_names() {
+        ret(AccessControl::get_role_names())
+    }
+
+    /// Returns the name `role` was defined with, `None` if it was not.
+    pub fn get_role_name(role: U256) -> Option<String> {
+        AccessControl::role_names().get(&role)
+    }
+
+    pub fn ret_role_name() {
+        let role: U256 = runtime::get_named_arg("role");
+
+        ret(AccessControl::get_role_name(role))
+    }
+
+    /// Returns the named keys of a registry defining the `DEFAULT_ADMIN_ROLE` and the roles
+    /// named `role_names`, whose ids are given by [`AccessControl::role_id`].
+    ///
+    /// Meant to be called by the session code installing the contract: the dictionary is
+    /// created in the current context and only handed over to the contract.
+    pub fn install_role_names(role_names: Vec<String>) -> NamedKeys {
+        let role_names_seed_uref =
+            storage::new_dictionary(AccessControl::ACCESS_ROLE_NAMES_KEY).unwrap_or_revert();
+        runtime::remove_key(AccessControl::ACCESS_ROLE_NAMES_KEY);
+
+        let mut names: Vec<String> = Vec::new();
+        for name in
+            core::iter::once(AccessControl::DEFAULT_ADMIN_ROLE_NAME.to_string()).chain(role_names)
+        {
+            if names.contains(&name) {
+                continue;
+            }
+            storage::dictionary_put(
+                role_names_seed_uref,
+                &Mapping::<U256, String>::item_key(&AccessControl::role_id(&name)),
+                name.clone(),
+            );
+            names.push(name);
+        }
+
+        let mut named_keys = NamedKeys::new();
+        named_keys.insert(
+            AccessControl::ACCESS_ROLE_NAMES_KEY.to_string(),
+            role_names_seed_uref.into(),
+        );
+        named_keys.insert(
+            AccessControl::ACCESS_ROLE_NAME_LIST_KEY.to_string(),
+            storage::new_uref(names).into(),
+        );
+        named_keys
+    }
+
+    /// Returns the named keys of the role registries, `default_admin` holding the
+    /// `DEFAULT_ADMIN_ROLE` and the roles named `role_names` being defined as by
+    /// [`AccessControl::install_role_names`].
+    ///
+    /// Meant to be called by the session code installing the contract: the dictionaries are
+    /// created in the current context and only handed over to the contract.
+    pub fn install_named_keys(default_admin: Key, role_names: Vec<String>) -> NamedKeys {
+        require_holder_key(&default_admin);
+
+        let role_admin_seed_uref =
+            storage::new_dictionary(AccessControl::ACCESS_ROLE_ADMIN_KEY).unwrap_or_revert();
+        runtime::remove_key(AccessControl::ACCESS_ROLE_ADMIN_KEY);
+        let role_members_seed_uref =
+            storage::new_dictionary(AccessControl::ACCESS_ROLE_MEMBER_KEY).unwrap_or_revert();
+        runtime::remove_key(AccessControl::ACCESS_ROLE_MEMBER_KEY);
+
+        storage::dictionary_put(
+            role_members_seed_uref,
+            &AccessControl::get_role_members_key(AccessControl::DEFAULT_ADMIN_ROLE, default_admin),
+            true,
+        );
+
+        let mut named_keys = AccessControl::install_role_names(role_names);
+        named_keys.insert(
+            AccessControl::ACCESS_ROLE_ADMIN_KEY.to_string(),
+            role_admin_seed_uref.into(),
+        );
+        named_keys.insert(
+            AccessControl::ACCESS_ROLE_MEMBER_KEY.to_string(),
+            role_members_seed_uref.into(),
+        );
+        named_keys
+    }
+
+    /// Returns whether `account` holds `role`, a role granted until a time now passed being no
+    /// longer held.
     pub fn has_role(role: U256, account: Key) -> bool {
-        get(
-            AccessControl::ACCESS_ROLE_MEMBER_KEY,
-            &AccessControl::get_role_members_key(role, account),
-        )
+        if !AccessControl::members().get_or_default(&(role, account)) {
+            return false;
+        }
+        let expires_at = AccessControl::get_role_expiration(role, account);
+        expires_at == 0 || u64::from(runtime::get_blocktime()) < expires_at
     }
 
     pub fn ret_has_role() {
@@ -139,10 +448,7 @@ impl AccessControl {
     }
 
     pub fn get_role_admin(role: U256) -> U256 {
-        get(
-            AccessControl::ACCESS_ROLE_ADMIN_KEY,
-            &AccessControl::get_role_admin_key(role),
-        )
+        AccessControl::admins().get_or_default(&role)
     }
 
     pub fn ret_role_admin() {
@@ -151,6 +457,18 @@ impl AccessControl {
         ret(AccessControl::get_role_admin(role))
     }
 
+    /// Returns the time at which the grant of `role` to `account` expires, `0` if it does not.
+    pub fn get_role_expiration(role: U256, account: Key) -> u64 {
+        AccessControl::expirations().get_or_default(&(role, account))
+    }
+
+    pub fn ret_role_expiration() {
+        let role: U256 = runtime::get_named_arg("role");
+        let account: Key = runtime::get_named_arg("account");
+
+        ret(AccessControl::get_role_expiration(role, account))
+    }
+
     pub fn grant_role() {
         let role: U256 = runtime::get_named_arg("role");
         let account: Key = runtime::get_named_arg("account");
@@ -160,6 +478,17 @@ impl AccessControl {
         AccessControl::_grant_role(role, account);
     }
 
+    /// Grants `role` to `account` until the `expires_at` timestamp, in milliseconds.
+    pub fn grant_role_until() {
+        let role: U256 = runtime::get_named_arg("role");
+        let account: Key = runtime::get_named_arg("account");
+        let expires_at: u64 = runtime::get_named_arg("expires_at");
+
+        AccessControl::check_only_role(AccessControl::get_role_admin(role));
+
+        AccessControl::_grant_role_until(role, account, expires_at);
+    }
+
     pub fn revoke_role() {
         let role: U256 = runtime::get_named_arg("role");
         let account: Key = runtime::get_named_arg("account");
@@ -169,62 +498,149 @@ impl AccessControl {
         AccessControl::_revoke_role(role, account);
     }
 
+    /// Grants `role` to every account of `accounts`.
+    pub fn grant_roles() {
+        let role: U256 = runtime::get_named_arg("role");
+        let accounts: Vec<Key> = runtime::get_named_arg("accounts");
+
+        AccessControl::check_only_role(AccessControl::get_role_admin(role));
+
+        for account in accounts {
+            AccessControl::_grant_role(role, account);
+        }
+    }
+
+    /// Revokes `role` from every account of `accounts`.
+    pub fn revoke_roles() {
+        let role: U256 = runtime::get_named_arg("role");
+        let accounts: Vec<Key> = runtime::get_named_arg("accounts");
+
+        AccessControl::check_only_role(AccessControl::get_role_admin(role));
+
+        for account in accounts {
+            AccessControl::_revoke_role(role, account);
+        }
+    }
+
+    /// Grants every `(role, account)` pair of `grants`, the caller having to be an admin of
+    /// every role.
+    pub fn grant_role_batch() {
+        let grants: Vec<(U256, Key)> = runtime::get_named_arg("grants");
+
+        for (role, account) in grants {
+            AccessControl::check_only_role(AccessControl::get_role_admin(role));
+            AccessControl::_grant_role(role, account);
+        }
+    }
+
     pub fn renounce_role() {
         let role: U256 = runtime::get_named_arg("role");
         let account: Key = runtime::get_named_arg("account");
 
         if account != get_caller() {
-            runtime::revert(Error::InvalidPermission);
+            runtime::revert(AccessControlError::CanOnlyRenounceForSelf);
         }
 
         AccessControl::_revoke_role(role, account);
     }
 
+    pub fn set_role_admin() {
+        let role: U256 = runtime::get_named_arg("role");
+        let admin_role: U256 = runtime::get_named_arg("admin_role");
+
+        AccessControl::check_only_role(AccessControl::get_role_admin(role));
+
+        AccessControl::_set_role_admin(role, admin_role);
+    }
+
     pub fn check_only_role(role: U256) {
         if !AccessControl::has_role(role, get_caller()) {
-            runtime::revert(Error::InvalidPermission);
+            runtime::revert(AccessControlError::MissingRole);
         }
     }
 
     pub fn check_role(role: U256, account: Key) {
         if !AccessControl::has_role(role, account) {
-            runtime::revert(Error::InvalidPermission);
+            runtime::revert(AccessControlError::MissingRole);
         }
     }
 
     pub fn _set_role_admin(role: U256, admin_role: U256) {
-        set(
-            AccessControl::ACCESS_ROLE_ADMIN_KEY,
-            &AccessControl::get_role_admin_key(role),
-            admin_role,
-        );
+        let previous_admin_role = AccessControl::get_role_admin(role);
+        AccessControl::admins().set(&role, admin_role);
+
+        AccessControl::emit(&AccessControlEvent::RoleAdminChanged {
+            role,
+            previous_admin_role,
+            new_admin_role: admin_role,
+        });
     }
 
     pub fn _grant_role(role: U256, account: Key) {
-        if !AccessControl::has_role(role, account) {
-            set(
-                AccessControl::ACCESS_ROLE_MEMBER_KEY,
-                &AccessControl::get_role_members_key(role, account),
-                true,
-            );
+        require_holder_key(&account);
+        let held = AccessControl::has_role(role, account);
+        if !held {
+            AccessControl::members().set(&(role, account), true);
+        }
+        // A permanent grant replaces a time-bounded one.
+        AccessControl::_set_role_expiration(role, account, 0);
+
+        if !held {
+            AccessControl::emit(&AccessControlEvent::RoleGranted {
+                role,
+                account,
+                sender: get_caller(),
+            });
+        }
+    }
+
+    pub fn _grant_role_until(role: U256, account: Key, expires_at: u64) {
+        require_holder_key(&account);
+        if expires_at <= u64::from(runtime::get_blocktime()) {
+            runtime::revert(AccessControlError::ExpiryInPast);
+        }
+        let held = AccessControl::has_role(role, account);
+        AccessControl::members().set(&(role, account), true);
+        AccessControl::_set_role_expiration(role, account, expires_at);
+
+        if !held {
+            AccessControl::emit(&AccessControlEvent::RoleGranted {
+                role,
+                account,
+                sender: get_caller(),
+            });
         }
     }
 
     pub fn _revoke_role(role: U256, account: Key) {
-        if AccessControl::has_role(role, account) {
-            set(
-                AccessControl::ACCESS_ROLE_MEMBER_KEY,
-                &AccessControl::get_role_members_key(role, account),
-                false,
-            );
+        let held = AccessControl::has_role(role, account);
+        // Expired grants are still recorded, and would become permanent once their expiry cleared.
+        if AccessControl::members().get_or_default(&(role, account)) {
+            AccessControl::members().set(&(role, account), false);
+        }
+        AccessControl::_set_role_expiration(role, account, 0);
+
+        if held {
+            AccessControl::emit(&AccessControlEvent::RoleRevoked {
+                role,
+                account,
+                sender: get_caller(),
+            });
+        }
+    }
+
+    fn _set_role_expiration(role: U256, account: Key, expires_at: u64) {
+        // Avoids creating the dictionary for contracts never granting time-bounded roles.
+        if AccessControl::get_role_expiration(role, account) != expires_at {
+            AccessControl::expirations().set(&(role, account), expires_at);
         }
     }
 
     pub fn get_role_members_key(role: U256, account: Key) -> String {
-        [key_to_str(&account), role.to_string()].join("_")
+        make_dictionary_item_key(&(role, account))
     }
 
     pub fn get_role_admin_key(role: U256) -> String {
-        role.to_string()
+        make_dictionary_item_key(&role)
     }
 }
@@ -0,0 +1,468 @@
+use contract::{
+    contract_api::{
+        runtime,
+        storage::{self},
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use std::collections::BTreeMap;
+use types::{
+    runtime_args, CLType, ContractHash, EntryPoint, EntryPoints, Key, Parameter, RuntimeArgs, URef,
+    U256,
+};
+
+use crate::{
+    access::{error::AccessManagerError, AccessControl, AccessManaged, AccessManager},
+    error::Error,
+    storage::{Mapping, Variable},
+    utils::{contract_package_hash, endpoint, get_caller, ret},
+};
+
+struct AccessManagerEntryPoints {}
+
+impl AccessManagerEntryPoints {
+    /// Returns the `get_target_function_role` entry point.
+    pub fn get_target_function_role() -> EntryPoint {
+        endpoint(
+            "get_target_function_role",
+            vec![
+                Parameter::new("target", CLType::Key),
+                Parameter::new("selector", CLType::String),
+            ],
+            CLType::U256,
+        )
+    }
+
+    /// Returns the `set_target_function_role` entry point.
+    pub fn set_target_function_role() -> EntryPoint {
+        endpoint(
+            "set_target_function_role",
+            vec![
+                Parameter::new("target", CLType::Key),
+                Parameter::new("selector", CLType::String),
+                Parameter::new("role", CLType::U256),
+            ],
+            CLType::Unit,
+        )
+    }
+
+    /// Returns the `get_role_execution_delay` entry point.
+    pub fn get_role_execution_delay() -> EntryPoint {
+        endpoint(
+            "get_role_execution_delay",
+            vec![Parameter::new("role", CLType::U256)],
+            CLType::U64,
+        )
+    }
+
+    /// Returns the `set_role_execution_delay` entry point.
+    pub fn set_role_execution_delay() -> EntryPoint {
+        endpoint(
+            "set_role_execution_delay",
+            vec![
+                Parameter::new("role", CLType::U256),
+                Parameter::new("delay", CLType::U64),
+            ],
+            CLType::Unit,
+        )
+    }
+
+    /// Returns the `can_call` entry point.
+    pub fn can_call() -> EntryPoint {
+        endpoint(
+            "can_call",
+            vec![
+                Parameter::new("caller", CLType::Key),
+                Parameter::new("target", CLType::Key),
+                Parameter::new("selector", CLType::String),
+            ],
+            CLType::Tuple2([Box::new(CLType::Bool), Box::new(CLType::U64)]),
+        )
+    }
+
+    /// Returns the `get_schedule` entry point.
+    pub fn get_schedule() -> EntryPoint {
+        endpoint(
+            "get_schedule",
+            vec![
+                Parameter::new("caller", CLType::Key),
+                Parameter::new("target", CLType::Key),
+                Parameter::new("selector", CLType::String),
+            ],
+            CLType::U64,
+        )
+    }
+
+    /// Returns the `schedule` entry point.
+    pub fn schedule() -> EntryPoint {
+        endpoint(
+            "schedule",
+            vec![
+                Parameter::new("target", CLType::Key),
+                Parameter::new("selector", CLType::String),
+            ],
+            CLType::U64,
+        )
+    }
+
+    /// Returns the `consume_scheduled_op` entry point.
+    pub fn consume_scheduled_op() -> EntryPoint {
+        endpoint(
+            "consume_scheduled_op",
+            vec![
+                Parameter::new("caller", CLType::Key),
+                Parameter::new("selector", CLType::String),
+            ],
+            CLType::Unit,
+        )
+    }
+}
+
+pub enum AccessManagerEvent {
+    TargetFunctionRoleUpdated {
+        target: Key,
+        selector: String,
+        role: U256,
+    },
+    RoleExecutionDelayUpdated {
+        role: U256,
+        delay: u64,
+    },
+    OperationScheduled {
+        caller: Key,
+        target: Key,
+        selector: String,
+        timepoint: u64,
+    },
+    OperationExecuted {
+        caller: Key,
+        target: Key,
+        selector: String,
+    },
+}
+
+impl AccessManagerEvent {
+    pub fn type_name(&self) -> String {
+        match self {
+            AccessManagerEvent::TargetFunctionRoleUpdated {
+                target: _,
+                selector: _,
+                role: _,
+            } => "target_function_role_updated",
+            AccessManagerEvent::RoleExecutionDelayUpdated { role: _, delay: _ } => {
+                "role_execution_delay_updated"
+            }
+            AccessManagerEvent::OperationScheduled {
+                caller: _,
+                target: _,
+                selector: _,
+                timepoint: _,
+            } => "operation_scheduled",
+            AccessManagerEvent::OperationExecuted {
+                caller: _,
+                target: _,
+                selector: _,
+            } => "operation_executed",
+        }
+        .to_string()
+    }
+}
+
+impl AccessManager {
+    /// Role held by every account, for entry points anyone can call.
+    pub const PUBLIC_ROLE: U256 = U256::MAX;
+    pub const FUNCTION_ROLES_KEY: &'static str = "_access_manager_function_roles";
+    pub const ROLE_DELAYS_KEY: &'static str = "_access_manager_role_delays";
+    pub const SCHEDULES_KEY: &'static str = "_access_manager_schedules";
+
+    pub fn set_entry_points(current_entry_points: &mut EntryPoints) -> &EntryPoints {
+        current_entry_points.add_entry_point(AccessManagerEntryPoints::get_target_function_role());
+        current_entry_points.add_entry_point(AccessManagerEntryPoints::set_target_function_role());
+        current_entry_points.add_entry_point(AccessManagerEntryPoints::get_role_execution_delay());
+        current_entry_points.add_entry_point(AccessManagerEntryPoints::set_role_execution_delay());
+        current_entry_points.add_entry_point(AccessManagerEntryPoints::can_call());
+        current_entry_points.add_entry_point(AccessManagerEntryPoints::get_schedule());
+        current_entry_points.add_entry_point(AccessManagerEntryPoints::schedule());
+        current_entry_points.add_entry_point(AccessManagerEntryPoints::consume_scheduled_op());
+
+        current_entry_points
+    }
+
+    pub fn emit(access_manager_event: &AccessManagerEvent) {
+        let mut events = Vec::new();
+        let package = contract_package_hash();
+        match access_manager_event {
+            AccessManagerEvent::TargetFunctionRoleUpdated {
+                target,
+                selector,
+                role,
+            } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_string());
+                event.insert("event_type", access_manager_event.type_name());
+                event.insert("target", target.to_string());
+                event.insert("selector", selector.clone());
+                event.insert("role", role.to_string());
+                events.push(event);
+            }
+            AccessManagerEvent::RoleExecutionDelayUpdated { role, delay } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_string());
+                event.insert("event_type", access_manager_event.type_name());
+                event.insert("role", role.to_string());
+                event.insert("delay", delay.to_string());
+                events.push(event);
+            }
+            AccessManagerEvent::OperationScheduled {
+                caller,
+                target,
+                selector,
+                timepoint,
+            } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_string());
+                event.insert("event_type", access_manager_event.type_name());
+                event.insert("caller", caller.to_string());
+                event.insert("target", target.to_string());
+                event.insert("selector", selector.clone());
+                event.insert("timepoint", timepoint.to_string());
+                events.push(event);
+            }
+            AccessManagerEvent::OperationExecuted {
+                caller,
+                target,
+                selector,
+            } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_string());
+                event.insert("event_type", access_manager_event.type_name());
+                event.insert("caller", caller.to_string());
+                event.insert("target", target.to_string());
+                event.insert("selector", selector.clone());
+                events.push(event);
+            }
+        };
+        for event in events {
+            let _: URef = storage::new_uref(event);
+        }
+    }
+
+    /// Role required to call an entry point, indexed by `(target, selector)`.
+    fn function_roles() -> Mapping<(Key, String), U256> {
+        Mapping::new(AccessManager::FUNCTION_ROLES_KEY)
+    }
+
+    fn role_delays() -> Mapping<U256, u64> {
+        Mapping::new(AccessManager::ROLE_DELAYS_KEY)
+    }
+
+    /// Time from which a scheduled call can be made, indexed by `(caller, target, selector)`.
+    fn schedules() -> Mapping<(Key, Key, String), u64> {
+        Mapping::new(AccessManager::SCHEDULES_KEY)
+    }
+
+    /// Returns the role required to call `selector` on `target`, `DEFAULT_ADMIN_ROLE` if none
+    /// was set.
+    pub fn get_target_function_role(target: Key, selector: String) -> U256 {
+        AccessManager::function_roles()
+            .get(&(target, selector))
+            .unwrap_or(AccessControl::DEFAULT_ADMIN_ROLE)
+    }
+
+    pub fn ret_target_function_role() {
+        let target: Key = runtime::get_named_arg("target");
+        let selector: String = runtime::get_named_arg("selector");
+
+        ret(AccessManager::get_target_function_role(target, selector))
+    }
+
+    /// Sets the role required to call `selector` on `target`. Only callable by the admins.
+    pub fn set_target_function_role() {
+        let target: Key = runtime::get_named_arg("target");
+        let selector: String = runtime::get_named_arg("selector");
+        let role: U256 = runtime::get_named_arg("role");
+
+        AccessControl::check_only_role(AccessControl::DEFAULT_ADMIN_ROLE);
+
+        AccessManager::function_roles().set(&(target, selector.clone()), role);
+
+        AccessManager::emit(&AccessManagerEvent::TargetFunctionRoleUpdated {
+            target,
+            selector,
+            role,
+        });
+    }
+
+    /// Returns the time members of `role` have to wait between scheduling a call and making
+    /// it, in milliseconds.
+    pub fn get_role_execution_delay(role: U256) -> u64 {
+        AccessManager::role_delays().get_or_default(&role)
+    }
+
+    pub fn ret_role_execution_delay() {
+        let role: U256 = runtime::get_named_arg("role");
+
+        ret(AccessManager::get_role_execution_delay(role))
+    }
+
+    /// Sets the execution delay of `role`. Only callable by the admins.
+    pub fn set_role_execution_delay() {
+        let role: U256 = runtime::get_named_arg("role");
+        let delay: u64 = runtime::get_named_arg("delay");
+
+        AccessControl::check_only_role(AccessControl::DEFAULT_ADMIN_ROLE);
+
+        AccessManager::role_delays().set(&role, delay);
+
+        AccessManager::emit(&AccessManagerEvent::RoleExecutionDelayUpdated { role, delay });
+    }
+
+    /// Returns whether `caller` can call `selector` on `target` right away and, if not, the
+    /// delay it has to schedule the call with, `0` when it cannot call it at all.
+    pub fn can_call(caller: Key, target: Key, selector: String) -> (bool, u64) {
+        let role = AccessManager::get_target_function_role(target, selector);
+        if role == AccessManager::PUBLIC_ROLE {
+            return (true, 0);
+        }
+        if !AccessControl::has_role(role, caller) {
+            return (false, 0);
+        }
+        let delay = AccessManager::get_role_execution_delay(role);
+        (delay == 0, delay)
+    }
+
+    pub fn ret_can_call() {
+        let caller: Key = runtime::get_named_arg("caller");
+        let target: Key = runtime::get_named_arg("target");
+        let selector: String = runtime::get_named_arg("selector");
+
+        ret(AccessManager::can_call(caller, target, selector))
+    }
+
+    /// Returns the time from which `caller` can make its scheduled call, `0` if none is
+    /// scheduled.
+    pub fn get_schedule(caller: Key, target: Key, selector: String) -> u64 {
+        AccessManager::schedules().get_or_default(&(caller, target, selector))
+    }
+
+    pub fn ret_schedule() {
+        let caller: Key = runtime::get_named_arg("caller");
+        let target: Key = runtime::get_named_arg("target");
+        let selector: String = runtime::get_named_arg("selector");
+
+        ret(AccessManager::get_schedule(caller, target, selector))
+    }
+
+    /// Schedules a call of the caller to `selector` on `target` and returns the time from which
+    /// it can be made.
+    pub fn schedule() {
+        let caller = get_caller();
+        let target: Key = runtime::get_named_arg("target");
+        let selector: String = runtime::get_named_arg("selector");
+
+        let (_, delay) = AccessManager::can_call(caller, target, selector.clone());
+        if delay == 0 {
+            runtime::revert(AccessManagerError::UnauthorizedCall);
+        }
+
+        let timepoint = u64::from(runtime::get_blocktime())
+            .checked_add(delay)
+            .ok_or(Error::Overflow)
+            .unwrap_or_revert();
+        AccessManager::schedules().set(&(caller, target, selector.clone()), timepoint);
+
+        AccessManager::emit(&AccessManagerEvent::OperationScheduled {
+            caller,
+            target,
+            selector,
+            timepoint,
+        });
+        ret(timepoint)
+    }
+
+    /// Consumes the call of `caller` to `selector` scheduled on the calling contract, reverting
+    /// if none is scheduled or it cannot be made yet.
+    pub fn consume_scheduled_op() {
+        let target = get_caller();
+        let caller: Key = runtime::get_named_arg("caller");
+        let selector: String = runtime::get_named_arg("selector");
+
+        let timepoint = AccessManager::get_schedule(caller, target, selector.clone());
+        if timepoint == 0 {
+            runtime::revert(AccessManagerError::NotScheduled);
+        }
+        if u64::from(runtime::get_blocktime()) < timepoint {
+            runtime::revert(AccessManagerError::NotReady);
+        }
+        AccessManager::schedules().set(&(caller, target, selector.clone()), 0);
+
+        AccessManager::emit(&AccessManagerEvent::OperationExecuted {
+            caller,
+            target,
+            selector,
+        });
+    }
+}
+
+struct AccessManagedEntryPoints {}
+
+impl AccessManagedEntryPoints {
+    /// Returns the `authority` entry point.
+    pub fn authority() -> EntryPoint {
+        endpoint("authority", vec![], CLType::Key)
+    }
+}
+
+impl AccessManaged {
+    pub const AUTHORITY_KEY: &'static str = "authority";
+
+    pub fn set_entry_points(current_entry_points: &mut EntryPoints) -> &EntryPoints {
+        current_entry_points.add_entry_point(AccessManagedEntryPoints::authority());
+
+        current_entry_points
+    }
+
+    /// Returns the access manager contract deciding who can call the restricted entry points.
+    pub fn authority() -> ContractHash {
+        let authority: Key = Variable::new(AccessManaged::AUTHORITY_KEY)
+            .get()
+            .unwrap_or_revert();
+        ContractHash::new(authority.into_hash().unwrap_or_revert())
+    }
+
+    pub fn ret_authority() {
+        ret(Key::from(AccessManaged::authority()))
+    }
+
+    /// Reverts unless the authority lets the caller call `selector`, consuming its scheduled
+    /// call if the role it holds has an execution delay. To be invoked at the start of every
+    /// restricted entry point.
+    pub fn check_can_call(selector: &str) {
+        let authority = AccessManaged::authority();
+        let caller = get_caller();
+
+        let (immediate, delay): (bool, u64) = runtime::call_contract(
+            authority,
+            "can_call",
+            runtime_args! {
+                "caller" => caller,
+                "target" => Key::from(contract_package_hash()),
+                "selector" => selector.to_string()
+            },
+        );
+        if immediate {
+            return;
+        }
+        if delay == 0 {
+            runtime::revert(AccessManagerError::UnauthorizedCall);
+        }
+        runtime::call_contract::<()>(
+            authority,
+            "consume_scheduled_op",
+            runtime_args! {
+                "caller" => caller,
+                "selector" => selector.to_string()
+            },
+        );
+    }
+}
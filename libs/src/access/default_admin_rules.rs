@@ -0,0 +1,368 @@
+use contract::{
+    contract_api::{
+        runtime,
+        storage::{self},
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use std::collections::BTreeMap;
+use types::{account::AccountHash, CLType, EntryPoint, EntryPoints, Key, Parameter, URef, U256};
+
+use crate::{
+    access::{error::AccessControlError, AccessControl, AccessControlDefaultAdminRules},
+    error::Error,
+    storage::Variable,
+    utils::{contract_package_hash, endpoint, get_caller, is_holder_key, ret},
+};
+
+struct DefaultAdminRulesEntryPoints {}
+
+impl DefaultAdminRulesEntryPoints {
+    /// Returns the `default_admin` entry point.
+    pub fn default_admin() -> EntryPoint {
+        endpoint("default_admin", vec![], CLType::Key)
+    }
+
+    /// Returns the `pending_default_admin` entry point.
+    pub fn pending_default_admin() -> EntryPoint {
+        endpoint(
+            "pending_default_admin",
+            vec![],
+            CLType::Tuple2([Box::new(CLType::Key), Box::new(CLType::U64)]),
+        )
+    }
+
+    /// Returns the `default_admin_delay` entry point.
+    pub fn default_admin_delay() -> EntryPoint {
+        endpoint("default_admin_delay", vec![], CLType::U64)
+    }
+
+    /// Returns the `begin_default_admin_transfer` entry point.
+    pub fn begin_default_admin_transfer() -> EntryPoint {
+        endpoint(
+            "begin_default_admin_transfer",
+            vec![Parameter::new("new_admin", CLType::Key)],
+            CLType::Unit,
+        )
+    }
+
+    /// Returns the `accept_default_admin_transfer` entry point.
+    pub fn accept_default_admin_transfer() -> EntryPoint {
+        endpoint("accept_default_admin_transfer", vec![], CLType::Unit)
+    }
+
+    /// Returns the `cancel_default_admin_transfer` entry point.
+    pub fn cancel_default_admin_transfer() -> EntryPoint {
+        endpoint("cancel_default_admin_transfer", vec![], CLType::Unit)
+    }
+}
+
+pub enum DefaultAdminRulesEvent {
+    DefaultAdminTransferScheduled {
+        new_admin: Key,
+        accept_schedule: u64,
+    },
+    DefaultAdminTransferCanceled {},
+    DefaultAdminTransferred {
+        old_admin: Key,
+        new_admin: Key,
+    },
+}
+
+impl DefaultAdminRulesEvent {
+    pub fn type_name(&self) -> String {
+        match self {
+            DefaultAdminRulesEvent::DefaultAdminTransferScheduled {
+                new_admin: _,
+                accept_schedule: _,
+            } => "default_admin_transfer_scheduled",
+            DefaultAdminRulesEvent::DefaultAdminTransferCanceled {} => {
+                "default_admin_transfer_canceled"
+            }
+            DefaultAdminRulesEvent::DefaultAdminTransferred {
+                old_admin: _,
+                new_admin: _,
+            } => "default_admin_transferred",
+        }
+        .to_string()
+    }
+}
+
+/// Rules enforced on top of [`AccessControl`] for the `DEFAULT_ADMIN_ROLE`:
+/// * it is held by a single account, the default admin.
+/// * it is only transferred in two steps, the new admin accepting the transfer once the default
+/// admin delay passed.
+/// * it cannot be granted or revoked through the regular entry points, and is only renounced
+/// after scheduling a transfer to the zero address.
+///
+/// Contracts using these rules export [`AccessControlDefaultAdminRules::grant_role`],
+/// [`AccessControlDefaultAdminRules::revoke_role`] and the like in place of the
+/// [`AccessControl`] ones.
+impl AccessControlDefaultAdminRules {
+    pub const DEFAULT_ADMIN_KEY: &'static str = "_default_admin";
+    pub const DEFAULT_ADMIN_DELAY_KEY: &'static str = "_default_admin_delay";
+    pub const PENDING_DEFAULT_ADMIN_KEY: &'static str = "_pending_default_admin";
+    pub const PENDING_DEFAULT_ADMIN_SCHEDULE_KEY: &'static str = "_pending_default_admin_schedule";
+
+    pub fn set_entry_points(current_entry_points: &mut EntryPoints) -> &EntryPoints {
+        current_entry_points.add_entry_point(DefaultAdminRulesEntryPoints::default_admin());
+        current_entry_points.add_entry_point(DefaultAdminRulesEntryPoints::pending_default_admin());
+        current_entry_points.add_entry_point(DefaultAdminRulesEntryPoints::default_admin_delay());
+        current_entry_points
+            .add_entry_point(DefaultAdminRulesEntryPoints::begin_default_admin_transfer());
+        current_entry_points
+            .add_entry_point(DefaultAdminRulesEntryPoints::accept_default_admin_transfer());
+        current_entry_points
+            .add_entry_point(DefaultAdminRulesEntryPoints::cancel_default_admin_transfer());
+
+        current_entry_points
+    }
+
+    pub fn emit(default_admin_rules_event: &DefaultAdminRulesEvent) {
+        let mut events = Vec::new();
+        let package = contract_package_hash();
+        match default_admin_rules_event {
+            DefaultAdminRulesEvent::DefaultAdminTransferScheduled {
+                new_admin,
+                accept_schedule,
+            } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_string());
+                event.insert("event_type", default_admin_rules_event.type_name());
+                event.insert("new_admin", new_admin.to_string());
+                event.insert("accept_schedule", accept_schedule.to_string());
+                events.push(event);
+            }
+            DefaultAdminRulesEvent::DefaultAdminTransferCanceled {} => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_string());
+                event.insert("event_type", default_admin_rules_event.type_name());
+                events.push(event);
+            }
+            DefaultAdminRulesEvent::DefaultAdminTransferred {
+                old_admin,
+                new_admin,
+            } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_string());
+                event.insert("event_type", default_admin_rules_event.type_name());
+                event.insert("old_admin", old_admin.to_string());
+                event.insert("new_admin", new_admin.to_string());
+                events.push(event);
+            }
+        };
+        for event in events {
+            let _: URef = storage::new_uref(event);
+        }
+    }
+
+    fn zero_admin() -> Key {
+        Key::Account(AccountHash::default())
+    }
+
+    fn default_admin_variable() -> Variable<Key> {
+        Variable::new(AccessControlDefaultAdminRules::DEFAULT_ADMIN_KEY)
+    }
+
+    fn pending_default_admin_variable() -> Variable<Key> {
+        Variable::new(AccessControlDefaultAdminRules::PENDING_DEFAULT_ADMIN_KEY)
+    }
+
+    fn pending_default_admin_schedule_variable() -> Variable<u64> {
+        Variable::new(AccessControlDefaultAdminRules::PENDING_DEFAULT_ADMIN_SCHEDULE_KEY)
+    }
+
+    /// Returns the single holder of the `DEFAULT_ADMIN_ROLE`, the zero address once renounced.
+    pub fn default_admin() -> Key {
+        AccessControlDefaultAdminRules::default_admin_variable()
+            .get()
+            .unwrap_or_else(AccessControlDefaultAdminRules::zero_admin)
+    }
+
+    pub fn ret_default_admin() {
+        ret(AccessControlDefaultAdminRules::default_admin())
+    }
+
+    /// Returns the pending default admin and the time from which it can accept the transfer,
+    /// `0` if no transfer is pending.
+    pub fn pending_default_admin() -> (Key, u64) {
+        (
+            AccessControlDefaultAdminRules::pending_default_admin_variable()
+                .get()
+                .unwrap_or_else(AccessControlDefaultAdminRules::zero_admin),
+            AccessControlDefaultAdminRules::pending_default_admin_schedule_variable()
+                .get_or_default(),
+        )
+    }
+
+    pub fn ret_pending_default_admin() {
+        ret(AccessControlDefaultAdminRules::pending_default_admin())
+    }
+
+    /// Returns the time between the start of a default admin transfer and its acceptance, in
+    /// milliseconds.
+    pub fn default_admin_delay() -> u64 {
+        Variable::new(AccessControlDefaultAdminRules::DEFAULT_ADMIN_DELAY_KEY).get_or_default()
+    }
+
+    pub fn ret_default_admin_delay() {
+        ret(AccessControlDefaultAdminRules::default_admin_delay())
+    }
+
+    /// Starts transferring the `DEFAULT_ADMIN_ROLE` to `new_admin`, replacing any pending
+    /// transfer. Only callable by the default admin.
+    ///
+    /// A transfer to the zero address lets the default admin renounce the role once accepted.
+    pub fn begin_default_admin_transfer() {
+        let new_admin: Key = runtime::get_named_arg("new_admin");
+
+        AccessControl::check_only_role(AccessControl::DEFAULT_ADMIN_ROLE);
+        if !is_holder_key(&new_admin) {
+            runtime::revert(Error::InvalidKeyVariant);
+        }
+
+        let accept_schedule = u64::from(runtime::get_blocktime())
+            .checked_add(AccessControlDefaultAdminRules::default_admin_delay())
+            .ok_or(Error::Overflow)
+            .unwrap_or_revert();
+        AccessControlDefaultAdminRules::_set_pending_default_admin(new_admin, accept_schedule);
+
+        AccessControlDefaultAdminRules::emit(
+            &DefaultAdminRulesEvent::DefaultAdminTransferScheduled {
+                new_admin,
+                accept_schedule,
+            },
+        );
+    }
+
+    /// Completes the pending default admin transfer. Only callable by the pending default admin
+    /// once the default admin delay passed.
+    pub fn accept_default_admin_transfer() {
+        let (new_admin, accept_schedule) = AccessControlDefaultAdminRules::pending_default_admin();
+        if accept_schedule == 0 || new_admin != get_caller() {
+            runtime::revert(AccessControlError::InvalidDefaultAdmin);
+        }
+        AccessControlDefaultAdminRules::check_schedule_passed(accept_schedule);
+
+        let old_admin = AccessControlDefaultAdminRules::default_admin();
+        AccessControl::_revoke_role(AccessControl::DEFAULT_ADMIN_ROLE, old_admin);
+        AccessControl::_grant_role(AccessControl::DEFAULT_ADMIN_ROLE, new_admin);
+        AccessControlDefaultAdminRules::_transfer_default_admin(old_admin, new_admin);
+    }
+
+    /// Cancels the pending default admin transfer. Only callable by the default admin.
+    pub fn cancel_default_admin_transfer() {
+        AccessControl::check_only_role(AccessControl::DEFAULT_ADMIN_ROLE);
+
+        AccessControlDefaultAdminRules::_set_pending_default_admin(
+            AccessControlDefaultAdminRules::zero_admin(),
+            0,
+        );
+
+        AccessControlDefaultAdminRules::emit(
+            &DefaultAdminRulesEvent::DefaultAdminTransferCanceled {},
+        );
+    }
+
+    /// Same as [`AccessControl::grant_role`], refusing to grant the `DEFAULT_ADMIN_ROLE`.
+    pub fn grant_role() {
+        AccessControlDefaultAdminRules::check_not_default_admin_role();
+        AccessControl::grant_role();
+    }
+
+    /// Same as [`AccessControl::grant_role_until`], refusing to grant the `DEFAULT_ADMIN_ROLE`.
+    pub fn grant_role_until() {
+        AccessControlDefaultAdminRules::check_not_default_admin_role();
+        AccessControl::grant_role_until();
+    }
+
+    /// Same as [`AccessControl::revoke_role`], refusing to revoke the `DEFAULT_ADMIN_ROLE`.
+    pub fn revoke_role() {
+        AccessControlDefaultAdminRules::check_not_default_admin_role();
+        AccessControl::revoke_role();
+    }
+
+    /// Same as [`AccessControl::grant_roles`], refusing to grant the `DEFAULT_ADMIN_ROLE`.
+    pub fn grant_roles() {
+        AccessControlDefaultAdminRules::check_not_default_admin_role();
+        AccessControl::grant_roles();
+    }
+
+    /// Same as [`AccessControl::revoke_roles`], refusing to revoke the `DEFAULT_ADMIN_ROLE`.
+    pub fn revoke_roles() {
+        AccessControlDefaultAdminRules::check_not_default_admin_role();
+        AccessControl::revoke_roles();
+    }
+
+    /// Same as [`AccessControl::grant_role_batch`], refusing to grant the `DEFAULT_ADMIN_ROLE`.
+    pub fn grant_role_batch() {
+        let grants: Vec<(U256, Key)> = runtime::get_named_arg("grants");
+        if grants
+            .iter()
+            .any(|(role, _)| *role == AccessControl::DEFAULT_ADMIN_ROLE)
+        {
+            runtime::revert(AccessControlError::EnforcedDefaultAdminRules);
+        }
+        AccessControl::grant_role_batch();
+    }
+
+    /// Same as [`AccessControl::set_role_admin`], refusing to change the admin of the
+    /// `DEFAULT_ADMIN_ROLE`.
+    pub fn set_role_admin() {
+        AccessControlDefaultAdminRules::check_not_default_admin_role();
+        AccessControl::set_role_admin();
+    }
+
+    /// Same as [`AccessControl::renounce_role`]. The default admin can only renounce the
+    /// `DEFAULT_ADMIN_ROLE` after a transfer to the zero address passed its delay.
+    pub fn renounce_role() {
+        let role: U256 = runtime::get_named_arg("role");
+        let account: Key = runtime::get_named_arg("account");
+
+        if role == AccessControl::DEFAULT_ADMIN_ROLE
+            && account == AccessControlDefaultAdminRules::default_admin()
+        {
+            let (new_admin, accept_schedule) =
+                AccessControlDefaultAdminRules::pending_default_admin();
+            if accept_schedule == 0 || new_admin != AccessControlDefaultAdminRules::zero_admin() {
+                runtime::revert(AccessControlError::EnforcedDefaultAdminRules);
+            }
+            AccessControlDefaultAdminRules::check_schedule_passed(accept_schedule);
+            AccessControlDefaultAdminRules::_transfer_default_admin(account, new_admin);
+        }
+
+        AccessControl::renounce_role();
+    }
+
+    fn check_not_default_admin_role() {
+        let role: U256 = runtime::get_named_arg("role");
+        if role == AccessControl::DEFAULT_ADMIN_ROLE {
+            runtime::revert(AccessControlError::EnforcedDefaultAdminRules);
+        }
+    }
+
+    fn check_schedule_passed(accept_schedule: u64) {
+        if u64::from(runtime::get_blocktime()) < accept_schedule {
+            runtime::revert(AccessControlError::EnforcedDefaultAdminDelay);
+        }
+    }
+
+    fn _set_pending_default_admin(new_admin: Key, accept_schedule: u64) {
+        AccessControlDefaultAdminRules::pending_default_admin_variable().set(new_admin);
+        AccessControlDefaultAdminRules::pending_default_admin_schedule_variable()
+            .set(accept_schedule);
+    }
+
+    fn _transfer_default_admin(old_admin: Key, new_admin: Key) {
+        AccessControlDefaultAdminRules::default_admin_variable().set(new_admin);
+        AccessControlDefaultAdminRules::_set_pending_default_admin(
+            AccessControlDefaultAdminRules::zero_admin(),
+            0,
+        );
+
+        AccessControlDefaultAdminRules::emit(&DefaultAdminRulesEvent::DefaultAdminTransferred {
+            old_admin,
+            new_admin,
+        });
+    }
+}
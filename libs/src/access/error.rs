@@ -0,0 +1,39 @@
+use crate::error::module_error;
+
+module_error! {
+    /// Errors returned by the Ownable module.
+    pub enum OwnableError: 65200..=65299 {
+        /// Caller is not the owner of the contract.
+        CallerNotOwner = 0,
+    }
+}
+
+module_error! {
+    /// Errors returned by the AccessControl module.
+    pub enum AccessControlError: 65300..=65399 {
+        /// Account is missing the role required by the entry point.
+        MissingRole = 0,
+        /// Roles can only be renounced by the account holding them.
+        CanOnlyRenounceForSelf = 1,
+        /// Expiry of a time-bounded role grant is not in the future.
+        ExpiryInPast = 2,
+        /// The `DEFAULT_ADMIN_ROLE` can only be transferred through a default admin transfer.
+        EnforcedDefaultAdminRules = 3,
+        /// Caller is not the pending default admin.
+        InvalidDefaultAdmin = 4,
+        /// Default admin delay of the pending transfer did not pass yet.
+        EnforcedDefaultAdminDelay = 5,
+    }
+}
+
+module_error! {
+    /// Errors returned by the AccessManager module.
+    pub enum AccessManagerError: 64600..=64699 {
+        /// Caller is not allowed to call the entry point.
+        UnauthorizedCall = 0,
+        /// Caller has no call scheduled for the entry point.
+        NotScheduled = 1,
+        /// Scheduled call cannot be made yet.
+        NotReady = 2,
+    }
+}
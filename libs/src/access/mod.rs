@@ -1,5 +1,11 @@
 pub mod access_control;
+pub mod access_manager;
+pub mod default_admin_rules;
+pub mod error;
 pub mod ownable;
 
 pub struct Ownable {}
 pub struct AccessControl {}
+pub struct AccessControlDefaultAdminRules {}
+pub struct AccessManager {}
+pub struct AccessManaged {}
@@ -6,9 +6,12 @@ use std::collections::BTreeMap;
 use types::{account::AccountHash, CLType, EntryPoint, EntryPoints, Key, Parameter, URef};
 
 use crate::{
-    access::Ownable,
-    error::Error,
-    utils::{contract_package_hash, endpoint, get_caller, get_optional_key, ret, set_key},
+    access::{error::OwnableError, Ownable},
+    introspection::{interface_id, register_interface},
+    utils::{
+        authorized_caller, contract_package_hash, endpoint, get_optional_key, require_holder_key,
+        ret, set_key,
+    },
 };
 
 struct OwnableEntryPoints {}
@@ -52,13 +55,28 @@ impl OwnableEvent {
 
 impl Ownable {
     pub fn set_entry_points(current_entry_points: &mut EntryPoints) -> &EntryPoints {
-        current_entry_points.add_entry_point(OwnableEntryPoints::owner());
-        current_entry_points.add_entry_point(OwnableEntryPoints::transfer_ownership());
-        current_entry_points.add_entry_point(OwnableEntryPoints::renounce_ownership());
+        let entry_points = Ownable::entry_points();
+        register_interface(&entry_points);
+        for entry_point in entry_points {
+            current_entry_points.add_entry_point(entry_point);
+        }
 
         current_entry_points
     }
 
+    /// Returns the entry points making up the Ownable interface.
+    pub fn entry_points() -> Vec<EntryPoint> {
+        vec![
+            OwnableEntryPoints::owner(),
+            OwnableEntryPoints::transfer_ownership(),
+            OwnableEntryPoints::renounce_ownership(),
+        ]
+    }
+
+    pub fn interface_id() -> u32 {
+        interface_id(&Ownable::entry_points())
+    }
+
     pub fn emit(ownable_event: &OwnableEvent) {
         let mut events = Vec::new();
         let package = contract_package_hash();
@@ -91,6 +109,7 @@ impl Ownable {
 
     pub fn transfer_ownership() {
         let new_owner: Key = runtime::get_named_arg("new_owner");
+        require_holder_key(&new_owner);
 
         Ownable::_transfer_ownership(new_owner, true);
     }
@@ -100,16 +119,16 @@ impl Ownable {
     }
 
     pub fn check_only_owner() {
-        if Ownable::owner() != get_caller() {
-            runtime::revert(Error::InvalidPermission);
+        if Ownable::owner() != authorized_caller() {
+            runtime::revert(OwnableError::CallerNotOwner);
         }
     }
 
     fn _transfer_ownership(new_owner: Key, check_permission: bool) {
         let old_owner = Ownable::owner();
 
-        if check_permission && old_owner != get_caller() {
-            runtime::revert(Error::InvalidPermission);
+        if check_permission && old_owner != authorized_caller() {
+            runtime::revert(OwnableError::CallerNotOwner);
         }
         set_key("owner", new_owner);
 
@@ -1,152 +1,241 @@
 //! Error handling on the casper platform.
+//!
+//! Every module of the library defines its own error type, converted to an [`ApiError::User`]
+//! within a range of 100 codes reserved for that module:
+//! * `[0, 64499]` - user errors, see [`Error::User`].
+//! * `[64500, 64599]` - [`Erc721Error`].
+//! * `[64600, 64699]` - [`AccessManagerError`].
+//! * `[64700, 64799]` - [`PaymentSplitterError`].
+//! * `[64800, 64899]` - [`TokenTimelockError`].
+//! * `[64900, 64999]` - [`VestingWalletError`].
+//! * `[65000, 65099]` - [`MerkleAirdropError`].
+//! * `[65100, 65199]` - [`Erc20Error`].
+//! * `[65200, 65299]` - [`OwnableError`].
+//! * `[65300, 65399]` - [`AccessControlError`].
+//! * `[65400, 65499]` - [`MigrationError`].
+//! * `[65500, 65535]` - [`Error`], shared by every module.
+//!
+//! A new module takes the 100 codes right below the lowest range, which [`USER_ERROR_MAX`] is
+//! lowered to make room for. Ranges are listed in [`ERROR_RANGES`], which is checked for
+//! overlaps at compile time, and [`error_name`] maps a code back to the name of the variant it
+//! belongs to.
 use types::ApiError;
 
-/// Errors which can be returned by the library.
+use crate::{
+    access::error::{AccessControlError, AccessManagerError, OwnableError},
+    finance::error::{PaymentSplitterError, TokenTimelockError, VestingWalletError},
+    migration::MigrationError,
+    token::{
+        erc20::error::Erc20Error, erc721::error::Erc721Error,
+        merkle_airdrop::error::MerkleAirdropError,
+    },
+};
+
+/// Range of `ApiError::User` codes reserved for an error type, bounds included.
+pub struct ErrorRange {
+    pub name: &'static str,
+    pub start: u16,
+    pub end: u16,
+}
+
+/// Defines a module error type whose variants are converted to `ApiError::User(start + offset)`.
 ///
-/// When an `Error` is returned from a smart contract, it is converted to an [`ApiError::User`].
+/// The offsets are given explicitly so that adding a variant never renumbers the existing ones,
+/// and every resulting code is checked at compile time to fall within `[start, end]`.
+macro_rules! module_error {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident: $start:literal..=$end:literal {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $offset:literal,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[repr(u16)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant = $offset,
+            )*
+        }
+
+        impl $name {
+            pub const RANGE: $crate::error::ErrorRange = $crate::error::ErrorRange {
+                name: stringify!($name),
+                start: $start,
+                end: $end,
+            };
+
+            pub const VARIANTS: &'static [$name] = &[$($name::$variant,)*];
+
+            pub fn code(self) -> u16 {
+                $start + self as u16
+            }
+
+            pub fn from_code(code: u16) -> Option<$name> {
+                $name::VARIANTS
+                    .iter()
+                    .copied()
+                    .find(|variant| variant.code() == code)
+            }
+
+            pub fn name(self) -> &'static str {
+                match self {
+                    $($name::$variant => concat!(stringify!($name), "::", stringify!($variant)),)*
+                }
+            }
+        }
+
+        // Every variant has to fit within the error range.
+        const _: () = {
+            $(assert!($offset <= $end - $start);)*
+        };
+
+        impl From<$name> for types::ApiError {
+            fn from(error: $name) -> Self {
+                types::ApiError::User(error.code())
+            }
+        }
+    };
+}
+
+pub(crate) use module_error;
+
+/// Errors shared by every module of the library.
 ///
 /// Where a smart contract consuming this library needs to define further error variants, it can
 /// return those via the [`Error::User`] variant or equivalently via the [`ApiError::User`]
 /// variant.
 ///
-/// Such a user error should be in the range `[0..(u16::MAX - 30)]` (i.e. [0, 65505]) to avoid
-/// conflicting with the other `Error` variants.
+/// Such a user error should be in the range `[0..(USER_ERROR_MAX)]` (i.e. [0, 64499]) to avoid
+/// conflicting with the error types of the library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Error {
-    /// Caller does not have permission
-    InvalidPermission,
-    /// Spender does not have enough balance.
+    /// The call stack does not contain the expected caller.
     InvalidContext,
-    /// Spender does not have enough allowance approved.
-    InsufficientAllowance,
     /// Operation would cause an integer overflow.
     Overflow,
-    /// Tokens addresses are identical.
-    IdenticalAddresses,
-    /// Tokens address is null.
+    /// Address is null.
     ZeroAddress,
-    /// Insufficient balance
-    InsufficientBalance,
-    /// Input amount for the swap is null.
-    InsufficientInputAmount,
-    /// Output amount for the swap is null.
-    InsufficientOutputAmount,
-    /// Given amount is null.
-    InsufficientAmount,
-    /// Path from the two tokens is inferior to 2.
-    InvalidPath,
-    /// Deadline Expired
-    Expired,
-    /// The amount of the A token is inferior to the minimum amount requested by the provider.
-    InsufficientAAmount,
-    /// The amount of the B token is inferior to the minimum amount requested by the provider.
-    InsufficientBAmount,
-    /// The input amount required for the swap surpasses the amount_in_max.
-    ExcessiveInputAmount,
-    /// Caller tries to withdraw more CSPR than his WCSPR balance.
-    ExcessiveAmount,
-    /// Tried to create a pair that already exists.
-    PairExists,
-    /// The caller is not authorized to call the function.
-    Forbidden,
-    /// The liquidity minted inside `mint()` equals zero.
-    InsufficientLiquidityMinted,
-    /// The liquidity that is set to be burned inside `burn()` equals zero.
-    InsufficientLiquidityBurned,
     /// Tried to call a locked contract's function.
     Locked,
-    /// Calling Pair's swap operation while `to` is one of the pair's tokens addresses.
-    InvalidTo,
-    /// In Pair's swap(): When the pair tokens' balances product is inferior than the reserves product multiplied by 1000^2.
-    K,
-    /// The given signature for permit() is erronous.
-    InvalidSignature,
-    /// Cannot mint tokens to zero hash address.
-    CannotMintToZeroHash,
-    /// Cannot burn tokens from zero hash address.
-    CannotBurnFromZeroHash,
-    /// Trying to burn an amount that surpasses the owner's balance.
-    BurnAmountExceedsBalance,
-    /// At leaset one of the pair's token reserves equals zero.
-    NoReserves,
-    /// Trying to call `simple-oracle::update` before `period` has elapsed since the last update.
-    PeriodNotElapsed,
-    /// Trying to call `simple-oracle::consult` while providing an invalid token address.
-    InvalidToken,
-    /// Trying to deploy a payment contract while providing an invalid deposit entry_point name.
-    InvalidDepositEntryPointName,
+    /// Key variant cannot hold tokens, ownership or roles.
+    InvalidKeyVariant,
     /// User error.
     User(u16),
 }
 
-// u16::MAX = 65535
-const ERROR_INVALID_PERMISSION: u16 = u16::MAX; // 65535
-const ERROR_INVALID_CONTEXT: u16 = u16::MAX - 1; // 65534
-const ERROR_INSUFFICIENT_ALLOWANCE: u16 = u16::MAX - 2; // 65533
-const ERROR_OVERFLOW: u16 = u16::MAX - 3; // 65532
-const ERROR_IDENTICAL_ADDRESSES: u16 = u16::MAX - 4; // 65531
-const ERROR_ZERO_ADDRESS: u16 = u16::MAX - 5; // 65530
-const ERROR_INSUFFICIENT_BALANCE: u16 = u16::MAX - 6; // 65529
-const ERROR_INSUFFICIENT_INPUT_AMOUNT: u16 = u16::MAX - 7; // 65528
-const ERROR_INSUFFICIENT_OUTPUT_AMOUNT: u16 = u16::MAX - 8; // 65527
-const ERROR_INSUFFICIENT_AMOUNT: u16 = u16::MAX - 9; // 65526
-const ERROR_INVALID_PATH: u16 = u16::MAX - 10; // 65525
-const ERROR_EXPIRED: u16 = u16::MAX - 11; // 65524
-const ERROR_INSUFFICIENT_A_AMOUNT: u16 = u16::MAX - 12; // 65523
-const ERROR_INSUFFICIENT_B_AMOUNT: u16 = u16::MAX - 13; // 65522
-const ERROR_EXCESSIVE_INPUT_AMOUNT: u16 = u16::MAX - 14; // 65521
-const ERROR_EXCESSIVE_AMOUNT: u16 = u16::MAX - 15; // 65520
-const ERROR_PAIR_EXISTS: u16 = u16::MAX - 16; // 65519
-const ERROR_FORBIDDEN: u16 = u16::MAX - 17; // 65518
-const ERROR_INSUFFICIENT_LIQUIDITY_MINTED: u16 = u16::MAX - 18; // 65517
-const ERROR_INSUFFICIENT_LIQUIDITY_BURNED: u16 = u16::MAX - 19; // 65516
-const ERROR_LOCKED: u16 = u16::MAX - 20; // 65515
-const ERROR_INVALID_TO: u16 = u16::MAX - 21; // 65514
-const ERROR_K: u16 = u16::MAX - 22; // 65513
-const ERROR_INVALID_SIGNATURE: u16 = u16::MAX - 23; // 65512
-const ERROR_CANNOT_MINT_TO_ZERO_HASH: u16 = u16::MAX - 24; // 65511
-const ERROR_CANNOT_BURN_FROM_ZERO_HASH: u16 = u16::MAX - 25; // 65510
-const ERROR_BURN_AMOUNT_EXCEEDS_BALANCE: u16 = u16::MAX - 26; // 65509
-const ERROR_NO_RESERVES: u16 = u16::MAX - 27; // 65508
-const ERROR_PERIOD_NOT_ELAPSED: u16 = u16::MAX - 28; // 65507
-const ERROR_INVALID_TOKEN: u16 = u16::MAX - 29; // 65506
-const ERROR_INVALID_DEPOSIT_ENTRY_POINT_NAME: u16 = u16::MAX - 30; // 65505
+pub const USER_ERROR_MAX: u16 = 64499;
+
+impl Error {
+    pub const RANGE: ErrorRange = ErrorRange {
+        name: "Error",
+        start: 65500,
+        end: u16::MAX,
+    };
+
+    const VARIANTS: &'static [Error] = &[
+        Error::InvalidContext,
+        Error::Overflow,
+        Error::ZeroAddress,
+        Error::Locked,
+        Error::InvalidKeyVariant,
+    ];
+
+    pub fn code(self) -> u16 {
+        match self {
+            Error::InvalidContext => Error::RANGE.start,
+            Error::Overflow => Error::RANGE.start + 1,
+            Error::ZeroAddress => Error::RANGE.start + 2,
+            Error::Locked => Error::RANGE.start + 3,
+            Error::InvalidKeyVariant => Error::RANGE.start + 4,
+            Error::User(user_error) => user_error,
+        }
+    }
+
+    pub fn from_code(code: u16) -> Option<Error> {
+        Error::VARIANTS
+            .iter()
+            .copied()
+            .find(|variant| variant.code() == code)
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Error::InvalidContext => "Error::InvalidContext",
+            Error::Overflow => "Error::Overflow",
+            Error::ZeroAddress => "Error::ZeroAddress",
+            Error::Locked => "Error::Locked",
+            Error::InvalidKeyVariant => "Error::InvalidKeyVariant",
+            Error::User(_) => "Error::User",
+        }
+    }
+}
 
 impl From<Error> for ApiError {
     fn from(error: Error) -> Self {
-        let user_error = match error {
-            Error::InvalidPermission => ERROR_INVALID_PERMISSION,
-            Error::InvalidContext => ERROR_INVALID_CONTEXT,
-            Error::InsufficientAllowance => ERROR_INSUFFICIENT_ALLOWANCE,
-            Error::Overflow => ERROR_OVERFLOW,
-            Error::IdenticalAddresses => ERROR_IDENTICAL_ADDRESSES,
-            Error::ZeroAddress => ERROR_ZERO_ADDRESS,
-            Error::InsufficientBalance => ERROR_INSUFFICIENT_BALANCE,
-            Error::InsufficientInputAmount => ERROR_INSUFFICIENT_INPUT_AMOUNT,
-            Error::InsufficientOutputAmount => ERROR_INSUFFICIENT_OUTPUT_AMOUNT,
-            Error::InsufficientAmount => ERROR_INSUFFICIENT_AMOUNT,
-            Error::InvalidPath => ERROR_INVALID_PATH,
-            Error::Expired => ERROR_EXPIRED,
-            Error::InsufficientAAmount => ERROR_INSUFFICIENT_A_AMOUNT,
-            Error::InsufficientBAmount => ERROR_INSUFFICIENT_B_AMOUNT,
-            Error::ExcessiveInputAmount => ERROR_EXCESSIVE_INPUT_AMOUNT,
-            Error::ExcessiveAmount => ERROR_EXCESSIVE_AMOUNT,
-            Error::PairExists => ERROR_PAIR_EXISTS,
-            Error::Forbidden => ERROR_FORBIDDEN,
-            Error::InsufficientLiquidityMinted => ERROR_INSUFFICIENT_LIQUIDITY_MINTED,
-            Error::InsufficientLiquidityBurned => ERROR_INSUFFICIENT_LIQUIDITY_BURNED,
-            Error::Locked => ERROR_LOCKED,
-            Error::InvalidTo => ERROR_INVALID_TO,
-            Error::K => ERROR_K,
-            Error::InvalidSignature => ERROR_INVALID_SIGNATURE,
-            Error::CannotMintToZeroHash => ERROR_CANNOT_MINT_TO_ZERO_HASH,
-            Error::CannotBurnFromZeroHash => ERROR_CANNOT_BURN_FROM_ZERO_HASH,
-            Error::BurnAmountExceedsBalance => ERROR_BURN_AMOUNT_EXCEEDS_BALANCE,
-            Error::NoReserves => ERROR_NO_RESERVES,
-            Error::PeriodNotElapsed => ERROR_PERIOD_NOT_ELAPSED,
-            Error::InvalidToken => ERROR_INVALID_TOKEN,
-            Error::InvalidDepositEntryPointName => ERROR_INVALID_DEPOSIT_ENTRY_POINT_NAME,
-            Error::User(user_error) => user_error,
-        };
-        ApiError::User(user_error)
+        ApiError::User(error.code())
+    }
+}
+
+/// Code ranges of every error type of the library, sorted by start code. See the
+/// [module documentation](self) for the map of the ranges.
+pub const ERROR_RANGES: &[ErrorRange] = &[
+    ErrorRange {
+        name: "User",
+        start: 0,
+        end: USER_ERROR_MAX,
+    },
+    Erc721Error::RANGE,
+    AccessManagerError::RANGE,
+    PaymentSplitterError::RANGE,
+    TokenTimelockError::RANGE,
+    VestingWalletError::RANGE,
+    MerkleAirdropError::RANGE,
+    Erc20Error::RANGE,
+    OwnableError::RANGE,
+    AccessControlError::RANGE,
+    MigrationError::RANGE,
+    Error::RANGE,
+];
+
+const fn ranges_overlap(ranges: &[ErrorRange]) -> bool {
+    let mut i = 0;
+    while i < ranges.len() {
+        if ranges[i].start > ranges[i].end {
+            return true;
+        }
+        if i > 0 && ranges[i - 1].end >= ranges[i].start {
+            return true;
+        }
+        i += 1;
     }
+    false
+}
+
+// Error code ranges have to be sorted and must not overlap.
+const _: () = assert!(!ranges_overlap(ERROR_RANGES));
+
+/// Returns the name of the library error variant an `ApiError` was converted from, e.g.
+/// `"Erc20Error::InsufficientBalance"`.
+pub fn error_name(error: ApiError) -> Option<&'static str> {
+    let code = match error {
+        ApiError::User(code) => code,
+        _ => return None,
+    };
+    Erc721Error::from_code(code)
+        .map(Erc721Error::name)
+        .or_else(|| AccessManagerError::from_code(code).map(AccessManagerError::name))
+        .or_else(|| PaymentSplitterError::from_code(code).map(PaymentSplitterError::name))
+        .or_else(|| TokenTimelockError::from_code(code).map(TokenTimelockError::name))
+        .or_else(|| VestingWalletError::from_code(code).map(VestingWalletError::name))
+        .or_else(|| MerkleAirdropError::from_code(code).map(MerkleAirdropError::name))
+        .or_else(|| Erc20Error::from_code(code).map(Erc20Error::name))
+        .or_else(|| OwnableError::from_code(code).map(OwnableError::name))
+        .or_else(|| AccessControlError::from_code(code).map(AccessControlError::name))
+        .or_else(|| MigrationError::from_code(code).map(MigrationError::name))
+        .or_else(|| Error::from_code(code).map(Error::name))
 }
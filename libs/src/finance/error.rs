@@ -0,0 +1,47 @@
+use crate::error::module_error;
+
+module_error! {
+    /// Errors returned by the VestingWallet module.
+    pub enum VestingWalletError: 64900..=64999 {
+        /// Vesting schedule has a cliff longer than its duration.
+        InvalidSchedule = 0,
+        /// Vesting wallet was not installed as revocable.
+        NotRevocable = 1,
+        /// Vesting wallet was already revoked.
+        AlreadyRevoked = 2,
+    }
+}
+
+module_error! {
+    /// Errors returned by the TokenTimelock module.
+    pub enum TokenTimelockError: 64800..=64899 {
+        /// No lock was created with the given id.
+        UnknownLock = 0,
+        /// Release time of the lock is not in the future.
+        ReleaseTimeInPast = 1,
+        /// Release time of the lock is not reached yet.
+        NotReleasable = 2,
+        /// Lock was already released.
+        AlreadyReleased = 3,
+    }
+}
+
+module_error! {
+    /// Errors returned by the PaymentSplitter module.
+    pub enum PaymentSplitterError: 64700..=64799 {
+        /// No payee was given at install.
+        NoPayees = 0,
+        /// Payees and shares given at install do not have the same length.
+        PayeesSharesMismatch = 1,
+        /// Payee was given no shares.
+        ZeroShares = 2,
+        /// Payee was given twice.
+        DuplicatePayee = 3,
+        /// Account is not a payee.
+        AccountHasNoShares = 4,
+        /// Account is not due any payment.
+        NoPaymentDue = 5,
+        /// Payee is not an account, which CSPR can't be released to.
+        NonAccountPayee = 6,
+    }
+}
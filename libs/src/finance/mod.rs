@@ -0,0 +1,8 @@
+pub mod error;
+pub mod payment_splitter;
+pub mod token_timelock;
+pub mod vesting_wallet;
+
+pub struct PaymentSplitter {}
+pub struct TokenTimelock {}
+pub struct VestingWallet {}
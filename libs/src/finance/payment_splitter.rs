@@ -0,0 +1,400 @@
+use contract::{
+    contract_api::{
+        runtime,
+        storage::{self},
+        system,
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use std::collections::BTreeMap;
+use types::{
+    contracts::NamedKeys, CLType, ContractHash, EntryPoint, EntryPoints, Key, Parameter, URef,
+    U256, U512,
+};
+
+use crate::{
+    error::Error,
+    finance::{error::PaymentSplitterError, PaymentSplitter},
+    storage::{Mapping, Variable},
+    token::erc20::ERC20,
+    utils::{contract_package_hash, endpoint, require_holder_key, ret},
+};
+
+struct PaymentSplitterEntryPoints {}
+
+impl PaymentSplitterEntryPoints {
+    /// Returns the `payees` entry point.
+    pub fn payees() -> EntryPoint {
+        endpoint("payees", vec![], CLType::List(Box::new(CLType::Key)))
+    }
+
+    /// Returns the `shares` entry point.
+    pub fn shares() -> EntryPoint {
+        endpoint(
+            "shares",
+            vec![Parameter::new("account", CLType::Key)],
+            CLType::U256,
+        )
+    }
+
+    /// Returns the `total_shares` entry point.
+    pub fn total_shares() -> EntryPoint {
+        endpoint("total_shares", vec![], CLType::U256)
+    }
+
+    /// Returns the `released` entry point.
+    pub fn released() -> EntryPoint {
+        endpoint(
+            "released",
+            vec![Parameter::new("account", CLType::Key)],
+            CLType::U512,
+        )
+    }
+
+    /// Returns the `total_released` entry point.
+    pub fn total_released() -> EntryPoint {
+        endpoint("total_released", vec![], CLType::U512)
+    }
+
+    /// Returns the `token_released` entry point.
+    pub fn token_released() -> EntryPoint {
+        endpoint(
+            "token_released",
+            vec![
+                Parameter::new("token", CLType::Key),
+                Parameter::new("account", CLType::Key),
+            ],
+            CLType::U256,
+        )
+    }
+
+    /// Returns the `release` entry point.
+    pub fn release() -> EntryPoint {
+        endpoint(
+            "release",
+            vec![Parameter::new("account", CLType::Key)],
+            CLType::Unit,
+        )
+    }
+
+    /// Returns the `release_token` entry point.
+    pub fn release_token() -> EntryPoint {
+        endpoint(
+            "release_token",
+            vec![
+                Parameter::new("token", CLType::Key),
+                Parameter::new("account", CLType::Key),
+            ],
+            CLType::Unit,
+        )
+    }
+}
+
+pub enum PaymentSplitterEvent {
+    PaymentReleased { to: Key, amount: U512 },
+    ERC20PaymentReleased { token: Key, to: Key, amount: U256 },
+}
+
+impl PaymentSplitterEvent {
+    pub fn type_name(&self) -> String {
+        match self {
+            PaymentSplitterEvent::PaymentReleased { to: _, amount: _ } => "payment_released",
+            PaymentSplitterEvent::ERC20PaymentReleased {
+                token: _,
+                to: _,
+                amount: _,
+            } => "erc20_payment_released",
+        }
+        .to_string()
+    }
+}
+
+impl PaymentSplitter {
+    pub const PURSE_KEY: &'static str = "purse";
+    pub const PAYEES_KEY: &'static str = "payees";
+    pub const SHARES_KEY: &'static str = "shares";
+    pub const TOTAL_SHARES_KEY: &'static str = "total_shares";
+    pub const RELEASED_KEY: &'static str = "released";
+    pub const TOTAL_RELEASED_KEY: &'static str = "total_released";
+    pub const TOKEN_RELEASED_KEY: &'static str = "token_released";
+    pub const TOKEN_TOTAL_RELEASED_KEY: &'static str = "token_total_released";
+
+    pub fn set_entry_points(current_entry_points: &mut EntryPoints) -> &EntryPoints {
+        current_entry_points.add_entry_point(PaymentSplitterEntryPoints::payees());
+        current_entry_points.add_entry_point(PaymentSplitterEntryPoints::shares());
+        current_entry_points.add_entry_point(PaymentSplitterEntryPoints::total_shares());
+        current_entry_points.add_entry_point(PaymentSplitterEntryPoints::released());
+        current_entry_points.add_entry_point(PaymentSplitterEntryPoints::total_released());
+        current_entry_points.add_entry_point(PaymentSplitterEntryPoints::token_released());
+        current_entry_points.add_entry_point(PaymentSplitterEntryPoints::release());
+        current_entry_points.add_entry_point(PaymentSplitterEntryPoints::release_token());
+
+        current_entry_points
+    }
+
+    pub fn emit(payment_splitter_event: &PaymentSplitterEvent) {
+        let mut events = Vec::new();
+        let package = contract_package_hash();
+        match payment_splitter_event {
+            PaymentSplitterEvent::PaymentReleased { to, amount } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_string());
+                event.insert("event_type", payment_splitter_event.type_name());
+                event.insert("to", to.to_string());
+                event.insert("amount", amount.to_string());
+                events.push(event);
+            }
+            PaymentSplitterEvent::ERC20PaymentReleased { token, to, amount } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_string());
+                event.insert("event_type", payment_splitter_event.type_name());
+                event.insert("token", token.to_string());
+                event.insert("to", to.to_string());
+                event.insert("amount", amount.to_string());
+                events.push(event);
+            }
+        };
+        for event in events {
+            let _: URef = storage::new_uref(event);
+        }
+    }
+
+    /// Returns the named keys of a payment splitter sharing the payments it receives between
+    /// `payees` according to their `shares`, and holding CSPR in a new purse. Payees have to be
+    /// accounts, as contracts can't be released CSPR.
+    ///
+    /// Meant to be called by the session code installing the contract: the dictionaries are
+    /// created in the current context and only handed over to the contract.
+    pub fn install_named_keys(payees: Vec<Key>, shares: Vec<U256>) -> NamedKeys {
+        if payees.is_empty() {
+            runtime::revert(PaymentSplitterError::NoPayees);
+        }
+        if payees.len() != shares.len() {
+            runtime::revert(PaymentSplitterError::PayeesSharesMismatch);
+        }
+
+        let shares_seed_uref =
+            storage::new_dictionary(PaymentSplitter::SHARES_KEY).unwrap_or_revert();
+        runtime::remove_key(PaymentSplitter::SHARES_KEY);
+
+        let mut total_shares = U256::zero();
+        for (index, (payee, share)) in payees.iter().zip(shares).enumerate() {
+            require_holder_key(payee);
+            if payee.into_account().is_none() {
+                runtime::revert(PaymentSplitterError::NonAccountPayee);
+            }
+            if share.is_zero() {
+                runtime::revert(PaymentSplitterError::ZeroShares);
+            }
+            if payees[..index].contains(payee) {
+                runtime::revert(PaymentSplitterError::DuplicatePayee);
+            }
+            total_shares = total_shares
+                .checked_add(share)
+                .ok_or(Error::Overflow)
+                .unwrap_or_revert();
+            storage::dictionary_put(
+                shares_seed_uref,
+                &Mapping::<Key, U256>::item_key(payee),
+                share,
+            );
+        }
+
+        let mut named_keys = NamedKeys::new();
+        named_keys.insert(
+            PaymentSplitter::PURSE_KEY.to_string(),
+            system::create_purse().into(),
+        );
+        named_keys.insert(
+            PaymentSplitter::PAYEES_KEY.to_string(),
+            storage::new_uref(payees).into(),
+        );
+        named_keys.insert(
+            PaymentSplitter::SHARES_KEY.to_string(),
+            shares_seed_uref.into(),
+        );
+        named_keys.insert(
+            PaymentSplitter::TOTAL_SHARES_KEY.to_string(),
+            storage::new_uref(total_shares).into(),
+        );
+        named_keys
+    }
+
+    fn shares_mapping() -> Mapping<Key, U256> {
+        Mapping::new(PaymentSplitter::SHARES_KEY)
+    }
+
+    fn released_mapping() -> Mapping<Key, U512> {
+        Mapping::new(PaymentSplitter::RELEASED_KEY)
+    }
+
+    fn total_released_variable() -> Variable<U512> {
+        Variable::new(PaymentSplitter::TOTAL_RELEASED_KEY)
+    }
+
+    fn token_released_mapping() -> Mapping<(Key, Key), U256> {
+        Mapping::new(PaymentSplitter::TOKEN_RELEASED_KEY)
+    }
+
+    fn token_total_released_mapping() -> Mapping<Key, U256> {
+        Mapping::new(PaymentSplitter::TOKEN_TOTAL_RELEASED_KEY)
+    }
+
+    fn purse() -> URef {
+        runtime::get_key(PaymentSplitter::PURSE_KEY)
+            .and_then(Key::into_uref)
+            .unwrap_or_revert()
+    }
+
+    pub fn payees() -> Vec<Key> {
+        Variable::new(PaymentSplitter::PAYEES_KEY).get_or_default()
+    }
+
+    pub fn ret_payees() {
+        ret(PaymentSplitter::payees())
+    }
+
+    pub fn shares(account: Key) -> U256 {
+        PaymentSplitter::shares_mapping().get_or_default(&account)
+    }
+
+    pub fn ret_shares() {
+        let account: Key = runtime::get_named_arg("account");
+        ret(PaymentSplitter::shares(account))
+    }
+
+    pub fn total_shares() -> U256 {
+        Variable::new(PaymentSplitter::TOTAL_SHARES_KEY).get_or_default()
+    }
+
+    pub fn ret_total_shares() {
+        ret(PaymentSplitter::total_shares())
+    }
+
+    /// Returns the amount of CSPR already released to `account`, in motes.
+    pub fn released(account: Key) -> U512 {
+        PaymentSplitter::released_mapping().get_or_default(&account)
+    }
+
+    pub fn ret_released() {
+        let account: Key = runtime::get_named_arg("account");
+        ret(PaymentSplitter::released(account))
+    }
+
+    pub fn total_released() -> U512 {
+        PaymentSplitter::total_released_variable().get_or_default()
+    }
+
+    pub fn ret_total_released() {
+        ret(PaymentSplitter::total_released())
+    }
+
+    /// Returns the amount of `token` already released to `account`.
+    pub fn token_released(token: Key, account: Key) -> U256 {
+        PaymentSplitter::token_released_mapping().get_or_default(&(token, account))
+    }
+
+    pub fn ret_token_released() {
+        let token: Key = runtime::get_named_arg("token");
+        let account: Key = runtime::get_named_arg("account");
+        ret(PaymentSplitter::token_released(token, account))
+    }
+
+    /// Returns the share of `account` in `total_received`, minus what was already released.
+    ///
+    /// Computed over `U512` so that token amounts times shares cannot overflow.
+    fn _pending_payment(account: Key, total_received: U512, already_released: U512) -> U512 {
+        let shares = PaymentSplitter::shares(account);
+        if shares.is_zero() {
+            runtime::revert(PaymentSplitterError::AccountHasNoShares);
+        }
+        let payment = total_received * u256_to_u512(shares)
+            / u256_to_u512(PaymentSplitter::total_shares())
+            - already_released;
+        if payment.is_zero() {
+            runtime::revert(PaymentSplitterError::NoPaymentDue);
+        }
+        payment
+    }
+
+    /// Transfers the CSPR `account` is due to it. Only accounts can receive CSPR.
+    pub fn release() {
+        let account: Key = runtime::get_named_arg("account");
+        let account_hash = account
+            .into_account()
+            .ok_or(Error::InvalidKeyVariant)
+            .unwrap_or_revert();
+
+        let purse = PaymentSplitter::purse();
+        let total_received =
+            system::get_purse_balance(purse).unwrap_or_revert() + PaymentSplitter::total_released();
+        let payment = PaymentSplitter::_pending_payment(
+            account,
+            total_received,
+            PaymentSplitter::released(account),
+        );
+
+        let released = PaymentSplitter::released_mapping();
+        released.set(&account, released.get_or_default(&account) + payment);
+        let total_released = PaymentSplitter::total_released_variable();
+        total_released.set(total_released.get_or_default() + payment);
+
+        system::transfer_from_purse_to_account(purse, account_hash, payment, None)
+            .unwrap_or_revert();
+
+        PaymentSplitter::emit(&PaymentSplitterEvent::PaymentReleased {
+            to: account,
+            amount: payment,
+        });
+    }
+
+    /// Transfers the `token` amount `account` is due to it.
+    pub fn release_token() {
+        let token: Key = runtime::get_named_arg("token");
+        let account: Key = runtime::get_named_arg("account");
+        let token_hash = ContractHash::new(token.into_hash().unwrap_or_revert());
+
+        let token_total_released = PaymentSplitter::token_total_released_mapping();
+        let total_received = u256_to_u512(ERC20::call_balance_of(
+            token_hash,
+            Key::from(contract_package_hash()),
+        )) + u256_to_u512(token_total_released.get_or_default(&token));
+        let payment = PaymentSplitter::_pending_payment(
+            account,
+            total_received,
+            u256_to_u512(PaymentSplitter::token_released(token, account)),
+        );
+        // The payment never exceeds the tokens received, so it fits in a `U256`.
+        let payment = u512_to_u256(payment);
+
+        let token_released = PaymentSplitter::token_released_mapping();
+        token_released.set(
+            &(token, account),
+            token_released.get_or_default(&(token, account)) + payment,
+        );
+        token_total_released.set(
+            &token,
+            token_total_released.get_or_default(&token) + payment,
+        );
+
+        ERC20::call_transfer(token_hash, account, payment);
+
+        PaymentSplitter::emit(&PaymentSplitterEvent::ERC20PaymentReleased {
+            token,
+            to: account,
+            amount: payment,
+        });
+    }
+}
+
+fn u256_to_u512(value: U256) -> U512 {
+    let mut bytes = [0u8; 32];
+    value.to_little_endian(&mut bytes);
+    U512::from_little_endian(&bytes)
+}
+
+fn u512_to_u256(value: U512) -> U256 {
+    let mut bytes = [0u8; 64];
+    value.to_little_endian(&mut bytes);
+    U256::from_little_endian(&bytes[..32])
+}
@@ -0,0 +1,311 @@
+use contract::{
+    contract_api::{
+        runtime,
+        storage::{self},
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use std::collections::BTreeMap;
+use types::{CLType, ContractHash, EntryPoint, EntryPoints, Key, Parameter, URef, U256};
+
+use crate::{
+    finance::{error::TokenTimelockError, TokenTimelock},
+    storage::{Mapping, Variable},
+    token::erc20::ERC20,
+    utils::{contract_package_hash, endpoint, get_caller, require_holder_key, ret},
+};
+
+struct TokenTimelockEntryPoints {}
+
+impl TokenTimelockEntryPoints {
+    /// Returns the `token` entry point.
+    pub fn token() -> EntryPoint {
+        endpoint("token", vec![], CLType::Key)
+    }
+
+    /// Returns the `lock_count` entry point.
+    pub fn lock_count() -> EntryPoint {
+        endpoint("lock_count", vec![], CLType::U64)
+    }
+
+    /// Returns the `beneficiary_lock_count` entry point.
+    pub fn beneficiary_lock_count() -> EntryPoint {
+        endpoint(
+            "beneficiary_lock_count",
+            vec![Parameter::new("beneficiary", CLType::Key)],
+            CLType::U64,
+        )
+    }
+
+    /// Returns the `beneficiary_lock` entry point.
+    pub fn beneficiary_lock() -> EntryPoint {
+        endpoint(
+            "beneficiary_lock",
+            vec![
+                Parameter::new("beneficiary", CLType::Key),
+                Parameter::new("index", CLType::U64),
+            ],
+            CLType::U64,
+        )
+    }
+
+    /// Returns the `lock` entry point.
+    pub fn lock() -> EntryPoint {
+        endpoint(
+            "lock",
+            vec![
+                Parameter::new("beneficiary", CLType::Key),
+                Parameter::new("amount", CLType::U256),
+                Parameter::new("release_time", CLType::U64),
+            ],
+            CLType::U64,
+        )
+    }
+
+    /// Returns the `release` entry point.
+    pub fn release() -> EntryPoint {
+        endpoint(
+            "release",
+            vec![Parameter::new("lock_id", CLType::U64)],
+            CLType::Unit,
+        )
+    }
+}
+
+pub enum TokenTimelockEvent {
+    Locked {
+        lock_id: u64,
+        depositor: Key,
+        beneficiary: Key,
+        amount: U256,
+        release_time: u64,
+    },
+    Released {
+        lock_id: u64,
+        beneficiary: Key,
+        amount: U256,
+    },
+}
+
+impl TokenTimelockEvent {
+    pub fn type_name(&self) -> String {
+        match self {
+            TokenTimelockEvent::Locked {
+                lock_id: _,
+                depositor: _,
+                beneficiary: _,
+                amount: _,
+                release_time: _,
+            } => "locked",
+            TokenTimelockEvent::Released {
+                lock_id: _,
+                beneficiary: _,
+                amount: _,
+            } => "released",
+        }
+        .to_string()
+    }
+}
+
+impl TokenTimelock {
+    pub const TOKEN_KEY: &'static str = "token";
+    pub const LOCK_COUNT_KEY: &'static str = "lock_count";
+    pub const LOCK_BENEFICIARIES_KEY: &'static str = "lock_beneficiaries";
+    pub const LOCK_AMOUNTS_KEY: &'static str = "lock_amounts";
+    pub const LOCK_RELEASE_TIMES_KEY: &'static str = "lock_release_times";
+    pub const LOCK_RELEASED_KEY: &'static str = "lock_released";
+    pub const BENEFICIARY_LOCK_COUNTS_KEY: &'static str = "beneficiary_lock_counts";
+    pub const BENEFICIARY_LOCKS_KEY: &'static str = "beneficiary_locks";
+
+    pub fn set_entry_points(current_entry_points: &mut EntryPoints) -> &EntryPoints {
+        current_entry_points.add_entry_point(TokenTimelockEntryPoints::token());
+        current_entry_points.add_entry_point(TokenTimelockEntryPoints::lock_count());
+        current_entry_points.add_entry_point(TokenTimelockEntryPoints::beneficiary_lock_count());
+        current_entry_points.add_entry_point(TokenTimelockEntryPoints::beneficiary_lock());
+        current_entry_points.add_entry_point(TokenTimelockEntryPoints::lock());
+        current_entry_points.add_entry_point(TokenTimelockEntryPoints::release());
+
+        current_entry_points
+    }
+
+    pub fn emit(token_timelock_event: &TokenTimelockEvent) {
+        let mut events = Vec::new();
+        let package = contract_package_hash();
+        match token_timelock_event {
+            TokenTimelockEvent::Locked {
+                lock_id,
+                depositor,
+                beneficiary,
+                amount,
+                release_time,
+            } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_string());
+                event.insert("event_type", token_timelock_event.type_name());
+                event.insert("lock_id", lock_id.to_string());
+                event.insert("depositor", depositor.to_string());
+                event.insert("beneficiary", beneficiary.to_string());
+                event.insert("amount", amount.to_string());
+                event.insert("release_time", release_time.to_string());
+                events.push(event);
+            }
+            TokenTimelockEvent::Released {
+                lock_id,
+                beneficiary,
+                amount,
+            } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_string());
+                event.insert("event_type", token_timelock_event.type_name());
+                event.insert("lock_id", lock_id.to_string());
+                event.insert("beneficiary", beneficiary.to_string());
+                event.insert("amount", amount.to_string());
+                events.push(event);
+            }
+        };
+        for event in events {
+            let _: URef = storage::new_uref(event);
+        }
+    }
+
+    fn lock_count_variable() -> Variable<u64> {
+        Variable::new(TokenTimelock::LOCK_COUNT_KEY)
+    }
+
+    fn lock_beneficiaries() -> Mapping<u64, Key> {
+        Mapping::new(TokenTimelock::LOCK_BENEFICIARIES_KEY)
+    }
+
+    fn lock_amounts() -> Mapping<u64, U256> {
+        Mapping::new(TokenTimelock::LOCK_AMOUNTS_KEY)
+    }
+
+    fn lock_release_times() -> Mapping<u64, u64> {
+        Mapping::new(TokenTimelock::LOCK_RELEASE_TIMES_KEY)
+    }
+
+    fn lock_released() -> Mapping<u64, bool> {
+        Mapping::new(TokenTimelock::LOCK_RELEASED_KEY)
+    }
+
+    fn beneficiary_lock_counts() -> Mapping<Key, u64> {
+        Mapping::new(TokenTimelock::BENEFICIARY_LOCK_COUNTS_KEY)
+    }
+
+    /// Lock ids of every beneficiary, indexed by `(beneficiary, index)`.
+    fn beneficiary_locks() -> Mapping<(Key, u64), u64> {
+        Mapping::new(TokenTimelock::BENEFICIARY_LOCKS_KEY)
+    }
+
+    pub fn token() -> ContractHash {
+        let token: Key = Variable::new(TokenTimelock::TOKEN_KEY)
+            .get()
+            .unwrap_or_revert();
+        ContractHash::new(token.into_hash().unwrap_or_revert())
+    }
+
+    pub fn ret_token() {
+        ret(Key::from(TokenTimelock::token()))
+    }
+
+    /// Returns the number of locks ever created, which is also the id of the next one.
+    pub fn lock_count() -> u64 {
+        TokenTimelock::lock_count_variable().get_or_default()
+    }
+
+    pub fn ret_lock_count() {
+        ret(TokenTimelock::lock_count())
+    }
+
+    pub fn beneficiary_lock_count(beneficiary: Key) -> u64 {
+        TokenTimelock::beneficiary_lock_counts().get_or_default(&beneficiary)
+    }
+
+    pub fn ret_beneficiary_lock_count() {
+        let beneficiary: Key = runtime::get_named_arg("beneficiary");
+        ret(TokenTimelock::beneficiary_lock_count(beneficiary))
+    }
+
+    /// Returns the id of the `index`-th lock created for `beneficiary`.
+    pub fn beneficiary_lock(beneficiary: Key, index: u64) -> u64 {
+        TokenTimelock::beneficiary_locks()
+            .get(&(beneficiary, index))
+            .ok_or(TokenTimelockError::UnknownLock)
+            .unwrap_or_revert()
+    }
+
+    pub fn ret_beneficiary_lock() {
+        let beneficiary: Key = runtime::get_named_arg("beneficiary");
+        let index: u64 = runtime::get_named_arg("index");
+        ret(TokenTimelock::beneficiary_lock(beneficiary, index))
+    }
+
+    /// Locks `amount` of the caller's tokens for `beneficiary` until `release_time` and returns
+    /// the id of the lock. The caller has to approve the amount to the contract beforehand.
+    pub fn lock() {
+        let depositor = get_caller();
+        let beneficiary: Key = runtime::get_named_arg("beneficiary");
+        let amount: U256 = runtime::get_named_arg("amount");
+        let release_time: u64 = runtime::get_named_arg("release_time");
+
+        require_holder_key(&beneficiary);
+        if release_time <= u64::from(runtime::get_blocktime()) {
+            runtime::revert(TokenTimelockError::ReleaseTimeInPast);
+        }
+
+        let lock_id = TokenTimelock::lock_count();
+        TokenTimelock::lock_count_variable().set(lock_id + 1);
+        TokenTimelock::lock_beneficiaries().set(&lock_id, beneficiary);
+        TokenTimelock::lock_amounts().set(&lock_id, amount);
+        TokenTimelock::lock_release_times().set(&lock_id, release_time);
+
+        let index = TokenTimelock::beneficiary_lock_count(beneficiary);
+        TokenTimelock::beneficiary_lock_counts().set(&beneficiary, index + 1);
+        TokenTimelock::beneficiary_locks().set(&(beneficiary, index), lock_id);
+
+        ERC20::call_transfer_from(
+            TokenTimelock::token(),
+            depositor,
+            Key::from(contract_package_hash()),
+            amount,
+        );
+
+        TokenTimelock::emit(&TokenTimelockEvent::Locked {
+            lock_id,
+            depositor,
+            beneficiary,
+            amount,
+            release_time,
+        });
+        ret(lock_id)
+    }
+
+    /// Transfers the tokens of the lock `lock_id` to its beneficiary once its release time is
+    /// reached.
+    pub fn release() {
+        let lock_id: u64 = runtime::get_named_arg("lock_id");
+
+        let beneficiary = TokenTimelock::lock_beneficiaries()
+            .get(&lock_id)
+            .ok_or(TokenTimelockError::UnknownLock)
+            .unwrap_or_revert();
+        if TokenTimelock::lock_released().get_or_default(&lock_id) {
+            runtime::revert(TokenTimelockError::AlreadyReleased);
+        }
+        let release_time = TokenTimelock::lock_release_times().get_or_default(&lock_id);
+        if u64::from(runtime::get_blocktime()) < release_time {
+            runtime::revert(TokenTimelockError::NotReleasable);
+        }
+
+        let amount = TokenTimelock::lock_amounts().get_or_default(&lock_id);
+        TokenTimelock::lock_released().set(&lock_id, true);
+
+        ERC20::call_transfer(TokenTimelock::token(), beneficiary, amount);
+
+        TokenTimelock::emit(&TokenTimelockEvent::Released {
+            lock_id,
+            beneficiary,
+            amount,
+        });
+    }
+}
@@ -0,0 +1,281 @@
+use contract::{
+    contract_api::{
+        runtime,
+        storage::{self},
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use std::collections::BTreeMap;
+use types::{CLType, ContractHash, EntryPoint, EntryPoints, Key, URef, U256};
+
+use crate::{
+    access::Ownable,
+    finance::{error::VestingWalletError, VestingWallet},
+    storage::Variable,
+    token::erc20::ERC20,
+    utils::{contract_package_hash, endpoint, ret},
+};
+
+struct VestingWalletEntryPoints {}
+
+impl VestingWalletEntryPoints {
+    /// Returns the `token` entry point.
+    pub fn token() -> EntryPoint {
+        endpoint("token", vec![], CLType::Key)
+    }
+
+    /// Returns the `beneficiary` entry point.
+    pub fn beneficiary() -> EntryPoint {
+        endpoint("beneficiary", vec![], CLType::Key)
+    }
+
+    /// Returns the `start` entry point.
+    pub fn start() -> EntryPoint {
+        endpoint("start", vec![], CLType::U64)
+    }
+
+    /// Returns the `cliff` entry point.
+    pub fn cliff() -> EntryPoint {
+        endpoint("cliff", vec![], CLType::U64)
+    }
+
+    /// Returns the `duration` entry point.
+    pub fn duration() -> EntryPoint {
+        endpoint("duration", vec![], CLType::U64)
+    }
+
+    /// Returns the `released` entry point.
+    pub fn released() -> EntryPoint {
+        endpoint("released", vec![], CLType::U256)
+    }
+
+    /// Returns the `releasable` entry point.
+    pub fn releasable() -> EntryPoint {
+        endpoint("releasable", vec![], CLType::U256)
+    }
+
+    /// Returns the `release` entry point.
+    pub fn release() -> EntryPoint {
+        endpoint("release", vec![], CLType::Unit)
+    }
+
+    /// Returns the `revoke` entry point.
+    pub fn revoke() -> EntryPoint {
+        endpoint("revoke", vec![], CLType::Unit)
+    }
+}
+
+pub enum VestingWalletEvent {
+    Released { beneficiary: Key, amount: U256 },
+    Revoked { refund: U256 },
+}
+
+impl VestingWalletEvent {
+    pub fn type_name(&self) -> String {
+        match self {
+            VestingWalletEvent::Released {
+                beneficiary: _,
+                amount: _,
+            } => "released",
+            VestingWalletEvent::Revoked { refund: _ } => "revoked",
+        }
+        .to_string()
+    }
+}
+
+impl VestingWallet {
+    pub const TOKEN_KEY: &'static str = "token";
+    pub const BENEFICIARY_KEY: &'static str = "beneficiary";
+    pub const START_KEY: &'static str = "start";
+    pub const CLIFF_KEY: &'static str = "cliff";
+    pub const DURATION_KEY: &'static str = "duration";
+    pub const RELEASED_KEY: &'static str = "released";
+    pub const REVOCABLE_KEY: &'static str = "revocable";
+    pub const REVOKED_KEY: &'static str = "revoked";
+
+    pub fn set_entry_points(current_entry_points: &mut EntryPoints) -> &EntryPoints {
+        current_entry_points.add_entry_point(VestingWalletEntryPoints::token());
+        current_entry_points.add_entry_point(VestingWalletEntryPoints::beneficiary());
+        current_entry_points.add_entry_point(VestingWalletEntryPoints::start());
+        current_entry_points.add_entry_point(VestingWalletEntryPoints::cliff());
+        current_entry_points.add_entry_point(VestingWalletEntryPoints::duration());
+        current_entry_points.add_entry_point(VestingWalletEntryPoints::released());
+        current_entry_points.add_entry_point(VestingWalletEntryPoints::releasable());
+        current_entry_points.add_entry_point(VestingWalletEntryPoints::release());
+        current_entry_points.add_entry_point(VestingWalletEntryPoints::revoke());
+
+        current_entry_points
+    }
+
+    pub fn emit(vesting_wallet_event: &VestingWalletEvent) {
+        let mut events = Vec::new();
+        let package = contract_package_hash();
+        match vesting_wallet_event {
+            VestingWalletEvent::Released {
+                beneficiary,
+                amount,
+            } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_string());
+                event.insert("event_type", vesting_wallet_event.type_name());
+                event.insert("beneficiary", beneficiary.to_string());
+                event.insert("amount", amount.to_string());
+                events.push(event);
+            }
+            VestingWalletEvent::Revoked { refund } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_string());
+                event.insert("event_type", vesting_wallet_event.type_name());
+                event.insert("refund", refund.to_string());
+                events.push(event);
+            }
+        };
+        for event in events {
+            let _: URef = storage::new_uref(event);
+        }
+    }
+
+    /// Checks the vesting schedule given at install.
+    pub fn check_schedule(cliff: u64, duration: u64) {
+        if cliff > duration {
+            runtime::revert(VestingWalletError::InvalidSchedule);
+        }
+    }
+
+    pub fn token() -> ContractHash {
+        let token: Key = Variable::new(VestingWallet::TOKEN_KEY)
+            .get()
+            .unwrap_or_revert();
+        ContractHash::new(token.into_hash().unwrap_or_revert())
+    }
+
+    pub fn ret_token() {
+        ret(Key::from(VestingWallet::token()))
+    }
+
+    pub fn beneficiary() -> Key {
+        Variable::new(VestingWallet::BENEFICIARY_KEY)
+            .get()
+            .unwrap_or_revert()
+    }
+
+    pub fn ret_beneficiary() {
+        ret(VestingWallet::beneficiary())
+    }
+
+    /// Returns the timestamp at which vesting starts, in milliseconds.
+    pub fn start() -> u64 {
+        Variable::new(VestingWallet::START_KEY).get_or_default()
+    }
+
+    pub fn ret_start() {
+        ret(VestingWallet::start())
+    }
+
+    /// Returns the time after `start` before which nothing vests, in milliseconds.
+    pub fn cliff() -> u64 {
+        Variable::new(VestingWallet::CLIFF_KEY).get_or_default()
+    }
+
+    pub fn ret_cliff() {
+        ret(VestingWallet::cliff())
+    }
+
+    /// Returns the time after `start` at which everything is vested, in milliseconds.
+    pub fn duration() -> u64 {
+        Variable::new(VestingWallet::DURATION_KEY).get_or_default()
+    }
+
+    pub fn ret_duration() {
+        ret(VestingWallet::duration())
+    }
+
+    fn released_variable() -> Variable<U256> {
+        Variable::new(VestingWallet::RELEASED_KEY)
+    }
+
+    /// Returns the amount of tokens already released to the beneficiary.
+    pub fn released() -> U256 {
+        VestingWallet::released_variable().get_or_default()
+    }
+
+    pub fn ret_released() {
+        ret(VestingWallet::released())
+    }
+
+    pub fn revocable() -> bool {
+        Variable::new(VestingWallet::REVOCABLE_KEY).get_or_default()
+    }
+
+    pub fn revoked() -> bool {
+        Variable::new(VestingWallet::REVOKED_KEY).get_or_default()
+    }
+
+    /// Returns the amount of tokens vested at `timestamp`, out of every token ever held.
+    ///
+    /// Once revoked, every token left in the wallet is vested.
+    pub fn vested_amount(timestamp: u64) -> U256 {
+        let balance =
+            ERC20::call_balance_of(VestingWallet::token(), Key::from(contract_package_hash()));
+        let allocation = balance + VestingWallet::released();
+
+        let start = VestingWallet::start();
+        let duration = VestingWallet::duration();
+        if VestingWallet::revoked() || timestamp >= start.saturating_add(duration) {
+            allocation
+        } else if timestamp < start.saturating_add(VestingWallet::cliff()) {
+            U256::zero()
+        } else {
+            allocation * U256::from(timestamp - start) / U256::from(duration)
+        }
+    }
+
+    /// Returns the amount of tokens that can be released to the beneficiary now.
+    pub fn releasable() -> U256 {
+        let now = u64::from(runtime::get_blocktime());
+        VestingWallet::vested_amount(now) - VestingWallet::released()
+    }
+
+    pub fn ret_releasable() {
+        ret(VestingWallet::releasable())
+    }
+
+    /// Transfers the releasable tokens to the beneficiary.
+    pub fn release() {
+        let amount = VestingWallet::releasable();
+        let beneficiary = VestingWallet::beneficiary();
+
+        let released = VestingWallet::released_variable();
+        released.set(released.get_or_default() + amount);
+
+        ERC20::call_transfer(VestingWallet::token(), beneficiary, amount);
+
+        VestingWallet::emit(&VestingWalletEvent::Released {
+            beneficiary,
+            amount,
+        });
+    }
+
+    /// Refunds the tokens not vested yet to the owner, the vested ones staying releasable by
+    /// the beneficiary. Only callable by the owner of a revocable wallet.
+    pub fn revoke() {
+        Ownable::check_only_owner();
+        if !VestingWallet::revocable() {
+            runtime::revert(VestingWalletError::NotRevocable);
+        }
+        if VestingWallet::revoked() {
+            runtime::revert(VestingWalletError::AlreadyRevoked);
+        }
+
+        let token = VestingWallet::token();
+        let now = u64::from(runtime::get_blocktime());
+        let balance = ERC20::call_balance_of(token, Key::from(contract_package_hash()));
+        let unreleased = VestingWallet::vested_amount(now) - VestingWallet::released();
+        let refund = balance - unreleased;
+
+        Variable::new(VestingWallet::REVOKED_KEY).set(true);
+        ERC20::call_transfer(token, Ownable::owner(), refund);
+
+        VestingWallet::emit(&VestingWalletEvent::Revoked { refund });
+    }
+}
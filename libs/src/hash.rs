@@ -0,0 +1,15 @@
+//! Hashing computed in wasm rather than by the host, so that the same functions can be called
+//! off-chain, e.g. by tooling deriving the ids used by a contract.
+use blake2::{
+    digest::{Update, VariableOutput},
+    VarBlake2b,
+};
+
+/// Returns the 32 bytes blake2b hash of `bytes`, same as `runtime::blake2b`.
+pub fn blake2b256(bytes: &[u8]) -> [u8; 32] {
+    let mut hash = [0u8; 32];
+    let mut hasher = VarBlake2b::new(hash.len()).unwrap();
+    hasher.update(bytes);
+    hasher.finalize_variable(|result| hash.copy_from_slice(result));
+    hash
+}
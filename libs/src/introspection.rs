@@ -0,0 +1,114 @@
+//! ERC165-style interface detection.
+//!
+//! The id of an interface is derived from the signatures of its standard entry points: every
+//! signature, made of the entry point name and the types of its arguments, is hashed with blake2b
+//! and the first four bytes of the hashes are XORed together. Entry points a module adds on top
+//! of a standard are left out, so that every contract implementing the standard shares its id.
+//!
+//! Modules register their interface while the session code builds the entry points of a
+//! contract, e.g. in [`Ownable::set_entry_points`](crate::access::Ownable::set_entry_points),
+//! and [`install`](crate::upgrade::install) and [`upgrade`](crate::upgrade::upgrade) record the
+//! registered interfaces under the [`INTERFACES_KEY`] named key of the contract. An upgrade
+//! rewrites that list, so an interface dropped by the new version is no longer supported.
+use contract::contract_api::runtime;
+use core::cell::RefCell;
+use types::{bytesrepr::ToBytes, CLType, EntryPoint, EntryPoints, Parameter};
+
+use crate::{
+    hash::blake2b256,
+    utils::{endpoint, get_key, ret},
+};
+
+/// Named key of the list of interfaces supported by the contract.
+pub const INTERFACES_KEY: &str = "_interfaces";
+
+/// Invalid interface id, never supported.
+pub const INVALID_INTERFACE_ID: u32 = 0xffff_ffff;
+
+std::thread_local! {
+    static REGISTERED_INTERFACES: RefCell<Vec<u32>> = RefCell::new(Vec::new());
+}
+
+/// Returns the id of the interface made of `entry_points`.
+///
+/// Doesn't call the host, so interface ids can be computed off-chain as well.
+pub fn interface_id(entry_points: &[EntryPoint]) -> u32 {
+    entry_points.iter().fold(0, |id, entry_point| {
+        let arg_types: Vec<CLType> = entry_point
+            .args()
+            .iter()
+            .map(|arg| arg.cl_type().clone())
+            .collect();
+        // Serializing a string and a list of types can't fail.
+        let signature = (entry_point.name().to_string(), arg_types)
+            .to_bytes()
+            .unwrap_or_default();
+        let hash = blake2b256(&signature);
+        id ^ u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]])
+    })
+}
+
+/// Registers the interface made of `entry_points` as supported by the contract being installed
+/// or upgraded.
+pub fn register_interface(entry_points: &[EntryPoint]) {
+    let id = interface_id(entry_points);
+    REGISTERED_INTERFACES.with(|interfaces| {
+        let mut interfaces = interfaces.borrow_mut();
+        if !interfaces.contains(&id) {
+            interfaces.push(id);
+        }
+    });
+}
+
+/// Returns the id of every registered interface, in registration order.
+pub fn registered_interfaces() -> Vec<u32> {
+    REGISTERED_INTERFACES.with(|interfaces| interfaces.borrow().clone())
+}
+
+struct IntrospectionEntryPoints {}
+
+impl IntrospectionEntryPoints {
+    /// Returns the `supports_interface` entry point.
+    pub fn supports_interface() -> EntryPoint {
+        endpoint(
+            "supports_interface",
+            vec![Parameter::new("interface_id", CLType::U32)],
+            CLType::Bool,
+        )
+    }
+}
+
+pub struct Introspection {}
+
+impl Introspection {
+    pub fn set_entry_points(current_entry_points: &mut EntryPoints) -> &EntryPoints {
+        let entry_points = Introspection::entry_points();
+        register_interface(&entry_points);
+        for entry_point in entry_points {
+            current_entry_points.add_entry_point(entry_point);
+        }
+
+        current_entry_points
+    }
+
+    /// Returns the entry points making up the introspection interface.
+    pub fn entry_points() -> Vec<EntryPoint> {
+        vec![IntrospectionEntryPoints::supports_interface()]
+    }
+
+    pub fn interface_id() -> u32 {
+        interface_id(&Introspection::entry_points())
+    }
+
+    /// Returns whether the contract supports the interface `interface_id`.
+    pub fn supports_interface(interface_id: u32) -> bool {
+        interface_id != INVALID_INTERFACE_ID
+            && get_key::<Vec<u32>>(INTERFACES_KEY).contains(&interface_id)
+    }
+
+    pub fn ret_supports_interface() {
+        let interface_id: u32 = runtime::get_named_arg("interface_id");
+
+        ret(Introspection::supports_interface(interface_id))
+    }
+}
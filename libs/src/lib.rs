@@ -1,4 +1,12 @@
 pub mod access;
 pub mod error;
+pub mod finance;
+pub mod hash;
+pub mod introspection;
+pub mod merkle;
+pub mod migration;
+pub mod security;
+pub mod storage;
 pub mod token;
+pub mod upgrade;
 pub mod utils;
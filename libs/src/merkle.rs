@@ -0,0 +1,48 @@
+//! Merkle proof verification over blake2b.
+//!
+//! Trees are built by hashing every pair of sibling nodes in sorted order, so a proof is only
+//! the list of sibling hashes from the leaf up to the root, without any position flags. A node
+//! without a sibling is promoted to the next level as is.
+//!
+//! Leaves and inner nodes are hashed with distinct prefixes, so the 64 bytes of two sibling
+//! nodes can never be passed off as the preimage of a leaf.
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use types::bytesrepr::ToBytes;
+
+pub type Hash = [u8; 32];
+
+/// Byte prepended to the preimage of a leaf.
+pub const LEAF_PREFIX: u8 = 0x00;
+/// Byte prepended to the preimage of an inner node.
+pub const NODE_PREFIX: u8 = 0x01;
+
+/// Returns the leaf of a serializable value: the blake2b hash of its bytes, prefixed with
+/// [`LEAF_PREFIX`].
+pub fn hash_leaf<T: ToBytes>(value: &T) -> Hash {
+    let mut preimage = vec![LEAF_PREFIX];
+    preimage.append(&mut value.to_bytes().unwrap_or_revert());
+    runtime::blake2b(preimage)
+}
+
+/// Returns the parent of two sibling nodes: the blake2b hash of their concatenation, smallest
+/// first, prefixed with [`NODE_PREFIX`].
+pub fn hash_pair(a: &Hash, b: &Hash) -> Hash {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut preimage = Vec::with_capacity(65);
+    preimage.push(NODE_PREFIX);
+    preimage.extend_from_slice(first);
+    preimage.extend_from_slice(second);
+    runtime::blake2b(preimage)
+}
+
+/// Returns the root of the tree obtained by walking `proof` up from `leaf`.
+pub fn process_proof(proof: &[Hash], leaf: Hash) -> Hash {
+    proof
+        .iter()
+        .fold(leaf, |node, sibling| hash_pair(&node, sibling))
+}
+
+/// Returns whether `proof` proves that `leaf` belongs to the tree of `root`.
+pub fn verify(proof: &[Hash], root: Hash, leaf: Hash) -> bool {
+    process_proof(proof, leaf) == root
+}
@@ -0,0 +1,89 @@
+//! Storage layout versioning for upgradable contracts.
+//!
+//! The storage layout version of a contract is recorded in the
+//! [`STORAGE_LAYOUT_VERSION_KEY`] named key. A contract registers one migration step per layout
+//! version, starting at version `1`, and runs them from its `migrate` entry point after an
+//! upgrade. Steps always run in registration order and only once, from the recorded version up
+//! to the requested one.
+use contract::contract_api::runtime;
+
+use crate::{
+    error::module_error,
+    utils::{get_key, set_key},
+};
+
+pub const STORAGE_LAYOUT_VERSION_KEY: &str = "_storage_layout_version";
+
+module_error! {
+    /// Errors returned by the migration module.
+    pub enum MigrationError: 65400..=65499 {
+        /// Trying to run a migration step out of order or to downgrade the storage layout.
+        OutOfOrder = 0,
+        /// Trying to migrate the storage layout to a version without a registered step.
+        MissingStep = 1,
+    }
+}
+
+/// Returns the storage layout version of the current contract, `0` if none was recorded.
+pub fn storage_layout_version() -> u32 {
+    get_key(STORAGE_LAYOUT_VERSION_KEY)
+}
+
+pub fn set_storage_layout_version(version: u32) {
+    set_key(STORAGE_LAYOUT_VERSION_KEY, version);
+}
+
+/// Step migrating the storage layout from `version - 1` to `version`.
+pub struct MigrationStep {
+    pub version: u32,
+    pub migrate: fn(),
+}
+
+#[derive(Default)]
+pub struct Migrations {
+    steps: Vec<MigrationStep>,
+}
+
+impl Migrations {
+    pub fn new() -> Migrations {
+        Migrations { steps: Vec::new() }
+    }
+
+    /// Registers the step migrating to `version`, which has to directly follow the latest
+    /// registered version.
+    pub fn register(mut self, version: u32, migrate: fn()) -> Migrations {
+        if version != self.latest_version() + 1 {
+            runtime::revert(MigrationError::OutOfOrder);
+        }
+        self.steps.push(MigrationStep { version, migrate });
+        self
+    }
+
+    /// Returns the storage layout version reached once every registered step ran.
+    pub fn latest_version(&self) -> u32 {
+        self.steps.last().map(|step| step.version).unwrap_or(0)
+    }
+
+    /// Runs every step between the recorded version and the latest registered version.
+    pub fn run(&self) {
+        self.run_to(self.latest_version());
+    }
+
+    /// Runs every step between the recorded version and `target_version`.
+    pub fn run_to(&self, target_version: u32) {
+        let current_version = storage_layout_version();
+        if target_version < current_version {
+            runtime::revert(MigrationError::OutOfOrder);
+        }
+        if target_version > self.latest_version() {
+            runtime::revert(MigrationError::MissingStep);
+        }
+
+        for step in self.steps.iter() {
+            if step.version > current_version && step.version <= target_version {
+                (step.migrate)();
+                set_storage_layout_version(step.version);
+            }
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod reentrancy_guard;
+
+pub struct ReentrancyGuard {}
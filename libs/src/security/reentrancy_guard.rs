@@ -0,0 +1,44 @@
+use contract::contract_api::runtime;
+
+use crate::{
+    error::Error,
+    security::ReentrancyGuard,
+    utils::{get_key, set_key},
+};
+
+/// Lock returned by [`ReentrancyGuard::lock`], released when it goes out of scope.
+///
+/// `runtime::ret` never returns, so the lock must be dropped before returning a value from an
+/// entry point, otherwise the contract stays locked.
+pub struct ReentrancyLock {}
+
+impl Drop for ReentrancyLock {
+    fn drop(&mut self) {
+        ReentrancyGuard::exit();
+    }
+}
+
+impl ReentrancyGuard {
+    pub const REENTRANCY_GUARD_KEY: &'static str = "_reentrancy_guard_entered";
+
+    pub fn entered() -> bool {
+        get_key(ReentrancyGuard::REENTRANCY_GUARD_KEY)
+    }
+
+    /// Marks the contract as entered, reverting with [`Error::Locked`] on a nested entry.
+    pub fn enter() {
+        if ReentrancyGuard::entered() {
+            runtime::revert(Error::Locked);
+        }
+        set_key(ReentrancyGuard::REENTRANCY_GUARD_KEY, true);
+    }
+
+    pub fn exit() {
+        set_key(ReentrancyGuard::REENTRANCY_GUARD_KEY, false);
+    }
+
+    pub fn lock() -> ReentrancyLock {
+        ReentrancyGuard::enter();
+        ReentrancyLock {}
+    }
+}
@@ -0,0 +1,154 @@
+//! Typed storage over named keys and dictionaries.
+use contract::{
+    contract_api::{
+        runtime,
+        storage::{self},
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use core::{cell::Cell, marker::PhantomData};
+use types::{
+    bytesrepr::{FromBytes, ToBytes},
+    CLTyped, URef,
+};
+
+use crate::utils::make_dictionary_item_key;
+
+/// Value stored behind the named key `name`.
+///
+/// The `URef` of the named key is looked up once and cached for the lifetime of the variable.
+pub struct Variable<T> {
+    name: &'static str,
+    uref: Cell<Option<URef>>,
+    value_type: PhantomData<T>,
+}
+
+impl<T: CLTyped + FromBytes + ToBytes> Variable<T> {
+    pub fn new(name: &'static str) -> Variable<T> {
+        Variable {
+            name,
+            uref: Cell::new(None),
+            value_type: PhantomData,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn uref(&self) -> Option<URef> {
+        if self.uref.get().is_none() {
+            self.uref
+                .set(runtime::get_key(self.name).map(|key| key.into_uref().unwrap_or_revert()));
+        }
+        self.uref.get()
+    }
+
+    /// Returns the stored value, `None` if it was never set.
+    pub fn get(&self) -> Option<T> {
+        self.uref()
+            .and_then(|uref| storage::read(uref).unwrap_or_revert())
+    }
+
+    /// Returns the stored value, or the default value of `T` if it was never set.
+    pub fn get_or_default(&self) -> T
+    where
+        T: Default,
+    {
+        self.get().unwrap_or_default()
+    }
+
+    pub fn is_set(&self) -> bool {
+        self.get().is_some()
+    }
+
+    /// Stores `value`, creating the named key if needed.
+    pub fn set(&self, value: T) {
+        match self.uref() {
+            Some(uref) => storage::write(uref, value),
+            None => {
+                let uref = storage::new_uref(value);
+                runtime::put_key(self.name, uref.into());
+                self.uref.set(Some(uref));
+            }
+        }
+    }
+}
+
+/// Dictionary stored behind the named key `name`, mapping keys of type `K` to values of type `V`.
+///
+/// Item keys are derived from `K` with [`make_dictionary_item_key`], unless another derivation
+/// is given to [`Mapping::with_item_key`], and the dictionary seed `URef` is looked up once and
+/// cached for the lifetime of the mapping.
+pub struct Mapping<K, V> {
+    name: &'static str,
+    item_key: fn(&K) -> String,
+    seed_uref: Cell<Option<URef>>,
+    types: PhantomData<(K, V)>,
+}
+
+impl<K: ToBytes, V: CLTyped + FromBytes + ToBytes> Mapping<K, V> {
+    pub fn new(name: &'static str) -> Mapping<K, V> {
+        Mapping::with_item_key(name, Mapping::<K, V>::item_key)
+    }
+
+    /// Returns the mapping stored behind `name` whose item keys are derived from `K` with
+    /// `item_key`, e.g. to follow the layout of a dictionary read by external clients.
+    pub fn with_item_key(name: &'static str, item_key: fn(&K) -> String) -> Mapping<K, V> {
+        Mapping {
+            name,
+            item_key,
+            seed_uref: Cell::new(None),
+            types: PhantomData,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn seed_uref(&self) -> Option<URef> {
+        if self.seed_uref.get().is_none() {
+            self.seed_uref
+                .set(runtime::get_key(self.name).map(|key| key.into_uref().unwrap_or_revert()));
+        }
+        self.seed_uref.get()
+    }
+
+    /// Returns the default dictionary item key of `key`, see [`make_dictionary_item_key`].
+    pub fn item_key(key: &K) -> String {
+        make_dictionary_item_key(key)
+    }
+
+    /// Returns the value stored for `key`, `None` if it was never set.
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.seed_uref().and_then(|seed_uref| {
+            storage::dictionary_get(seed_uref, &(self.item_key)(key)).unwrap_or_revert()
+        })
+    }
+
+    /// Returns the value stored for `key`, or the default value of `V` if it was never set.
+    pub fn get_or_default(&self, key: &K) -> V
+    where
+        V: Default,
+    {
+        self.get(key).unwrap_or_default()
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Stores `value` for `key`, creating the dictionary if needed.
+    pub fn set(&self, key: &K, value: V) {
+        let seed_uref = match self.seed_uref() {
+            Some(seed_uref) => seed_uref,
+            None => {
+                let seed_uref = storage::new_dictionary(self.name).unwrap_or_revert();
+                self.seed_uref.set(Some(seed_uref));
+                seed_uref
+            }
+        };
+        storage::dictionary_put(seed_uref, &(self.item_key)(key), value);
+    }
+}
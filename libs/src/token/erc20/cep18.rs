@@ -0,0 +1,855 @@
+//! CEP-18 compatibility mode of the ERC20 module.
+//!
+//! A contract built with [`CEP18`] exposes the entry points, argument names, named keys and
+//! events of the Casper CEP-18 fungible token standard, so that wallets and explorers
+//! recognising CEP-18 can read and move its tokens:
+//! * The entry points adapt the CEP-18 arguments, e.g. `recipient` and `owner`, to the
+//!   [`ERC20`] storage and transfer logic, so balances, allowances and the supply live under the
+//!   same named keys as those of an [`ERC20`] token.
+//! * `balances` and `security_badges` are keyed by the base64-encoded bytes of the holder key,
+//!   see [`CEP18::item_key`], the [`ERC20`] balances following this layout once the `cep18`
+//!   feature is enabled. Allowances keep the [`ERC20`] item keys.
+//! * `mint` and `burn` are only available when the token was installed with `enable_mint_burn`,
+//!   and `mint` and `change_security` are gated by the admin and minter lists.
+//! * Events are recorded in the `__events` dictionary following the Casper Event Standard, as
+//!   CEP-18 tokens do.
+use contract::{
+    contract_api::{
+        runtime,
+        storage::{self},
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use std::collections::BTreeMap;
+use types::{
+    bytesrepr::{Bytes, ToBytes},
+    contracts::NamedKeys,
+    runtime_args, ApiError, CLType, ContractHash, EntryPoint, EntryPoints, Key, Parameter,
+    RuntimeArgs, URef, U256,
+};
+
+use crate::{
+    introspection::{interface_id, register_interface},
+    storage::{Mapping, Variable},
+    token::erc20::{error::Erc20Error, CEP18, ERC20},
+    utils::{endpoint, get_caller, ret},
+};
+
+struct CEP18EntryPoints {}
+
+impl CEP18EntryPoints {
+    /// Returns the `balance_of` entry point.
+    pub fn balance_of() -> EntryPoint {
+        endpoint(
+            "balance_of",
+            vec![Parameter::new("address", CLType::Key)],
+            CLType::U256,
+        )
+    }
+
+    /// Returns the `approve` entry point.
+    pub fn approve() -> EntryPoint {
+        endpoint(
+            "approve",
+            vec![
+                Parameter::new("spender", CLType::Key),
+                Parameter::new("amount", CLType::U256),
+            ],
+            CLType::Unit,
+        )
+    }
+
+    /// Returns the `increase_allowance` entry point.
+    pub fn increase_allowance() -> EntryPoint {
+        endpoint(
+            "increase_allowance",
+            vec![
+                Parameter::new("spender", CLType::Key),
+                Parameter::new("amount", CLType::U256),
+            ],
+            CLType::Unit,
+        )
+    }
+
+    /// Returns the `decrease_allowance` entry point.
+    pub fn decrease_allowance() -> EntryPoint {
+        endpoint(
+            "decrease_allowance",
+            vec![
+                Parameter::new("spender", CLType::Key),
+                Parameter::new("amount", CLType::U256),
+            ],
+            CLType::Unit,
+        )
+    }
+
+    /// Returns the `transfer` entry point.
+    pub fn transfer() -> EntryPoint {
+        endpoint(
+            "transfer",
+            vec![
+                Parameter::new("recipient", CLType::Key),
+                Parameter::new("amount", CLType::U256),
+            ],
+            CLType::Unit,
+        )
+    }
+
+    /// Returns the `transfer_from` entry point.
+    pub fn transfer_from() -> EntryPoint {
+        endpoint(
+            "transfer_from",
+            vec![
+                Parameter::new("owner", CLType::Key),
+                Parameter::new("recipient", CLType::Key),
+                Parameter::new("amount", CLType::U256),
+            ],
+            CLType::Unit,
+        )
+    }
+
+    /// Returns the `mint` entry point.
+    pub fn mint() -> EntryPoint {
+        endpoint(
+            "mint",
+            vec![
+                Parameter::new("owner", CLType::Key),
+                Parameter::new("amount", CLType::U256),
+            ],
+            CLType::Unit,
+        )
+    }
+
+    /// Returns the `burn` entry point.
+    pub fn burn() -> EntryPoint {
+        endpoint(
+            "burn",
+            vec![
+                Parameter::new("owner", CLType::Key),
+                Parameter::new("amount", CLType::U256),
+            ],
+            CLType::Unit,
+        )
+    }
+
+    /// Returns the `change_security` entry point.
+    pub fn change_security() -> EntryPoint {
+        endpoint(
+            "change_security",
+            vec![
+                Parameter::new("admin_list", CLType::List(Box::new(CLType::Key))),
+                Parameter::new("minter_list", CLType::List(Box::new(CLType::Key))),
+                Parameter::new("none_list", CLType::List(Box::new(CLType::Key))),
+            ],
+            CLType::Unit,
+        )
+    }
+}
+
+/// Rights of an account over a CEP-18 token, stored as a `u8` in `security_badges`.
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SecurityBadge {
+    /// May mint and change the security badges of other accounts.
+    Admin = 0,
+    /// May mint.
+    Minter = 1,
+    /// No rights, used to strip the badge of an account.
+    None = 2,
+}
+
+impl SecurityBadge {
+    pub fn from_u8(value: u8) -> Option<SecurityBadge> {
+        match value {
+            0 => Some(SecurityBadge::Admin),
+            1 => Some(SecurityBadge::Minter),
+            2 => Some(SecurityBadge::None),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SecurityBadge::Admin => "Admin",
+            SecurityBadge::Minter => "Minter",
+            SecurityBadge::None => "None",
+        }
+    }
+}
+
+pub enum CEP18Event {
+    Mint {
+        recipient: Key,
+        amount: U256,
+    },
+    Burn {
+        owner: Key,
+        amount: U256,
+    },
+    SetAllowance {
+        owner: Key,
+        spender: Key,
+        allowance: U256,
+    },
+    IncreaseAllowance {
+        owner: Key,
+        spender: Key,
+        allowance: U256,
+        inc_by: U256,
+    },
+    DecreaseAllowance {
+        owner: Key,
+        spender: Key,
+        allowance: U256,
+        decr_by: U256,
+    },
+    Transfer {
+        sender: Key,
+        recipient: Key,
+        amount: U256,
+    },
+    TransferFrom {
+        spender: Key,
+        owner: Key,
+        recipient: Key,
+        amount: U256,
+    },
+    ChangeSecurity {
+        admin: Key,
+        sec_change_map: BTreeMap<Key, SecurityBadge>,
+    },
+}
+
+impl CEP18Event {
+    pub fn type_name(&self) -> String {
+        match self {
+            CEP18Event::Mint {
+                recipient: _,
+                amount: _,
+            } => "Mint",
+            CEP18Event::Burn {
+                owner: _,
+                amount: _,
+            } => "Burn",
+            CEP18Event::SetAllowance {
+                owner: _,
+                spender: _,
+                allowance: _,
+            } => "SetAllowance",
+            CEP18Event::IncreaseAllowance {
+                owner: _,
+                spender: _,
+                allowance: _,
+                inc_by: _,
+            } => "IncreaseAllowance",
+            CEP18Event::DecreaseAllowance {
+                owner: _,
+                spender: _,
+                allowance: _,
+                decr_by: _,
+            } => "DecreaseAllowance",
+            CEP18Event::Transfer {
+                sender: _,
+                recipient: _,
+                amount: _,
+            } => "Transfer",
+            CEP18Event::TransferFrom {
+                spender: _,
+                owner: _,
+                recipient: _,
+                amount: _,
+            } => "TransferFrom",
+            CEP18Event::ChangeSecurity {
+                admin: _,
+                sec_change_map: _,
+            } => "ChangeSecurity",
+        }
+        .to_string()
+    }
+
+    /// Returns the event serialized as the Casper Event Standard does: its `event_` prefixed
+    /// name followed by its fields, in the order of [`CEP18Event::schemas`].
+    pub fn to_ces_bytes(&self) -> Vec<u8> {
+        let fields = match self {
+            CEP18Event::Mint { recipient, amount } => vec![ces_field(recipient), ces_field(amount)],
+            CEP18Event::Burn { owner, amount } => vec![ces_field(owner), ces_field(amount)],
+            CEP18Event::SetAllowance {
+                owner,
+                spender,
+                allowance,
+            } => vec![ces_field(owner), ces_field(spender), ces_field(allowance)],
+            CEP18Event::IncreaseAllowance {
+                owner,
+                spender,
+                allowance,
+                inc_by,
+            } => vec![
+                ces_field(owner),
+                ces_field(spender),
+                ces_field(allowance),
+                ces_field(inc_by),
+            ],
+            CEP18Event::DecreaseAllowance {
+                owner,
+                spender,
+                allowance,
+                decr_by,
+            } => vec![
+                ces_field(owner),
+                ces_field(spender),
+                ces_field(allowance),
+                ces_field(decr_by),
+            ],
+            CEP18Event::Transfer {
+                sender,
+                recipient,
+                amount,
+            } => vec![ces_field(sender), ces_field(recipient), ces_field(amount)],
+            CEP18Event::TransferFrom {
+                spender,
+                owner,
+                recipient,
+                amount,
+            } => vec![
+                ces_field(spender),
+                ces_field(owner),
+                ces_field(recipient),
+                ces_field(amount),
+            ],
+            CEP18Event::ChangeSecurity {
+                admin,
+                sec_change_map,
+            } => vec![
+                ces_field(admin),
+                ces_field(
+                    &sec_change_map
+                        .iter()
+                        .map(|(account, badge)| (*account, *badge as u8))
+                        .collect::<BTreeMap<Key, u8>>(),
+                ),
+            ],
+        };
+
+        let mut bytes = ces_field(&format!("event_{}", self.type_name()));
+        bytes.extend(fields.concat());
+        bytes
+    }
+
+    /// Returns the fields of every CEP-18 event with their `CLType`, by event name.
+    pub fn schemas() -> BTreeMap<String, Vec<(String, CLType)>> {
+        let schema = |fields: &[(&str, CLType)]| -> Vec<(String, CLType)> {
+            fields
+                .iter()
+                .map(|(name, cl_type)| (name.to_string(), cl_type.clone()))
+                .collect()
+        };
+        let sec_change_map = CLType::Map {
+            key: Box::new(CLType::Key),
+            value: Box::new(CLType::U8),
+        };
+
+        vec![
+            (
+                "Mint",
+                schema(&[("recipient", CLType::Key), ("amount", CLType::U256)]),
+            ),
+            (
+                "Burn",
+                schema(&[("owner", CLType::Key), ("amount", CLType::U256)]),
+            ),
+            (
+                "SetAllowance",
+                schema(&[
+                    ("owner", CLType::Key),
+                    ("spender", CLType::Key),
+                    ("allowance", CLType::U256),
+                ]),
+            ),
+            (
+                "IncreaseAllowance",
+                schema(&[
+                    ("owner", CLType::Key),
+                    ("spender", CLType::Key),
+                    ("allowance", CLType::U256),
+                    ("inc_by", CLType::U256),
+                ]),
+            ),
+            (
+                "DecreaseAllowance",
+                schema(&[
+                    ("owner", CLType::Key),
+                    ("spender", CLType::Key),
+                    ("allowance", CLType::U256),
+                    ("decr_by", CLType::U256),
+                ]),
+            ),
+            (
+                "Transfer",
+                schema(&[
+                    ("sender", CLType::Key),
+                    ("recipient", CLType::Key),
+                    ("amount", CLType::U256),
+                ]),
+            ),
+            (
+                "TransferFrom",
+                schema(&[
+                    ("spender", CLType::Key),
+                    ("owner", CLType::Key),
+                    ("recipient", CLType::Key),
+                    ("amount", CLType::U256),
+                ]),
+            ),
+            (
+                "ChangeSecurity",
+                schema(&[("admin", CLType::Key), ("sec_change_map", sec_change_map)]),
+            ),
+        ]
+        .into_iter()
+        .map(|(name, fields)| (name.to_string(), fields))
+        .collect()
+    }
+}
+
+fn ces_field<T: ToBytes>(value: &T) -> Vec<u8> {
+    value.to_bytes().unwrap_or_revert()
+}
+
+/// Appends the bytes of `cl_type` as `casper-types` serializes them: its tag followed by the
+/// tags of its inner types. Only the types of the CEP-18 event fields are supported.
+fn append_cl_type(cl_type: &CLType, bytes: &mut Vec<u8>) {
+    match cl_type {
+        CLType::U8 => bytes.push(3),
+        CLType::U256 => bytes.push(7),
+        CLType::Key => bytes.push(11),
+        CLType::Map { key, value } => {
+            bytes.push(17);
+            append_cl_type(key, bytes);
+            append_cl_type(value, bytes);
+        }
+        _ => runtime::revert(ApiError::Unhandled),
+    }
+}
+
+impl CEP18 {
+    pub const CEP18_BALANCES_KEY: &'static str = ERC20::ERC20_BALANCE_KEY;
+    pub const CEP18_ALLOWANCES_KEY: &'static str = ERC20::ERC20_ALLOWANCE_KEY;
+    pub const CEP18_SECURITY_BADGES_KEY: &'static str = "security_badges";
+    pub const CEP18_ENABLE_MINT_BURN_KEY: &'static str = "enable_mint_burn";
+    pub const CEP18_EVENTS_KEY: &'static str = "__events";
+    pub const CEP18_EVENTS_LENGTH_KEY: &'static str = "__events_length";
+    pub const CEP18_EVENTS_SCHEMA_KEY: &'static str = "__events_schema";
+    pub const CEP18_EVENTS_CES_VERSION_KEY: &'static str = "__events_ces_version";
+    /// Version of the Casper Event Standard the events follow.
+    pub const CEP18_CES_VERSION: &'static str = "0.4.1";
+
+    pub fn set_entry_points(current_entry_points: &mut EntryPoints) -> &EntryPoints {
+        let entry_points = CEP18::entry_points();
+        register_interface(&entry_points);
+        for entry_point in entry_points {
+            current_entry_points.add_entry_point(entry_point);
+        }
+
+        current_entry_points
+    }
+
+    /// Returns the entry points making up the CEP-18 interface. The metadata and `allowance`
+    /// entry points are the ERC20 ones, which already follow CEP-18.
+    pub fn entry_points() -> Vec<EntryPoint> {
+        let erc20_entry_points = ERC20::entry_points();
+        let shared = ["name", "symbol", "decimals", "total_supply", "allowance"];
+        let mut entry_points: Vec<EntryPoint> = shared
+            .iter()
+            .map(|name| {
+                erc20_entry_points
+                    .iter()
+                    .find(|entry_point| entry_point.name() == *name)
+                    .cloned()
+                    .unwrap_or_revert()
+            })
+            .collect();
+        entry_points.extend(vec![
+            CEP18EntryPoints::balance_of(),
+            CEP18EntryPoints::approve(),
+            CEP18EntryPoints::increase_allowance(),
+            CEP18EntryPoints::decrease_allowance(),
+            CEP18EntryPoints::transfer(),
+            CEP18EntryPoints::transfer_from(),
+            CEP18EntryPoints::mint(),
+            CEP18EntryPoints::burn(),
+            CEP18EntryPoints::change_security(),
+        ]);
+        entry_points
+    }
+
+    pub fn interface_id() -> u32 {
+        interface_id(&CEP18::entry_points())
+    }
+
+    /// Records `cep18_event` in the `__events` dictionary under the next event index.
+    pub fn emit(cep18_event: &CEP18Event) {
+        let events_length = Variable::<u32>::new(CEP18::CEP18_EVENTS_LENGTH_KEY);
+        let index = events_length.get_or_default();
+
+        storage::dictionary_put(
+            CEP18::dictionary_seed_uref(CEP18::CEP18_EVENTS_KEY),
+            &index.to_string(),
+            Bytes::from(cep18_event.to_ces_bytes()),
+        );
+        events_length.set(index + 1);
+    }
+
+    /// Returns the serialized [`CEP18Event::schemas`], as stored under `__events_schema`.
+    pub fn events_schema() -> Bytes {
+        let schemas = CEP18Event::schemas();
+        let mut bytes = ces_field(&(schemas.len() as u32));
+        for (name, fields) in schemas {
+            bytes.extend(ces_field(&name));
+            bytes.extend(ces_field(&(fields.len() as u32)));
+            for (field, cl_type) in fields {
+                bytes.extend(ces_field(&field));
+                append_cl_type(&cl_type, &mut bytes);
+            }
+        }
+        Bytes::from(bytes)
+    }
+
+    fn dictionary_seed_uref(name: &str) -> URef {
+        runtime::get_key(name)
+            .and_then(Key::into_uref)
+            .unwrap_or_revert_with(ApiError::MissingKey)
+    }
+
+    /// Returns the dictionary item key of `key` in `balances` and `security_badges`: the
+    /// base64-encoded bytes of the key, as CEP-18 clients compute it.
+    pub fn item_key(key: &Key) -> String {
+        base64::encode(key.to_bytes().unwrap_or_revert())
+    }
+
+    fn security_badges() -> Mapping<Key, u8> {
+        Mapping::with_item_key(CEP18::CEP18_SECURITY_BADGES_KEY, CEP18::item_key)
+    }
+
+    pub fn balance_of(address: Key) -> U256 {
+        ERC20::balance_of(address)
+    }
+
+    pub fn ret_balance_of() {
+        let address: Key = runtime::get_named_arg("address");
+        ret(CEP18::balance_of(address))
+    }
+
+    /// Returns the security badge of `account`, `None` if it never had one.
+    pub fn security_badge(account: Key) -> Option<SecurityBadge> {
+        CEP18::security_badges()
+            .get(&account)
+            .and_then(SecurityBadge::from_u8)
+    }
+
+    /// Reverts unless the caller holds one of the `allowed` badges.
+    fn check_badge(allowed: &[SecurityBadge]) {
+        match CEP18::security_badge(get_caller()) {
+            Some(badge) if allowed.contains(&badge) => {}
+            _ => runtime::revert(Erc20Error::InsufficientRights),
+        }
+    }
+
+    pub fn mint_burn_enabled() -> bool {
+        Variable::<u8>::new(CEP18::CEP18_ENABLE_MINT_BURN_KEY).get_or_default() == 1
+    }
+
+    fn check_mint_burn_enabled() {
+        if !CEP18::mint_burn_enabled() {
+            runtime::revert(Erc20Error::MintBurnDisabled);
+        }
+    }
+
+    pub fn approve() {
+        let owner: Key = get_caller();
+        let spender: Key = runtime::get_named_arg("spender");
+        let amount: U256 = runtime::get_named_arg("amount");
+
+        CEP18::_approve(owner, spender, amount);
+        CEP18::emit(&CEP18Event::SetAllowance {
+            owner,
+            spender,
+            allowance: amount,
+        });
+    }
+
+    pub fn increase_allowance() {
+        let owner: Key = get_caller();
+        let spender: Key = runtime::get_named_arg("spender");
+        let amount: U256 = runtime::get_named_arg("amount");
+
+        let allowance = ERC20::get_allowance(owner, spender).saturating_add(amount);
+        CEP18::_approve(owner, spender, allowance);
+        CEP18::emit(&CEP18Event::IncreaseAllowance {
+            owner,
+            spender,
+            allowance,
+            inc_by: amount,
+        });
+    }
+
+    /// Decreases the allowance of `spender`, down to zero at most as CEP-18 does.
+    pub fn decrease_allowance() {
+        let owner: Key = get_caller();
+        let spender: Key = runtime::get_named_arg("spender");
+        let amount: U256 = runtime::get_named_arg("amount");
+
+        let allowance = ERC20::get_allowance(owner, spender).saturating_sub(amount);
+        CEP18::_approve(owner, spender, allowance);
+        CEP18::emit(&CEP18Event::DecreaseAllowance {
+            owner,
+            spender,
+            allowance,
+            decr_by: amount,
+        });
+    }
+
+    pub fn transfer() {
+        let sender: Key = get_caller();
+        let recipient: Key = runtime::get_named_arg("recipient");
+        let amount: U256 = runtime::get_named_arg("amount");
+        if sender == recipient {
+            runtime::revert(Erc20Error::CannotTargetSelfUser);
+        }
+
+        ERC20::_update(Some(sender), Some(recipient), amount);
+        CEP18::emit(&CEP18Event::Transfer {
+            sender,
+            recipient,
+            amount,
+        });
+    }
+
+    pub fn transfer_from() {
+        let spender: Key = get_caller();
+        let owner: Key = runtime::get_named_arg("owner");
+        let recipient: Key = runtime::get_named_arg("recipient");
+        let amount: U256 = runtime::get_named_arg("amount");
+        if owner == recipient {
+            runtime::revert(Erc20Error::CannotTargetSelfUser);
+        }
+
+        ERC20::_spend_allowance(owner, spender, amount);
+        ERC20::_update(Some(owner), Some(recipient), amount);
+        CEP18::emit(&CEP18Event::TransferFrom {
+            spender,
+            owner,
+            recipient,
+            amount,
+        });
+    }
+
+    /// Mints `amount` tokens to `owner`. Only callable by admins and minters, and only if the
+    /// token was installed with `enable_mint_burn`.
+    pub fn mint() {
+        CEP18::check_mint_burn_enabled();
+        CEP18::check_badge(&[SecurityBadge::Admin, SecurityBadge::Minter]);
+
+        let owner: Key = runtime::get_named_arg("owner");
+        let amount: U256 = runtime::get_named_arg("amount");
+
+        ERC20::_update(None, Some(owner), amount);
+        CEP18::emit(&CEP18Event::Mint {
+            recipient: owner,
+            amount,
+        });
+    }
+
+    /// Burns `amount` of the caller's tokens, only if the token was installed with
+    /// `enable_mint_burn`. As in CEP-18, `owner` must be the caller.
+    pub fn burn() {
+        CEP18::check_mint_burn_enabled();
+
+        let owner: Key = runtime::get_named_arg("owner");
+        let amount: U256 = runtime::get_named_arg("amount");
+        if owner != get_caller() {
+            runtime::revert(Erc20Error::InvalidBurnTarget);
+        }
+
+        ERC20::_update(Some(owner), None, amount);
+        CEP18::emit(&CEP18Event::Burn { owner, amount });
+    }
+
+    /// Gives the `Admin`, `Minter` and `None` badges to the accounts of the matching lists.
+    /// Only callable by admins, who cannot strip their own admin badge.
+    pub fn change_security() {
+        CEP18::check_mint_burn_enabled();
+        CEP18::check_badge(&[SecurityBadge::Admin]);
+
+        let admin_list: Vec<Key> = runtime::get_named_arg("admin_list");
+        let minter_list: Vec<Key> = runtime::get_named_arg("minter_list");
+        let none_list: Vec<Key> = runtime::get_named_arg("none_list");
+
+        let admin = get_caller();
+        let mut sec_change_map: BTreeMap<Key, SecurityBadge> = BTreeMap::new();
+        for (list, badge) in vec![
+            (admin_list, SecurityBadge::Admin),
+            (minter_list, SecurityBadge::Minter),
+            (none_list, SecurityBadge::None),
+        ] {
+            for account in list {
+                if account == admin && badge != SecurityBadge::Admin {
+                    runtime::revert(Erc20Error::CannotTargetSelfUser);
+                }
+                sec_change_map.insert(account, badge);
+            }
+        }
+
+        let security_badges = CEP18::security_badges();
+        for (account, badge) in sec_change_map.iter() {
+            security_badges.set(account, *badge as u8);
+        }
+        CEP18::emit(&CEP18Event::ChangeSecurity {
+            admin,
+            sec_change_map,
+        });
+    }
+
+    /// Sets the allowance of `spender`, who cannot be `owner` as in CEP-18.
+    fn _approve(owner: Key, spender: Key, amount: U256) {
+        if owner == spender {
+            runtime::revert(Erc20Error::CannotTargetSelfUser);
+        }
+
+        ERC20::_set_allowance(owner, spender, amount);
+    }
+
+    /// Returns the balance of `address` in the CEP-18 contract `token`.
+    pub fn call_balance_of(token: ContractHash, address: Key) -> U256 {
+        runtime::call_contract(
+            token,
+            "balance_of",
+            runtime_args! {
+                "address" => address
+            },
+        )
+    }
+
+    /// Transfers `amount` of the current contract's tokens in the CEP-18 contract `token`.
+    pub fn call_transfer(token: ContractHash, recipient: Key, amount: U256) {
+        runtime::call_contract::<()>(
+            token,
+            "transfer",
+            runtime_args! {
+                "recipient" => recipient,
+                "amount" => amount
+            },
+        );
+    }
+
+    /// Transfers `amount` of `owner`'s tokens in the CEP-18 contract `token`, spending the
+    /// allowance given to the current contract.
+    pub fn call_transfer_from(token: ContractHash, owner: Key, recipient: Key, amount: U256) {
+        runtime::call_contract::<()>(
+            token,
+            "transfer_from",
+            runtime_args! {
+                "owner" => owner,
+                "recipient" => recipient,
+                "amount" => amount
+            },
+        );
+    }
+
+    /// Creates the CEP-18 named keys from session code and returns them for the contract
+    /// installation. The `total_supply` is credited to `installer`, who also becomes an admin
+    /// alongside the accounts of `admin_list`, while `minter_list` accounts become minters.
+    #[allow(clippy::too_many_arguments)]
+    pub fn install_named_keys(
+        name: String,
+        symbol: String,
+        decimals: u8,
+        total_supply: U256,
+        enable_mint_burn: bool,
+        installer: Key,
+        admin_list: Vec<Key>,
+        minter_list: Vec<Key>,
+    ) -> NamedKeys {
+        let balances_seed_uref =
+            storage::new_dictionary(CEP18::CEP18_BALANCES_KEY).unwrap_or_revert();
+        runtime::remove_key(CEP18::CEP18_BALANCES_KEY);
+        let allowances_seed_uref =
+            storage::new_dictionary(CEP18::CEP18_ALLOWANCES_KEY).unwrap_or_revert();
+        runtime::remove_key(CEP18::CEP18_ALLOWANCES_KEY);
+        let security_badges_seed_uref =
+            storage::new_dictionary(CEP18::CEP18_SECURITY_BADGES_KEY).unwrap_or_revert();
+        runtime::remove_key(CEP18::CEP18_SECURITY_BADGES_KEY);
+        let events_seed_uref = storage::new_dictionary(CEP18::CEP18_EVENTS_KEY).unwrap_or_revert();
+        runtime::remove_key(CEP18::CEP18_EVENTS_KEY);
+
+        if total_supply > U256::zero() {
+            storage::dictionary_put(
+                balances_seed_uref,
+                &CEP18::item_key(&installer),
+                total_supply,
+            );
+        }
+        for (account, badge) in core::iter::once(installer)
+            .chain(admin_list)
+            .map(|account| (account, SecurityBadge::Admin))
+            .chain(
+                minter_list
+                    .into_iter()
+                    .map(|account| (account, SecurityBadge::Minter)),
+            )
+        {
+            storage::dictionary_put(
+                security_badges_seed_uref,
+                &CEP18::item_key(&account),
+                badge as u8,
+            );
+        }
+
+        let mut named_keys = NamedKeys::new();
+        named_keys.insert(
+            ERC20::ERC20_NAME_KEY.to_string(),
+            storage::new_uref(name).into(),
+        );
+        named_keys.insert(
+            ERC20::ERC20_SYMBOL_KEY.to_string(),
+            storage::new_uref(symbol).into(),
+        );
+        named_keys.insert(
+            ERC20::ERC20_DECIMALS_KEY.to_string(),
+            storage::new_uref(decimals).into(),
+        );
+        named_keys.insert(
+            ERC20::ERC20_TOTAL_SUPPLY_KEY.to_string(),
+            storage::new_uref(total_supply).into(),
+        );
+        named_keys.insert(
+            CEP18::CEP18_BALANCES_KEY.to_string(),
+            balances_seed_uref.into(),
+        );
+        named_keys.insert(
+            CEP18::CEP18_ALLOWANCES_KEY.to_string(),
+            allowances_seed_uref.into(),
+        );
+        named_keys.insert(
+            CEP18::CEP18_SECURITY_BADGES_KEY.to_string(),
+            security_badges_seed_uref.into(),
+        );
+        named_keys.insert(
+            CEP18::CEP18_ENABLE_MINT_BURN_KEY.to_string(),
+            storage::new_uref(enable_mint_burn as u8).into(),
+        );
+        named_keys.insert(CEP18::CEP18_EVENTS_KEY.to_string(), events_seed_uref.into());
+        named_keys.insert(
+            CEP18::CEP18_EVENTS_LENGTH_KEY.to_string(),
+            storage::new_uref(0u32).into(),
+        );
+        named_keys.insert(
+            CEP18::CEP18_EVENTS_SCHEMA_KEY.to_string(),
+            storage::new_uref(CEP18::events_schema()).into(),
+        );
+        named_keys.insert(
+            CEP18::CEP18_EVENTS_CES_VERSION_KEY.to_string(),
+            storage::new_uref(CEP18::CEP18_CES_VERSION.to_string()).into(),
+        );
+        named_keys
+    }
+}
@@ -7,15 +7,19 @@ use contract::{
 };
 use std::collections::BTreeMap;
 use types::{
-    account::AccountHash, bytesrepr::ToBytes, CLType, EntryPoint, EntryPoints, Key, Parameter,
-    URef, U256,
+    account::AccountHash, runtime_args, CLType, ContractHash, EntryPoint, EntryPoints, Key,
+    Parameter, RuntimeArgs, URef, U256,
 };
 
 use crate::{
+    access::Ownable,
     error::Error,
-    token::erc20::ERC20,
+    introspection::{interface_id, register_interface},
+    storage::{Mapping, Variable},
+    token::erc20::{error::Erc20Error, ERC20},
     utils::{
-        contract_package_hash, endpoint, get, get_caller, get_key, key_to_str, ret, set, set_key,
+        authorized_caller, contract_package_hash, endpoint, make_dictionary_item_key,
+        require_holder_key, ret,
     },
 };
 
@@ -75,6 +79,19 @@ impl ERC20EntryPoints {
         )
     }
 
+    /// Returns the `approve_from` entry point.
+    pub fn approve_from() -> EntryPoint {
+        endpoint(
+            "approve_from",
+            vec![
+                Parameter::new("spender", CLType::Key),
+                Parameter::new("current_amount", CLType::U256),
+                Parameter::new("new_amount", CLType::U256),
+            ],
+            CLType::Bool,
+        )
+    }
+
     /// Returns the `increase_allowance` entry point.
     pub fn increase_allowance() -> EntryPoint {
         endpoint(
@@ -123,6 +140,30 @@ impl ERC20EntryPoints {
             CLType::Bool,
         )
     }
+
+    /// Returns the `transfer_batch` entry point.
+    pub fn transfer_batch() -> EntryPoint {
+        endpoint(
+            "transfer_batch",
+            vec![
+                Parameter::new("recipients", CLType::List(Box::new(CLType::Key))),
+                Parameter::new("amounts", CLType::List(Box::new(CLType::U256))),
+            ],
+            CLType::Bool,
+        )
+    }
+
+    /// Returns the `mint_batch` entry point.
+    pub fn mint_batch() -> EntryPoint {
+        endpoint(
+            "mint_batch",
+            vec![
+                Parameter::new("recipients", CLType::List(Box::new(CLType::Key))),
+                Parameter::new("amounts", CLType::List(Box::new(CLType::U256))),
+            ],
+            CLType::Unit,
+        )
+    }
 }
 
 pub enum ERC20Event {
@@ -165,21 +206,55 @@ impl ERC20 {
     pub const ERC20_TOTAL_SUPPLY_KEY: &'static str = "total_supply";
 
     pub fn set_entry_points(current_entry_points: &mut EntryPoints) -> &EntryPoints {
-        current_entry_points.add_entry_point(ERC20EntryPoints::name());
-        current_entry_points.add_entry_point(ERC20EntryPoints::symbol());
-        current_entry_points.add_entry_point(ERC20EntryPoints::decimals());
-        current_entry_points.add_entry_point(ERC20EntryPoints::total_supply());
-        current_entry_points.add_entry_point(ERC20EntryPoints::balance_of());
-        current_entry_points.add_entry_point(ERC20EntryPoints::allowance());
-        current_entry_points.add_entry_point(ERC20EntryPoints::approve());
-        current_entry_points.add_entry_point(ERC20EntryPoints::increase_allowance());
-        current_entry_points.add_entry_point(ERC20EntryPoints::decrease_allowance());
-        current_entry_points.add_entry_point(ERC20EntryPoints::transfer());
-        current_entry_points.add_entry_point(ERC20EntryPoints::transfer_from());
+        let entry_points = ERC20::entry_points();
+        register_interface(&ERC20::interface_entry_points());
+        for entry_point in entry_points {
+            current_entry_points.add_entry_point(entry_point);
+        }
 
         current_entry_points
     }
 
+    /// Returns the entry points of the ERC20 module.
+    pub fn entry_points() -> Vec<EntryPoint> {
+        vec![
+            ERC20EntryPoints::name(),
+            ERC20EntryPoints::symbol(),
+            ERC20EntryPoints::decimals(),
+            ERC20EntryPoints::total_supply(),
+            ERC20EntryPoints::balance_of(),
+            ERC20EntryPoints::allowance(),
+            ERC20EntryPoints::approve(),
+            ERC20EntryPoints::approve_from(),
+            ERC20EntryPoints::increase_allowance(),
+            ERC20EntryPoints::decrease_allowance(),
+            ERC20EntryPoints::transfer(),
+            ERC20EntryPoints::transfer_from(),
+            ERC20EntryPoints::transfer_batch(),
+            ERC20EntryPoints::mint_batch(),
+        ]
+    }
+
+    /// Returns the standard ERC20 entry points making up the ERC20 interface, without the
+    /// extensions of the module such as `approve_from` or `mint_batch`.
+    pub fn interface_entry_points() -> Vec<EntryPoint> {
+        vec![
+            ERC20EntryPoints::name(),
+            ERC20EntryPoints::symbol(),
+            ERC20EntryPoints::decimals(),
+            ERC20EntryPoints::total_supply(),
+            ERC20EntryPoints::balance_of(),
+            ERC20EntryPoints::allowance(),
+            ERC20EntryPoints::approve(),
+            ERC20EntryPoints::transfer(),
+            ERC20EntryPoints::transfer_from(),
+        ]
+    }
+
+    pub fn interface_id() -> u32 {
+        interface_id(&ERC20::interface_entry_points())
+    }
+
     pub fn emit(erc20_event: &ERC20Event) {
         let mut events = Vec::new();
         let package = contract_package_hash();
@@ -212,8 +287,30 @@ impl ERC20 {
         }
     }
 
+    #[cfg(not(feature = "cep18"))]
+    fn balances() -> Mapping<Key, U256> {
+        Mapping::new(ERC20::ERC20_BALANCE_KEY)
+    }
+
+    /// Balances keyed as CEP-18 clients read them, see [`crate::token::erc20::CEP18::item_key`].
+    #[cfg(feature = "cep18")]
+    fn balances() -> Mapping<Key, U256> {
+        Mapping::with_item_key(
+            ERC20::ERC20_BALANCE_KEY,
+            crate::token::erc20::CEP18::item_key,
+        )
+    }
+
+    fn allowances() -> Mapping<(Key, Key), U256> {
+        Mapping::new(ERC20::ERC20_ALLOWANCE_KEY)
+    }
+
+    fn supply() -> Variable<U256> {
+        Variable::new(ERC20::ERC20_TOTAL_SUPPLY_KEY)
+    }
+
     pub fn name() -> String {
-        get_key(ERC20::ERC20_NAME_KEY)
+        Variable::new(ERC20::ERC20_NAME_KEY).get_or_default()
     }
 
     pub fn ret_name() {
@@ -221,7 +318,7 @@ impl ERC20 {
     }
 
     pub fn symbol() -> String {
-        get_key(ERC20::ERC20_SYMBOL_KEY)
+        Variable::new(ERC20::ERC20_SYMBOL_KEY).get_or_default()
     }
 
     pub fn ret_symbol() {
@@ -229,7 +326,7 @@ impl ERC20 {
     }
 
     pub fn decimals() -> u8 {
-        get_key(ERC20::ERC20_DECIMALS_KEY)
+        Variable::new(ERC20::ERC20_DECIMALS_KEY).get_or_default()
     }
 
     pub fn ret_decimals() {
@@ -237,8 +334,7 @@ impl ERC20 {
     }
 
     pub fn balance_of(account: Key) -> U256 {
-        let balance: U256 = get(ERC20::ERC20_BALANCE_KEY, &key_to_str(&account));
-        balance
+        ERC20::balances().get_or_default(&account)
     }
 
     pub fn ret_balance_of() {
@@ -247,8 +343,7 @@ impl ERC20 {
     }
 
     pub fn total_supply() -> U256 {
-        let supply: U256 = get_key(ERC20::ERC20_TOTAL_SUPPLY_KEY);
-        supply
+        ERC20::supply().get_or_default()
     }
 
     pub fn ret_total_supply() {
@@ -256,11 +351,7 @@ impl ERC20 {
     }
 
     pub fn get_allowance(owner: Key, spender: Key) -> U256 {
-        let allowance: U256 = get(
-            ERC20::ERC20_ALLOWANCE_KEY,
-            &ERC20::get_allowances_key(owner, spender),
-        );
-        allowance
+        ERC20::allowances().get_or_default(&(owner, spender))
     }
 
     pub fn ret_allowance() {
@@ -270,33 +361,47 @@ impl ERC20 {
     }
 
     pub fn approve() {
-        let owner: Key = get_caller();
+        let owner: Key = authorized_caller();
         let spender: Key = runtime::get_named_arg("spender");
         let amount: U256 = runtime::get_named_arg("amount");
 
         ERC20::_approve(owner, spender, amount);
     }
 
+    /// Sets the allowance of `spender` to `new_amount` only if it is still `current_amount`, so
+    /// that a spender cannot use the old allowance and then the new one.
+    pub fn approve_from() {
+        let owner: Key = authorized_caller();
+        let spender: Key = runtime::get_named_arg("spender");
+        let current_amount: U256 = runtime::get_named_arg("current_amount");
+        let new_amount: U256 = runtime::get_named_arg("new_amount");
+
+        if ERC20::get_allowance(owner, spender) != current_amount {
+            runtime::revert(Erc20Error::AllowanceMismatch);
+        }
+        ERC20::_approve(owner, spender, new_amount);
+    }
+
     pub fn increase_allowance() {
-        let owner: Key = get_caller();
+        let owner: Key = authorized_caller();
         let spender: Key = runtime::get_named_arg("spender");
         let amount: U256 = runtime::get_named_arg("amount");
 
-        ERC20::_approve(
-            owner,
-            spender,
-            ERC20::get_allowance(owner, spender) + amount,
-        );
+        let new_allowance = ERC20::get_allowance(owner, spender)
+            .checked_add(amount)
+            .ok_or(Error::Overflow)
+            .unwrap_or_revert();
+        ERC20::_approve(owner, spender, new_allowance);
     }
 
     pub fn decrease_allowance() {
-        let owner: Key = get_caller();
+        let owner: Key = authorized_caller();
         let spender: Key = runtime::get_named_arg("spender");
         let amount: U256 = runtime::get_named_arg("amount");
 
         let current_allowance = ERC20::get_allowance(owner, spender);
         if current_allowance < amount {
-            runtime::revert(Error::InsufficientAllowance);
+            runtime::revert(Erc20Error::InsufficientAllowance);
         }
         ERC20::_approve(
             owner,
@@ -306,7 +411,7 @@ impl ERC20 {
     }
 
     pub fn transfer() {
-        let from: Key = get_caller();
+        let from: Key = authorized_caller();
         let to: Key = runtime::get_named_arg("to");
         let amount: U256 = runtime::get_named_arg("amount");
 
@@ -314,7 +419,7 @@ impl ERC20 {
     }
 
     pub fn transfer_from() {
-        let spender: Key = get_caller();
+        let spender: Key = authorized_caller();
         let from: Key = runtime::get_named_arg("from");
         let to: Key = runtime::get_named_arg("to");
         let amount: U256 = runtime::get_named_arg("amount");
@@ -323,30 +428,91 @@ impl ERC20 {
         ERC20::_transfer(from, to, amount);
     }
 
-    pub fn _transfer(from: Key, to: Key, amount: U256) {
-        if from == Key::Account(AccountHash::default())
-            || to == Key::Account(AccountHash::default())
-        {
-            runtime::revert(Error::ZeroAddress);
+    /// Transfers `amounts[i]` of the caller's tokens to `recipients[i]` for every `i`, after
+    /// checking the caller can afford the whole batch.
+    pub fn transfer_batch() {
+        let from: Key = authorized_caller();
+        let recipients: Vec<Key> = runtime::get_named_arg("recipients");
+        let amounts: Vec<U256> = runtime::get_named_arg("amounts");
+
+        let total = ERC20::_batch_total(&recipients, &amounts);
+        if ERC20::balance_of(from) < total {
+            runtime::revert(Erc20Error::InsufficientBalance);
         }
 
-        let from_balance = ERC20::balance_of(from);
-        if from_balance < amount {
-            runtime::revert(Error::InsufficientBalance);
+        for (to, amount) in recipients.into_iter().zip(amounts) {
+            ERC20::_transfer(from, to, amount);
         }
+    }
 
-        let to_balance = ERC20::balance_of(to);
+    /// Mints `amounts[i]` tokens to `recipients[i]` for every `i`. Only callable by the owner.
+    pub fn mint_batch() {
+        Ownable::check_only_owner();
 
-        set(
-            ERC20::ERC20_BALANCE_KEY,
-            &key_to_str(&from),
-            from_balance - amount,
-        );
-        set(
-            ERC20::ERC20_BALANCE_KEY,
-            &key_to_str(&to),
-            to_balance + amount,
-        );
+        let recipients: Vec<Key> = runtime::get_named_arg("recipients");
+        let amounts: Vec<U256> = runtime::get_named_arg("amounts");
+
+        let total = ERC20::_batch_total(&recipients, &amounts);
+        ERC20::total_supply()
+            .checked_add(total)
+            .ok_or(Error::Overflow)
+            .unwrap_or_revert();
+
+        for (to, amount) in recipients.into_iter().zip(amounts) {
+            ERC20::_mint(to, amount);
+        }
+    }
+
+    /// Returns the sum of the `amounts` of a batch, reverting if it does not match the
+    /// `recipients`.
+    fn _batch_total(recipients: &[Key], amounts: &[U256]) -> U256 {
+        if recipients.len() != amounts.len() {
+            runtime::revert(Erc20Error::BatchLengthMismatch);
+        }
+        amounts
+            .iter()
+            .try_fold(U256::zero(), |total, amount| total.checked_add(*amount))
+            .ok_or(Error::Overflow)
+            .unwrap_or_revert()
+    }
+
+    /// Moves `amount` tokens from `from` to `to` without emitting any event. Tokens are minted
+    /// when `from` is `None` and burned when `to` is `None`.
+    pub fn _update(from: Option<Key>, to: Option<Key>, amount: U256) {
+        let balances = ERC20::balances();
+        let supply = ERC20::supply();
+
+        match from {
+            Some(from) => {
+                require_holder_key(&from);
+                let from_balance = balances.get_or_default(&from);
+                if from_balance < amount {
+                    runtime::revert(Erc20Error::InsufficientBalance);
+                }
+                balances.set(&from, from_balance - amount);
+            }
+            None => supply.set(
+                supply
+                    .get_or_default()
+                    .checked_add(amount)
+                    .ok_or(Error::Overflow)
+                    .unwrap_or_revert(),
+            ),
+        }
+
+        match to {
+            Some(to) => {
+                require_holder_key(&to);
+                // Read after the debit so that a transfer to oneself leaves the balance unchanged.
+                let to_balance = balances.get_or_default(&to);
+                balances.set(&to, to_balance + amount);
+            }
+            None => supply.set(supply.get_or_default() - amount),
+        }
+    }
+
+    pub fn _transfer(from: Key, to: Key, amount: U256) {
+        ERC20::_update(Some(from), Some(to), amount);
 
         ERC20::emit(&ERC20Event::Transfer {
             from,
@@ -356,19 +522,7 @@ impl ERC20 {
     }
 
     pub fn _mint(to: Key, amount: U256) {
-        if to == Key::Account(AccountHash::default()) {
-            runtime::revert(Error::ZeroAddress);
-        }
-
-        let to_balance = ERC20::balance_of(to);
-        let supply = ERC20::total_supply();
-
-        set_key(ERC20::ERC20_TOTAL_SUPPLY_KEY, supply + amount);
-        set(
-            ERC20::ERC20_BALANCE_KEY,
-            &key_to_str(&to),
-            to_balance + amount,
-        );
+        ERC20::_update(None, Some(to), amount);
 
         ERC20::emit(&ERC20Event::Transfer {
             from: Key::Account(AccountHash::default()),
@@ -378,22 +532,7 @@ impl ERC20 {
     }
 
     pub fn _burn(account: Key, amount: U256) {
-        if account == Key::Account(AccountHash::default()) {
-            runtime::revert(Error::ZeroAddress);
-        }
-
-        let account_balance = ERC20::balance_of(account);
-        if account_balance < amount {
-            runtime::revert(Error::InsufficientBalance);
-        }
-        let supply = ERC20::total_supply();
-
-        set_key(ERC20::ERC20_TOTAL_SUPPLY_KEY, supply - amount);
-        set(
-            ERC20::ERC20_BALANCE_KEY,
-            &key_to_str(&account),
-            account_balance - amount,
-        );
+        ERC20::_update(Some(account), None, amount);
 
         ERC20::emit(&ERC20Event::Transfer {
             from: account,
@@ -402,18 +541,16 @@ impl ERC20 {
         });
     }
 
-    pub fn _approve(owner: Key, spender: Key, amount: U256) {
-        if owner == Key::Account(AccountHash::default())
-            || spender == Key::Account(AccountHash::default())
-        {
-            runtime::revert(Error::ZeroAddress);
-        }
+    /// Sets the allowance of `spender` over the tokens of `owner` without emitting any event.
+    pub fn _set_allowance(owner: Key, spender: Key, amount: U256) {
+        require_holder_key(&owner);
+        require_holder_key(&spender);
 
-        set(
-            ERC20::ERC20_ALLOWANCE_KEY,
-            &ERC20::get_allowances_key(owner, spender),
-            amount,
-        );
+        ERC20::allowances().set(&(owner, spender), amount);
+    }
+
+    pub fn _approve(owner: Key, spender: Key, amount: U256) {
+        ERC20::_set_allowance(owner, spender, amount);
 
         ERC20::emit(&ERC20Event::Approval {
             owner,
@@ -423,26 +560,55 @@ impl ERC20 {
     }
 
     pub fn _spend_allowance(owner: Key, spender: Key, amount: U256) {
-        let allowance = ERC20::get_allowance(owner, spender);
+        let allowances = ERC20::allowances();
+        let allowance = allowances.get_or_default(&(owner, spender));
 
         if allowance != U256::MAX {
             if allowance < amount {
-                runtime::revert(Error::InsufficientAllowance);
+                runtime::revert(Erc20Error::InsufficientAllowance);
             }
-            set(
-                ERC20::ERC20_ALLOWANCE_KEY,
-                &ERC20::get_allowances_key(owner, spender),
-                allowance - amount,
-            );
+            allowances.set(&(owner, spender), allowance - amount);
         }
     }
 
-    pub fn get_allowances_key(owner: Key, spender: Key) -> String {
-        let mut preimage = Vec::new();
-        preimage.append(&mut owner.to_bytes().unwrap_or_revert());
-        preimage.append(&mut spender.to_bytes().unwrap_or_revert());
+    /// Returns the balance of `account` in the ERC20 contract `token`.
+    pub fn call_balance_of(token: ContractHash, account: Key) -> U256 {
+        runtime::call_contract(
+            token,
+            "balance_of",
+            runtime_args! {
+                "account" => account
+            },
+        )
+    }
+
+    /// Transfers `amount` of the current contract's tokens in the ERC20 contract `token`.
+    pub fn call_transfer(token: ContractHash, to: Key, amount: U256) {
+        runtime::call_contract::<()>(
+            token,
+            "transfer",
+            runtime_args! {
+                "to" => to,
+                "amount" => amount
+            },
+        );
+    }
 
-        let key_bytes = runtime::blake2b(&preimage);
-        hex::encode(&key_bytes)
+    /// Transfers `amount` of `from`'s tokens in the ERC20 contract `token`, spending the
+    /// allowance given to the current contract.
+    pub fn call_transfer_from(token: ContractHash, from: Key, to: Key, amount: U256) {
+        runtime::call_contract::<()>(
+            token,
+            "transfer_from",
+            runtime_args! {
+                "from" => from,
+                "to" => to,
+                "amount" => amount
+            },
+        );
+    }
+
+    pub fn get_allowances_key(owner: Key, spender: Key) -> String {
+        make_dictionary_item_key(&(owner, spender))
     }
 }
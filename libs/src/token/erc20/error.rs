@@ -0,0 +1,23 @@
+use crate::error::module_error;
+
+module_error! {
+    /// Errors returned by the ERC20 module.
+    pub enum Erc20Error: 65100..=65199 {
+        /// Spender does not have enough balance.
+        InsufficientBalance = 0,
+        /// Spender does not have enough allowance approved.
+        InsufficientAllowance = 1,
+        /// Batch arguments do not have the same length.
+        BatchLengthMismatch = 2,
+        /// Stored allowance does not match the expected current amount.
+        AllowanceMismatch = 3,
+        /// Caller does not hold a security badge allowing the operation.
+        InsufficientRights = 4,
+        /// Token was installed without `enable_mint_burn`.
+        MintBurnDisabled = 5,
+        /// Operation cannot target the caller itself.
+        CannotTargetSelfUser = 6,
+        /// Tokens can only be burned by their owner.
+        InvalidBurnTarget = 7,
+    }
+}
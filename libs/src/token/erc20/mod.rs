@@ -1,3 +1,10 @@
+#[cfg(feature = "cep18")]
+pub mod cep18;
 pub mod erc20;
+pub mod error;
 
 pub struct ERC20 {}
+
+/// ERC20 token following the CEP-18 conventions, see [`cep18`].
+#[cfg(feature = "cep18")]
+pub struct CEP18 {}
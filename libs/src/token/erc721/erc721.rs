@@ -0,0 +1,979 @@
+//! Non-fungible token collection following the Casper CEP-78 conventions.
+//!
+//! The collection is configured once at install time, see [`ERC721Config`]:
+//! * the ownership mode decides whether tokens are owned by their minter, assigned to an owner
+//!   for good, or freely transferable;
+//! * the metadata kind decides how the metadata given at mint time is validated: CEP-78 or
+//!   NFT-721 JSON documents, or raw strings;
+//! * the identifier mode decides whether tokens are identified by their mint order (`token_id`,
+//!   a `u64`) or by the hex-encoded blake2b hash of their metadata (`token_hash`, a `String`).
+//!
+//! Entry points taking a token declare the `token_id` or the `token_hash` argument depending on
+//! the identifier mode. Configuration and dictionaries use the CEP-78 named keys, and the
+//! dictionaries are keyed the CEP-78 way so that CEP-78 clients can read them: by token
+//! identifier, or by the hex-encoded account or contract hash of a token owner.
+use contract::{
+    contract_api::{
+        runtime,
+        storage::{self},
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use types::{
+    bytesrepr::{FromBytes, ToBytes},
+    contracts::NamedKeys,
+    ApiError, CLType, CLTyped, EntryPoint, EntryPoints, Key, Parameter, URef,
+};
+
+use crate::{
+    error::Error,
+    introspection::{interface_id, register_interface},
+    storage::{Mapping, Variable},
+    token::erc721::{error::Erc721Error, ERC721},
+    utils::{
+        contract_package_hash, endpoint, get_caller, get_optional_named_arg, require_holder_key,
+        ret,
+    },
+};
+
+struct ERC721EntryPoints {}
+
+impl ERC721EntryPoints {
+    /// Returns the `balance_of` entry point.
+    pub fn balance_of() -> EntryPoint {
+        endpoint(
+            "balance_of",
+            vec![Parameter::new("token_owner", CLType::Key)],
+            CLType::U64,
+        )
+    }
+
+    /// Returns the `owner_of` entry point.
+    pub fn owner_of(identifier_mode: NFTIdentifierMode) -> EntryPoint {
+        endpoint("owner_of", vec![identifier_mode.parameter()], CLType::Key)
+    }
+
+    /// Returns the `get_approved` entry point.
+    pub fn get_approved(identifier_mode: NFTIdentifierMode) -> EntryPoint {
+        endpoint(
+            "get_approved",
+            vec![identifier_mode.parameter()],
+            CLType::Option(Box::new(CLType::Key)),
+        )
+    }
+
+    /// Returns the `is_approved_for_all` entry point.
+    pub fn is_approved_for_all() -> EntryPoint {
+        endpoint(
+            "is_approved_for_all",
+            vec![
+                Parameter::new("token_owner", CLType::Key),
+                Parameter::new("operator", CLType::Key),
+            ],
+            CLType::Bool,
+        )
+    }
+
+    /// Returns the `metadata` entry point.
+    pub fn metadata(identifier_mode: NFTIdentifierMode) -> EntryPoint {
+        endpoint(
+            "metadata",
+            vec![identifier_mode.parameter()],
+            CLType::String,
+        )
+    }
+
+    /// Returns the `mint` entry point.
+    pub fn mint() -> EntryPoint {
+        endpoint(
+            "mint",
+            vec![
+                Parameter::new("token_owner", CLType::Key),
+                Parameter::new("token_meta_data", CLType::String),
+            ],
+            CLType::Unit,
+        )
+    }
+
+    /// Returns the `burn` entry point.
+    pub fn burn(identifier_mode: NFTIdentifierMode) -> EntryPoint {
+        endpoint("burn", vec![identifier_mode.parameter()], CLType::Unit)
+    }
+
+    /// Returns the `transfer` entry point.
+    pub fn transfer(identifier_mode: NFTIdentifierMode) -> EntryPoint {
+        endpoint(
+            "transfer",
+            vec![
+                identifier_mode.parameter(),
+                Parameter::new("source_key", CLType::Key),
+                Parameter::new("target_key", CLType::Key),
+            ],
+            CLType::Unit,
+        )
+    }
+
+    /// Returns the `approve` entry point.
+    pub fn approve(identifier_mode: NFTIdentifierMode) -> EntryPoint {
+        endpoint(
+            "approve",
+            vec![
+                identifier_mode.parameter(),
+                Parameter::new("operator", CLType::Key),
+            ],
+            CLType::Unit,
+        )
+    }
+
+    /// Returns the `set_approval_for_all` entry point.
+    pub fn set_approval_for_all() -> EntryPoint {
+        endpoint(
+            "set_approval_for_all",
+            vec![
+                Parameter::new("approve_all", CLType::Bool),
+                Parameter::new("operator", CLType::Key),
+            ],
+            CLType::Unit,
+        )
+    }
+
+    /// Returns the `set_token_metadata` entry point.
+    pub fn set_token_metadata(identifier_mode: NFTIdentifierMode) -> EntryPoint {
+        endpoint(
+            "set_token_metadata",
+            vec![
+                identifier_mode.parameter(),
+                Parameter::new("token_meta_data", CLType::String),
+            ],
+            CLType::Unit,
+        )
+    }
+}
+
+/// Defines a configuration mode of the collection, stored as a `u8` named key with the CEP-78
+/// value of each variant.
+macro_rules! nft_mode {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $value:literal,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[repr(u8)]
+        #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant = $value,
+            )*
+        }
+
+        impl $name {
+            pub fn from_u8(value: u8) -> Option<$name> {
+                match value {
+                    $($value => Some($name::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+nft_mode! {
+    /// Who may mint tokens.
+    pub enum MintingMode {
+        /// Only the installer of the collection.
+        Installer = 0,
+        /// Anyone.
+        Public = 1,
+    }
+}
+
+nft_mode! {
+    /// Who owns minted tokens and whether they can change hands.
+    pub enum OwnershipMode {
+        /// Tokens are owned by their minter and cannot be transferred.
+        Minter = 0,
+        /// Tokens are minted to any owner and cannot be transferred.
+        Assigned = 1,
+        /// Tokens are minted to any owner and can be transferred.
+        Transferable = 2,
+    }
+}
+
+nft_mode! {
+    /// What the tokens of the collection stand for.
+    pub enum NFTKind {
+        Physical = 0,
+        Digital = 1,
+        Virtual = 2,
+    }
+}
+
+nft_mode! {
+    /// How the metadata of the tokens is validated.
+    pub enum NFTMetadataKind {
+        /// JSON document with a `name`, a `token_uri` and a `checksum`.
+        CEP78 = 0,
+        /// JSON document with a `name`, a `symbol` and a `token_uri`.
+        NFT721 = 1,
+        /// Any string.
+        Raw = 2,
+    }
+}
+
+nft_mode! {
+    /// How tokens are identified.
+    pub enum NFTIdentifierMode {
+        /// By their mint order, starting at zero, given as the `token_id` argument.
+        Ordinal = 0,
+        /// By the hex-encoded blake2b hash of their metadata, given as the `token_hash` argument.
+        Hash = 1,
+    }
+}
+
+nft_mode! {
+    /// Whether the metadata of a token can be updated after mint.
+    pub enum MetadataMutability {
+        Immutable = 0,
+        Mutable = 1,
+    }
+}
+
+nft_mode! {
+    /// Whether owners can burn their tokens.
+    pub enum BurnMode {
+        Burnable = 0,
+        NonBurnable = 1,
+    }
+}
+
+impl NFTIdentifierMode {
+    /// Returns the parameter identifying a token in this mode.
+    pub fn parameter(&self) -> Parameter {
+        match self {
+            NFTIdentifierMode::Ordinal => Parameter::new("token_id", CLType::U64),
+            NFTIdentifierMode::Hash => Parameter::new("token_hash", CLType::String),
+        }
+    }
+}
+
+impl NFTMetadataKind {
+    /// Returns the named key of the dictionary holding the metadata of this kind.
+    pub fn dictionary_name(&self) -> &'static str {
+        match self {
+            NFTMetadataKind::CEP78 => ERC721::METADATA_CEP78_KEY,
+            NFTMetadataKind::NFT721 => ERC721::METADATA_NFT721_KEY,
+            NFTMetadataKind::Raw => ERC721::METADATA_RAW_KEY,
+        }
+    }
+
+    /// Returns `metadata` in canonical form, reverting if it does not follow this kind.
+    fn validate(&self, metadata: String) -> String {
+        let canonical = match self {
+            NFTMetadataKind::CEP78 => serde_json::from_str::<MetadataCEP78>(&metadata)
+                .and_then(|metadata| serde_json::to_string(&metadata)),
+            NFTMetadataKind::NFT721 => serde_json::from_str::<MetadataNFT721>(&metadata)
+                .and_then(|metadata| serde_json::to_string(&metadata)),
+            NFTMetadataKind::Raw => return metadata,
+        };
+        canonical
+            .ok()
+            .unwrap_or_revert_with(Erc721Error::InvalidMetadata)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MetadataCEP78 {
+    name: String,
+    token_uri: String,
+    checksum: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MetadataNFT721 {
+    name: String,
+    symbol: String,
+    token_uri: String,
+}
+
+/// Install-time configuration of a collection.
+pub struct ERC721Config {
+    pub collection_name: String,
+    pub collection_symbol: String,
+    pub total_token_supply: u64,
+    pub allow_minting: bool,
+    pub minting_mode: MintingMode,
+    pub ownership_mode: OwnershipMode,
+    pub nft_kind: NFTKind,
+    pub json_schema: String,
+    pub nft_metadata_kind: NFTMetadataKind,
+    pub identifier_mode: NFTIdentifierMode,
+    pub metadata_mutability: MetadataMutability,
+    pub burn_mode: BurnMode,
+}
+
+impl ERC721Config {
+    /// Reads the configuration from the CEP-78 install arguments. `allow_minting`,
+    /// `minting_mode`, `json_schema` and `burn_mode` are optional and default to allowing the
+    /// installer to mint burnable tokens.
+    pub fn from_named_args() -> ERC721Config {
+        fn mode<T>(name: &str, from_u8: fn(u8) -> Option<T>) -> T {
+            from_u8(runtime::get_named_arg(name))
+                .unwrap_or_revert_with(Erc721Error::InvalidConfiguration)
+        }
+
+        ERC721Config {
+            collection_name: runtime::get_named_arg("collection_name"),
+            collection_symbol: runtime::get_named_arg("collection_symbol"),
+            total_token_supply: runtime::get_named_arg("total_token_supply"),
+            allow_minting: get_optional_named_arg("allow_minting").unwrap_or(true),
+            minting_mode: get_optional_named_arg("minting_mode")
+                .map(|value| {
+                    MintingMode::from_u8(value)
+                        .unwrap_or_revert_with(Erc721Error::InvalidConfiguration)
+                })
+                .unwrap_or(MintingMode::Installer),
+            ownership_mode: mode("ownership_mode", OwnershipMode::from_u8),
+            nft_kind: mode("nft_kind", NFTKind::from_u8),
+            json_schema: get_optional_named_arg("json_schema").unwrap_or_default(),
+            nft_metadata_kind: mode("nft_metadata_kind", NFTMetadataKind::from_u8),
+            identifier_mode: mode("identifier_mode", NFTIdentifierMode::from_u8),
+            metadata_mutability: mode("metadata_mutability", MetadataMutability::from_u8),
+            burn_mode: get_optional_named_arg("burn_mode")
+                .map(|value| {
+                    BurnMode::from_u8(value)
+                        .unwrap_or_revert_with(Erc721Error::InvalidConfiguration)
+                })
+                .unwrap_or(BurnMode::Burnable),
+        }
+    }
+}
+
+pub enum ERC721Event {
+    Mint {
+        recipient: Key,
+        token_id: String,
+    },
+    Burn {
+        owner: Key,
+        token_id: String,
+    },
+    Approval {
+        owner: Key,
+        operator: Key,
+        token_id: String,
+    },
+    ApprovalForAll {
+        owner: Key,
+        operator: Key,
+        approved: bool,
+    },
+    Transfer {
+        owner: Key,
+        recipient: Key,
+        token_id: String,
+    },
+    MetadataUpdated {
+        token_id: String,
+        data: String,
+    },
+}
+
+impl ERC721Event {
+    pub fn type_name(&self) -> String {
+        match self {
+            ERC721Event::Mint {
+                recipient: _,
+                token_id: _,
+            } => "Mint",
+            ERC721Event::Burn {
+                owner: _,
+                token_id: _,
+            } => "Burn",
+            ERC721Event::Approval {
+                owner: _,
+                operator: _,
+                token_id: _,
+            } => "Approval",
+            ERC721Event::ApprovalForAll {
+                owner: _,
+                operator: _,
+                approved: _,
+            } => "ApprovalForAll",
+            ERC721Event::Transfer {
+                owner: _,
+                recipient: _,
+                token_id: _,
+            } => "Transfer",
+            ERC721Event::MetadataUpdated {
+                token_id: _,
+                data: _,
+            } => "MetadataUpdated",
+        }
+        .to_string()
+    }
+}
+
+impl ERC721 {
+    pub const COLLECTION_NAME_KEY: &'static str = "collection_name";
+    pub const COLLECTION_SYMBOL_KEY: &'static str = "collection_symbol";
+    pub const TOTAL_TOKEN_SUPPLY_KEY: &'static str = "total_token_supply";
+    pub const ALLOW_MINTING_KEY: &'static str = "allow_minting";
+    pub const MINTING_MODE_KEY: &'static str = "minting_mode";
+    pub const OWNERSHIP_MODE_KEY: &'static str = "ownership_mode";
+    pub const NFT_KIND_KEY: &'static str = "nft_kind";
+    pub const JSON_SCHEMA_KEY: &'static str = "json_schema";
+    pub const NFT_METADATA_KIND_KEY: &'static str = "nft_metadata_kind";
+    pub const IDENTIFIER_MODE_KEY: &'static str = "identifier_mode";
+    pub const METADATA_MUTABILITY_KEY: &'static str = "metadata_mutability";
+    pub const BURN_MODE_KEY: &'static str = "burn_mode";
+    pub const INSTALLER_KEY: &'static str = "installer";
+    pub const NUMBER_OF_MINTED_TOKENS_KEY: &'static str = "number_of_minted_tokens";
+    pub const TOKEN_OWNERS_KEY: &'static str = "token_owners";
+    pub const TOKEN_ISSUERS_KEY: &'static str = "token_issuers";
+    pub const BALANCES_KEY: &'static str = "balances";
+    pub const BURNT_TOKENS_KEY: &'static str = "burnt_tokens";
+    pub const APPROVED_KEY: &'static str = "approved";
+    pub const OPERATORS_KEY: &'static str = "operators";
+    pub const METADATA_CEP78_KEY: &'static str = "metadata_cep78";
+    pub const METADATA_NFT721_KEY: &'static str = "metadata_nft721";
+    pub const METADATA_RAW_KEY: &'static str = "metadata_raw";
+
+    /// Adds the entry points of the module, taking tokens as `identifier_mode` identifies them.
+    pub fn set_entry_points(
+        current_entry_points: &mut EntryPoints,
+        identifier_mode: NFTIdentifierMode,
+    ) -> &EntryPoints {
+        let entry_points = ERC721::entry_points(identifier_mode);
+        register_interface(&entry_points);
+        for entry_point in entry_points {
+            current_entry_points.add_entry_point(entry_point);
+        }
+
+        current_entry_points
+    }
+
+    /// Returns the entry points making up the ERC721 interface. Entry points taking a token
+    /// declare the `token_id` or the `token_hash` parameter depending on `identifier_mode`.
+    pub fn entry_points(identifier_mode: NFTIdentifierMode) -> Vec<EntryPoint> {
+        vec![
+            ERC721EntryPoints::balance_of(),
+            ERC721EntryPoints::owner_of(identifier_mode),
+            ERC721EntryPoints::get_approved(identifier_mode),
+            ERC721EntryPoints::is_approved_for_all(),
+            ERC721EntryPoints::metadata(identifier_mode),
+            ERC721EntryPoints::mint(),
+            ERC721EntryPoints::burn(identifier_mode),
+            ERC721EntryPoints::transfer(identifier_mode),
+            ERC721EntryPoints::approve(identifier_mode),
+            ERC721EntryPoints::set_approval_for_all(),
+            ERC721EntryPoints::set_token_metadata(identifier_mode),
+        ]
+    }
+
+    pub fn interface_id(identifier_mode: NFTIdentifierMode) -> u32 {
+        interface_id(&ERC721::entry_points(identifier_mode))
+    }
+
+    pub fn emit(erc721_event: &ERC721Event) {
+        let mut events = Vec::new();
+        let package = contract_package_hash();
+        match erc721_event {
+            ERC721Event::Mint {
+                recipient,
+                token_id,
+            } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_string());
+                event.insert("event_type", erc721_event.type_name());
+                event.insert("recipient", recipient.to_string());
+                event.insert("token_id", token_id.clone());
+                events.push(event);
+            }
+            ERC721Event::Burn { owner, token_id } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_string());
+                event.insert("event_type", erc721_event.type_name());
+                event.insert("owner", owner.to_string());
+                event.insert("token_id", token_id.clone());
+                events.push(event);
+            }
+            ERC721Event::Approval {
+                owner,
+                operator,
+                token_id,
+            } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_string());
+                event.insert("event_type", erc721_event.type_name());
+                event.insert("owner", owner.to_string());
+                event.insert("operator", operator.to_string());
+                event.insert("token_id", token_id.clone());
+                events.push(event);
+            }
+            ERC721Event::ApprovalForAll {
+                owner,
+                operator,
+                approved,
+            } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_string());
+                event.insert("event_type", erc721_event.type_name());
+                event.insert("owner", owner.to_string());
+                event.insert("operator", operator.to_string());
+                event.insert("approved", approved.to_string());
+                events.push(event);
+            }
+            ERC721Event::Transfer {
+                owner,
+                recipient,
+                token_id,
+            } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_string());
+                event.insert("event_type", erc721_event.type_name());
+                event.insert("owner", owner.to_string());
+                event.insert("recipient", recipient.to_string());
+                event.insert("token_id", token_id.clone());
+                events.push(event);
+            }
+            ERC721Event::MetadataUpdated { token_id, data } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_string());
+                event.insert("event_type", erc721_event.type_name());
+                event.insert("token_id", token_id.clone());
+                event.insert("data", data.clone());
+                events.push(event);
+            }
+        };
+        for event in events {
+            let _: URef = storage::new_uref(event);
+        }
+    }
+
+    /// Returns the item key of `owner` in the `balances` dictionary: the hex-encoded account or
+    /// contract hash, as CEP-78 clients compute it.
+    pub fn owner_item_key(owner: &Key) -> String {
+        require_holder_key(owner);
+        match owner {
+            Key::Account(account_hash) => hex::encode(account_hash.value()),
+            Key::Hash(hash) => hex::encode(hash),
+            _ => runtime::revert(Error::InvalidKeyVariant),
+        }
+    }
+
+    fn dictionary_seed_uref(name: &str) -> URef {
+        runtime::get_key(name)
+            .and_then(Key::into_uref)
+            .unwrap_or_revert_with(ApiError::MissingKey)
+    }
+
+    fn read<T: CLTyped + FromBytes>(dictionary_name: &str, item_key: &str) -> Option<T> {
+        storage::dictionary_get(ERC721::dictionary_seed_uref(dictionary_name), item_key)
+            .unwrap_or_revert()
+    }
+
+    fn write<T: CLTyped + ToBytes>(dictionary_name: &str, item_key: &str, value: T) {
+        storage::dictionary_put(
+            ERC721::dictionary_seed_uref(dictionary_name),
+            item_key,
+            value,
+        );
+    }
+
+    fn mode<T>(name: &'static str, from_u8: fn(u8) -> Option<T>) -> T {
+        Variable::<u8>::new(name)
+            .get()
+            .and_then(from_u8)
+            .unwrap_or_revert_with(Erc721Error::InvalidConfiguration)
+    }
+
+    pub fn ownership_mode() -> OwnershipMode {
+        ERC721::mode(ERC721::OWNERSHIP_MODE_KEY, OwnershipMode::from_u8)
+    }
+
+    pub fn nft_metadata_kind() -> NFTMetadataKind {
+        ERC721::mode(ERC721::NFT_METADATA_KIND_KEY, NFTMetadataKind::from_u8)
+    }
+
+    pub fn identifier_mode() -> NFTIdentifierMode {
+        ERC721::mode(ERC721::IDENTIFIER_MODE_KEY, NFTIdentifierMode::from_u8)
+    }
+
+    fn operators() -> Mapping<(Key, Key), bool> {
+        Mapping::new(ERC721::OPERATORS_KEY)
+    }
+
+    fn minted_tokens() -> Variable<u64> {
+        Variable::new(ERC721::NUMBER_OF_MINTED_TOKENS_KEY)
+    }
+
+    /// Returns the identifier of the token the entry point was called with: the `token_id`
+    /// argument as a string in the `Ordinal` identifier mode, the `token_hash` argument in the
+    /// `Hash` identifier mode.
+    pub fn token_identifier_arg() -> String {
+        match ERC721::identifier_mode() {
+            NFTIdentifierMode::Ordinal => runtime::get_named_arg::<u64>("token_id").to_string(),
+            NFTIdentifierMode::Hash => runtime::get_named_arg("token_hash"),
+        }
+    }
+
+    pub fn balance_of(token_owner: Key) -> u64 {
+        ERC721::read(ERC721::BALANCES_KEY, &ERC721::owner_item_key(&token_owner))
+            .unwrap_or_default()
+    }
+
+    pub fn ret_balance_of() {
+        let token_owner: Key = runtime::get_named_arg("token_owner");
+        ret(ERC721::balance_of(token_owner))
+    }
+
+    /// Returns the owner of `token_id`, reverting if it was never minted.
+    pub fn owner_of(token_id: &str) -> Key {
+        ERC721::read(ERC721::TOKEN_OWNERS_KEY, token_id)
+            .unwrap_or_revert_with(Erc721Error::InvalidTokenIdentifier)
+    }
+
+    pub fn ret_owner_of() {
+        ret(ERC721::owner_of(&ERC721::token_identifier_arg()))
+    }
+
+    pub fn is_burnt(token_id: &str) -> bool {
+        ERC721::read(ERC721::BURNT_TOKENS_KEY, token_id).unwrap_or_default()
+    }
+
+    /// Returns the owner of `token_id`, reverting if it was never minted or was burned.
+    fn live_owner_of(token_id: &str) -> Key {
+        let owner = ERC721::owner_of(token_id);
+        if ERC721::is_burnt(token_id) {
+            runtime::revert(Erc721Error::PreviouslyBurntToken);
+        }
+        owner
+    }
+
+    pub fn get_approved(token_id: &str) -> Option<Key> {
+        ERC721::live_owner_of(token_id);
+        ERC721::read::<Option<Key>>(ERC721::APPROVED_KEY, token_id).flatten()
+    }
+
+    pub fn ret_get_approved() {
+        ret(ERC721::get_approved(&ERC721::token_identifier_arg()))
+    }
+
+    pub fn is_approved_for_all(token_owner: Key, operator: Key) -> bool {
+        ERC721::operators().get_or_default(&(token_owner, operator))
+    }
+
+    pub fn ret_is_approved_for_all() {
+        let token_owner: Key = runtime::get_named_arg("token_owner");
+        let operator: Key = runtime::get_named_arg("operator");
+        ret(ERC721::is_approved_for_all(token_owner, operator))
+    }
+
+    pub fn metadata(token_id: &str) -> String {
+        ERC721::live_owner_of(token_id);
+        ERC721::read(ERC721::nft_metadata_kind().dictionary_name(), token_id).unwrap_or_default()
+    }
+
+    pub fn ret_metadata() {
+        ret(ERC721::metadata(&ERC721::token_identifier_arg()))
+    }
+
+    /// Mints a token to `token_owner`, identified by its mint order or by the hash of
+    /// `token_meta_data` depending on the identifier mode.
+    pub fn mint() {
+        let minter = get_caller();
+        let token_owner: Key = runtime::get_named_arg("token_owner");
+        let token_meta_data: String = runtime::get_named_arg("token_meta_data");
+
+        if !Variable::<bool>::new(ERC721::ALLOW_MINTING_KEY).get_or_default() {
+            runtime::revert(Erc721Error::MintingNotAllowed);
+        }
+        if ERC721::mode(ERC721::MINTING_MODE_KEY, MintingMode::from_u8) == MintingMode::Installer
+            && Variable::<Key>::new(ERC721::INSTALLER_KEY).get() != Some(minter)
+        {
+            runtime::revert(Erc721Error::InvalidMinter);
+        }
+        if ERC721::ownership_mode() == OwnershipMode::Minter && token_owner != minter {
+            runtime::revert(Erc721Error::InvalidTokenOwner);
+        }
+
+        ERC721::_mint(minter, token_owner, token_meta_data);
+    }
+
+    /// Burns the token, only callable by its owner.
+    pub fn burn() {
+        if ERC721::mode(ERC721::BURN_MODE_KEY, BurnMode::from_u8) == BurnMode::NonBurnable {
+            runtime::revert(Erc721Error::NonBurnable);
+        }
+        let token_id = ERC721::token_identifier_arg();
+        let owner = ERC721::live_owner_of(&token_id);
+        if owner != get_caller() {
+            runtime::revert(Erc721Error::InvalidTokenOperator);
+        }
+
+        ERC721::write(ERC721::BURNT_TOKENS_KEY, &token_id, true);
+        let owner_item_key = ERC721::owner_item_key(&owner);
+        ERC721::write(
+            ERC721::BALANCES_KEY,
+            &owner_item_key,
+            ERC721::balance_of(owner) - 1,
+        );
+
+        ERC721::emit(&ERC721Event::Burn { owner, token_id });
+    }
+
+    /// Transfers the token from `source_key` to `target_key`. Only callable by the owner of the
+    /// token, the account approved for it or an operator of the owner, and only in the
+    /// `Transferable` ownership mode.
+    pub fn transfer() {
+        if ERC721::ownership_mode() != OwnershipMode::Transferable {
+            runtime::revert(Erc721Error::NonTransferable);
+        }
+        let token_id = ERC721::token_identifier_arg();
+        let source_key: Key = runtime::get_named_arg("source_key");
+        let target_key: Key = runtime::get_named_arg("target_key");
+
+        let owner = ERC721::live_owner_of(&token_id);
+        if owner != source_key {
+            runtime::revert(Erc721Error::InvalidSourceKey);
+        }
+        ERC721::check_token_operator(owner, &token_id);
+
+        ERC721::_transfer(owner, target_key, token_id);
+    }
+
+    /// Approves `operator` to transfer the token. Only callable by the owner of the token or an
+    /// operator of the owner.
+    pub fn approve() {
+        if ERC721::ownership_mode() != OwnershipMode::Transferable {
+            runtime::revert(Erc721Error::NonTransferable);
+        }
+        let token_id = ERC721::token_identifier_arg();
+        let operator: Key = runtime::get_named_arg("operator");
+        require_holder_key(&operator);
+
+        let owner = ERC721::live_owner_of(&token_id);
+        let caller = get_caller();
+        if caller != owner && !ERC721::is_approved_for_all(owner, caller) {
+            runtime::revert(Erc721Error::InvalidTokenOperator);
+        }
+
+        ERC721::write(ERC721::APPROVED_KEY, &token_id, Some(operator));
+        ERC721::emit(&ERC721Event::Approval {
+            owner,
+            operator,
+            token_id,
+        });
+    }
+
+    /// Approves or revokes `operator` as an operator of every token of the caller.
+    pub fn set_approval_for_all() {
+        let owner = get_caller();
+        let approve_all: bool = runtime::get_named_arg("approve_all");
+        let operator: Key = runtime::get_named_arg("operator");
+        require_holder_key(&operator);
+
+        ERC721::operators().set(&(owner, operator), approve_all);
+        ERC721::emit(&ERC721Event::ApprovalForAll {
+            owner,
+            operator,
+            approved: approve_all,
+        });
+    }
+
+    /// Replaces the metadata of the token, only callable by its owner and only if the collection
+    /// has mutable metadata.
+    pub fn set_token_metadata() {
+        if ERC721::mode(ERC721::METADATA_MUTABILITY_KEY, MetadataMutability::from_u8)
+            != MetadataMutability::Mutable
+        {
+            runtime::revert(Erc721Error::ImmutableMetadata);
+        }
+        let token_id = ERC721::token_identifier_arg();
+        let token_meta_data: String = runtime::get_named_arg("token_meta_data");
+
+        if ERC721::live_owner_of(&token_id) != get_caller() {
+            runtime::revert(Erc721Error::InvalidTokenOperator);
+        }
+
+        let metadata_kind = ERC721::nft_metadata_kind();
+        let data = metadata_kind.validate(token_meta_data);
+        ERC721::write(metadata_kind.dictionary_name(), &token_id, data.clone());
+        ERC721::emit(&ERC721Event::MetadataUpdated { token_id, data });
+    }
+
+    /// Reverts unless the caller is `owner`, approved for `token_id` or an operator of `owner`.
+    fn check_token_operator(owner: Key, token_id: &str) {
+        let caller = get_caller();
+        if caller == owner
+            || ERC721::read::<Option<Key>>(ERC721::APPROVED_KEY, token_id).flatten() == Some(caller)
+            || ERC721::is_approved_for_all(owner, caller)
+        {
+            return;
+        }
+        runtime::revert(Erc721Error::InvalidTokenOperator);
+    }
+
+    pub fn _mint(minter: Key, token_owner: Key, token_meta_data: String) {
+        let owner_item_key = ERC721::owner_item_key(&token_owner);
+
+        let minted_tokens = ERC721::minted_tokens();
+        let number_of_minted_tokens = minted_tokens.get_or_default();
+        let total_token_supply: u64 =
+            Variable::new(ERC721::TOTAL_TOKEN_SUPPLY_KEY).get_or_default();
+        if number_of_minted_tokens >= total_token_supply {
+            runtime::revert(Erc721Error::TokenSupplyDepleted);
+        }
+
+        let metadata_kind = ERC721::nft_metadata_kind();
+        let token_id = match ERC721::identifier_mode() {
+            NFTIdentifierMode::Ordinal => number_of_minted_tokens.to_string(),
+            NFTIdentifierMode::Hash => hex::encode(runtime::blake2b(token_meta_data.as_bytes())),
+        };
+        if ERC721::read::<Key>(ERC721::TOKEN_OWNERS_KEY, &token_id).is_some() {
+            runtime::revert(Erc721Error::DuplicateIdentifier);
+        }
+        let data = metadata_kind.validate(token_meta_data);
+
+        ERC721::write(ERC721::TOKEN_OWNERS_KEY, &token_id, token_owner);
+        ERC721::write(ERC721::TOKEN_ISSUERS_KEY, &token_id, minter);
+        ERC721::write(metadata_kind.dictionary_name(), &token_id, data);
+        ERC721::write(
+            ERC721::BALANCES_KEY,
+            &owner_item_key,
+            ERC721::balance_of(token_owner) + 1,
+        );
+        minted_tokens.set(number_of_minted_tokens + 1);
+
+        ERC721::emit(&ERC721Event::Mint {
+            recipient: token_owner,
+            token_id,
+        });
+    }
+
+    pub fn _transfer(owner: Key, recipient: Key, token_id: String) {
+        let owner_item_key = ERC721::owner_item_key(&owner);
+        let recipient_item_key = ERC721::owner_item_key(&recipient);
+
+        ERC721::write(ERC721::TOKEN_OWNERS_KEY, &token_id, recipient);
+        ERC721::write::<Option<Key>>(ERC721::APPROVED_KEY, &token_id, None);
+        ERC721::write(
+            ERC721::BALANCES_KEY,
+            &owner_item_key,
+            ERC721::balance_of(owner) - 1,
+        );
+        // Read after the debit so that a transfer to oneself leaves the balance unchanged.
+        ERC721::write(
+            ERC721::BALANCES_KEY,
+            &recipient_item_key,
+            ERC721::balance_of(recipient) + 1,
+        );
+
+        ERC721::emit(&ERC721Event::Transfer {
+            owner,
+            recipient,
+            token_id,
+        });
+    }
+
+    /// Creates the CEP-78 named keys of a collection from session code and returns them for the
+    /// contract installation, `installer` being the account allowed to mint in the `Installer`
+    /// minting mode.
+    pub fn install_named_keys(config: ERC721Config, installer: Key) -> NamedKeys {
+        if config.total_token_supply == 0 {
+            runtime::revert(Erc721Error::ZeroTokenSupply);
+        }
+        if config.identifier_mode == NFTIdentifierMode::Hash
+            && config.metadata_mutability == MetadataMutability::Mutable
+        {
+            runtime::revert(Erc721Error::InvalidMetadataMutability);
+        }
+
+        let mut named_keys = NamedKeys::new();
+        named_keys.insert(
+            ERC721::COLLECTION_NAME_KEY.to_string(),
+            storage::new_uref(config.collection_name).into(),
+        );
+        named_keys.insert(
+            ERC721::COLLECTION_SYMBOL_KEY.to_string(),
+            storage::new_uref(config.collection_symbol).into(),
+        );
+        named_keys.insert(
+            ERC721::TOTAL_TOKEN_SUPPLY_KEY.to_string(),
+            storage::new_uref(config.total_token_supply).into(),
+        );
+        named_keys.insert(
+            ERC721::ALLOW_MINTING_KEY.to_string(),
+            storage::new_uref(config.allow_minting).into(),
+        );
+        named_keys.insert(
+            ERC721::MINTING_MODE_KEY.to_string(),
+            storage::new_uref(config.minting_mode as u8).into(),
+        );
+        named_keys.insert(
+            ERC721::OWNERSHIP_MODE_KEY.to_string(),
+            storage::new_uref(config.ownership_mode as u8).into(),
+        );
+        named_keys.insert(
+            ERC721::NFT_KIND_KEY.to_string(),
+            storage::new_uref(config.nft_kind as u8).into(),
+        );
+        named_keys.insert(
+            ERC721::JSON_SCHEMA_KEY.to_string(),
+            storage::new_uref(config.json_schema).into(),
+        );
+        named_keys.insert(
+            ERC721::NFT_METADATA_KIND_KEY.to_string(),
+            storage::new_uref(config.nft_metadata_kind as u8).into(),
+        );
+        named_keys.insert(
+            ERC721::IDENTIFIER_MODE_KEY.to_string(),
+            storage::new_uref(config.identifier_mode as u8).into(),
+        );
+        named_keys.insert(
+            ERC721::METADATA_MUTABILITY_KEY.to_string(),
+            storage::new_uref(config.metadata_mutability as u8).into(),
+        );
+        named_keys.insert(
+            ERC721::BURN_MODE_KEY.to_string(),
+            storage::new_uref(config.burn_mode as u8).into(),
+        );
+        named_keys.insert(
+            ERC721::INSTALLER_KEY.to_string(),
+            storage::new_uref(installer).into(),
+        );
+        named_keys.insert(
+            ERC721::NUMBER_OF_MINTED_TOKENS_KEY.to_string(),
+            storage::new_uref(0u64).into(),
+        );
+
+        for dictionary_name in [
+            ERC721::TOKEN_OWNERS_KEY,
+            ERC721::TOKEN_ISSUERS_KEY,
+            ERC721::BALANCES_KEY,
+            ERC721::BURNT_TOKENS_KEY,
+            ERC721::APPROVED_KEY,
+            ERC721::OPERATORS_KEY,
+            ERC721::METADATA_CEP78_KEY,
+            ERC721::METADATA_NFT721_KEY,
+            ERC721::METADATA_RAW_KEY,
+        ]
+        .iter()
+        {
+            let seed_uref = storage::new_dictionary(dictionary_name).unwrap_or_revert();
+            runtime::remove_key(dictionary_name);
+            named_keys.insert(dictionary_name.to_string(), seed_uref.into());
+        }
+        named_keys
+    }
+}
@@ -0,0 +1,39 @@
+use crate::error::module_error;
+
+module_error! {
+    /// Errors returned by the ERC721 module.
+    pub enum Erc721Error: 64500..=64599 {
+        /// Install argument is not a valid mode of the collection.
+        InvalidConfiguration = 0,
+        /// Tokens identified by the hash of their metadata cannot have mutable metadata.
+        InvalidMetadataMutability = 1,
+        /// Collection cannot be installed with a total token supply of zero.
+        ZeroTokenSupply = 2,
+        /// Minting is disabled for the collection.
+        MintingNotAllowed = 3,
+        /// Only the installer can mint tokens of the collection.
+        InvalidMinter = 4,
+        /// Every token of the total token supply was already minted.
+        TokenSupplyDepleted = 5,
+        /// Minted tokens must be owned by their minter in the `Minter` ownership mode.
+        InvalidTokenOwner = 6,
+        /// Metadata does not follow the metadata kind of the collection.
+        InvalidMetadata = 7,
+        /// A token with the same hash identifier was already minted.
+        DuplicateIdentifier = 8,
+        /// No token was minted with the given identifier.
+        InvalidTokenIdentifier = 9,
+        /// Token was burned.
+        PreviouslyBurntToken = 10,
+        /// Tokens of the collection cannot be burned.
+        NonBurnable = 11,
+        /// Caller neither owns nor is approved to manage the token.
+        InvalidTokenOperator = 12,
+        /// Tokens of the collection cannot be transferred.
+        NonTransferable = 13,
+        /// Source key does not own the token.
+        InvalidSourceKey = 14,
+        /// Metadata of the collection tokens cannot be updated.
+        ImmutableMetadata = 15,
+    }
+}
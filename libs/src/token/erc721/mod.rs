@@ -0,0 +1,4 @@
+pub mod erc721;
+pub mod error;
+
+pub struct ERC721 {}
@@ -0,0 +1,11 @@
+use crate::error::module_error;
+
+module_error! {
+    /// Errors returned by the Merkle airdrop module.
+    pub enum MerkleAirdropError: 65000..=65099 {
+        /// The airdrop of the given index was already claimed.
+        AlreadyClaimed = 0,
+        /// The proof does not match the Merkle root of the airdrop.
+        InvalidProof = 1,
+    }
+}
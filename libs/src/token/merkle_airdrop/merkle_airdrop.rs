@@ -0,0 +1,189 @@
+use contract::{
+    contract_api::{
+        runtime,
+        storage::{self},
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use std::collections::BTreeMap;
+use types::{CLType, ContractHash, EntryPoint, EntryPoints, Key, Parameter, URef, U256};
+
+use crate::{
+    merkle::{self, Hash},
+    storage::{Mapping, Variable},
+    token::{
+        erc20::ERC20,
+        merkle_airdrop::{error::MerkleAirdropError, MerkleAirdrop},
+    },
+    utils::{contract_package_hash, endpoint, ret},
+};
+
+struct MerkleAirdropEntryPoints {}
+
+impl MerkleAirdropEntryPoints {
+    /// Returns the `token` entry point.
+    pub fn token() -> EntryPoint {
+        endpoint("token", vec![], CLType::Key)
+    }
+
+    /// Returns the `merkle_root` entry point.
+    pub fn merkle_root() -> EntryPoint {
+        endpoint("merkle_root", vec![], CLType::ByteArray(32))
+    }
+
+    /// Returns the `is_claimed` entry point.
+    pub fn is_claimed() -> EntryPoint {
+        endpoint(
+            "is_claimed",
+            vec![Parameter::new("index", CLType::U64)],
+            CLType::Bool,
+        )
+    }
+
+    /// Returns the `claim` entry point.
+    pub fn claim() -> EntryPoint {
+        endpoint(
+            "claim",
+            vec![
+                Parameter::new("index", CLType::U64),
+                Parameter::new("account", CLType::Key),
+                Parameter::new("amount", CLType::U256),
+                Parameter::new("proof", CLType::List(Box::new(CLType::ByteArray(32)))),
+            ],
+            CLType::Unit,
+        )
+    }
+}
+
+pub enum MerkleAirdropEvent {
+    Claimed {
+        index: u64,
+        account: Key,
+        amount: U256,
+    },
+}
+
+impl MerkleAirdropEvent {
+    pub fn type_name(&self) -> String {
+        match self {
+            MerkleAirdropEvent::Claimed {
+                index: _,
+                account: _,
+                amount: _,
+            } => "claimed",
+        }
+        .to_string()
+    }
+}
+
+impl MerkleAirdrop {
+    pub const TOKEN_KEY: &'static str = "token";
+    pub const MERKLE_ROOT_KEY: &'static str = "merkle_root";
+    pub const CLAIMED_KEY: &'static str = "claimed";
+
+    pub fn set_entry_points(current_entry_points: &mut EntryPoints) -> &EntryPoints {
+        current_entry_points.add_entry_point(MerkleAirdropEntryPoints::token());
+        current_entry_points.add_entry_point(MerkleAirdropEntryPoints::merkle_root());
+        current_entry_points.add_entry_point(MerkleAirdropEntryPoints::is_claimed());
+        current_entry_points.add_entry_point(MerkleAirdropEntryPoints::claim());
+
+        current_entry_points
+    }
+
+    pub fn emit(merkle_airdrop_event: &MerkleAirdropEvent) {
+        let mut events = Vec::new();
+        let package = contract_package_hash();
+        match merkle_airdrop_event {
+            MerkleAirdropEvent::Claimed {
+                index,
+                account,
+                amount,
+            } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_string());
+                event.insert("event_type", merkle_airdrop_event.type_name());
+                event.insert("index", index.to_string());
+                event.insert("account", account.to_string());
+                event.insert("amount", amount.to_string());
+                events.push(event);
+            }
+        };
+        for event in events {
+            let _: URef = storage::new_uref(event);
+        }
+    }
+
+    /// Claimed flags, packed 256 indexes per dictionary item.
+    fn claimed() -> Mapping<u64, U256> {
+        Mapping::new(MerkleAirdrop::CLAIMED_KEY)
+    }
+
+    pub fn token() -> Key {
+        Variable::new(MerkleAirdrop::TOKEN_KEY)
+            .get()
+            .unwrap_or_revert()
+    }
+
+    pub fn ret_token() {
+        ret(MerkleAirdrop::token())
+    }
+
+    pub fn merkle_root() -> Hash {
+        Variable::new(MerkleAirdrop::MERKLE_ROOT_KEY)
+            .get()
+            .unwrap_or_revert()
+    }
+
+    pub fn ret_merkle_root() {
+        ret(MerkleAirdrop::merkle_root())
+    }
+
+    pub fn is_claimed(index: u64) -> bool {
+        let word = MerkleAirdrop::claimed().get_or_default(&(index / 256));
+        !(word & (U256::one() << (index % 256))).is_zero()
+    }
+
+    pub fn ret_is_claimed() {
+        let index: u64 = runtime::get_named_arg("index");
+        ret(MerkleAirdrop::is_claimed(index))
+    }
+
+    /// Returns the leaf of the airdrop of `amount` tokens to `account` at `index`: the
+    /// [`merkle::hash_leaf`] of the serialized `(index, account, amount)` tuple.
+    pub fn leaf(index: u64, account: Key, amount: U256) -> Hash {
+        merkle::hash_leaf(&(index, account, amount))
+    }
+
+    pub fn claim() {
+        let index: u64 = runtime::get_named_arg("index");
+        let account: Key = runtime::get_named_arg("account");
+        let amount: U256 = runtime::get_named_arg("amount");
+        let proof: Vec<Hash> = runtime::get_named_arg("proof");
+
+        if MerkleAirdrop::is_claimed(index) {
+            runtime::revert(MerkleAirdropError::AlreadyClaimed);
+        }
+        let leaf = MerkleAirdrop::leaf(index, account, amount);
+        if !merkle::verify(&proof, MerkleAirdrop::merkle_root(), leaf) {
+            runtime::revert(MerkleAirdropError::InvalidProof);
+        }
+
+        // Flag the claim before paying out so that a reentrant call cannot claim it twice.
+        MerkleAirdrop::_set_claimed(index);
+
+        let token = ContractHash::new(MerkleAirdrop::token().into_hash().unwrap_or_revert());
+        ERC20::call_transfer(token, account, amount);
+
+        MerkleAirdrop::emit(&MerkleAirdropEvent::Claimed {
+            index,
+            account,
+            amount,
+        });
+    }
+
+    fn _set_claimed(index: u64) {
+        let claimed = MerkleAirdrop::claimed();
+        let word = claimed.get_or_default(&(index / 256));
+        claimed.set(&(index / 256), word | (U256::one() << (index % 256)));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod error;
+pub mod merkle_airdrop;
+
+pub struct MerkleAirdrop {}
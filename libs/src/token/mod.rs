@@ -1 +1,3 @@
 pub mod erc20;
+pub mod erc721;
+pub mod merkle_airdrop;
@@ -0,0 +1,139 @@
+//! Install/upgrade split for contracts stored in a contract package.
+//!
+//! Every contract installed through [`install`] stores the following named keys under the
+//! deployer's account, where `<name>` is the contract name given by the session code:
+//! * `<name>` - hash of the current contract version.
+//! * `<name>_hash` - `URef` to the hash of the current contract version.
+//! * `<name>_package_hash` - hash of the contract package.
+//! * `<name>_access_token` - access `URef` of the contract package.
+//! * `<name>_contract_version` - `URef` to the current contract version number.
+//! * `<name>_interfaces` - `URef` to the interfaces supported by the contract, shared with the
+//!   contract.
+//!
+//! When the session code is executed again by the same deployer, [`upgrade`] adds a new version
+//! to the existing package. The execution engine carries the named keys of the previous version
+//! over to the new one, so dictionaries such as ERC20 `balances` survive the upgrade. Named keys
+//! passed to [`upgrade`] are only used for keys the previous version did not have.
+//!
+//! Both also record the interfaces registered while building the entry points, see
+//! [`crate::introspection`]. [`upgrade`] overwrites the list shared with the contract, so the
+//! interfaces of the previous version don't carry over.
+use contract::{
+    contract_api::{
+        runtime,
+        storage::{self, create_contract_package_at_hash},
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{contracts::NamedKeys, ContractHash, ContractPackageHash, EntryPoints, Key};
+
+use crate::{
+    introspection::{registered_interfaces, INTERFACES_KEY},
+    utils::CONTRACT_PACKAGE_HASH_KEY,
+};
+
+fn hash_key_name(contract_name: &str) -> String {
+    [contract_name, "_hash"].join("")
+}
+
+fn package_hash_key_name(contract_name: &str) -> String {
+    [contract_name, "_package_hash"].join("")
+}
+
+fn access_token_key_name(contract_name: &str) -> String {
+    [contract_name, "_access_token"].join("")
+}
+
+fn contract_version_key_name(contract_name: &str) -> String {
+    [contract_name, "_contract_version"].join("")
+}
+
+fn interfaces_key_name(contract_name: &str) -> String {
+    [contract_name, "_interfaces"].join("")
+}
+
+/// Records the registered interfaces in the `URef` shared by the deployer and the contract,
+/// creating it under `named_keys` if the contract doesn't have one yet.
+fn store_interfaces(contract_name: &str, named_keys: &mut NamedKeys) {
+    match runtime::get_key(&interfaces_key_name(contract_name)) {
+        Some(key) => storage::write(key.into_uref().unwrap_or_revert(), registered_interfaces()),
+        None => {
+            let interfaces_uref = storage::new_uref(registered_interfaces());
+            named_keys.insert(INTERFACES_KEY.to_string(), interfaces_uref.into());
+            runtime::put_key(&interfaces_key_name(contract_name), interfaces_uref.into());
+        }
+    }
+}
+
+/// Returns the package hash of `contract_name` if the caller already installed it.
+pub fn installed_package_hash(contract_name: &str) -> Option<ContractPackageHash> {
+    runtime::get_key(&package_hash_key_name(contract_name))
+        .and_then(Key::into_hash)
+        .map(ContractPackageHash::new)
+}
+
+fn store_contract_version(contract_name: &str, contract_hash: ContractHash, contract_version: u32) {
+    runtime::put_key(contract_name, contract_hash.into());
+    runtime::put_key(
+        &hash_key_name(contract_name),
+        storage::new_uref(contract_hash).into(),
+    );
+    runtime::put_key(
+        &contract_version_key_name(contract_name),
+        storage::new_uref(contract_version).into(),
+    );
+}
+
+/// Creates a new contract package and adds its first version.
+pub fn install(
+    contract_name: &str,
+    entry_points: EntryPoints,
+    mut named_keys: NamedKeys,
+) -> ContractHash {
+    let (contract_package_hash, access_uref) = create_contract_package_at_hash();
+    named_keys.insert(
+        CONTRACT_PACKAGE_HASH_KEY.to_string(),
+        storage::new_uref(contract_package_hash).into(),
+    );
+    store_interfaces(contract_name, &mut named_keys);
+
+    // Add new version to the package.
+    let (contract_hash, contract_version) =
+        storage::add_contract_version(contract_package_hash, entry_points, named_keys);
+    store_contract_version(contract_name, contract_hash, contract_version);
+    runtime::put_key(
+        &package_hash_key_name(contract_name),
+        contract_package_hash.into(),
+    );
+    runtime::put_key(&access_token_key_name(contract_name), access_uref.into());
+
+    contract_hash
+}
+
+/// Adds a new version to the package of `contract_name` installed by the caller, optionally
+/// disabling the previous version so it can no longer be called.
+pub fn upgrade(
+    contract_name: &str,
+    entry_points: EntryPoints,
+    mut named_keys: NamedKeys,
+    disable_previous: bool,
+) -> ContractHash {
+    let contract_package_hash = installed_package_hash(contract_name).unwrap_or_revert();
+    let previous_contract_hash = runtime::get_key(contract_name)
+        .and_then(Key::into_hash)
+        .map(ContractHash::new)
+        .unwrap_or_revert();
+    store_interfaces(contract_name, &mut named_keys);
+
+    // Add new version to the package.
+    let (contract_hash, contract_version) =
+        storage::add_contract_version(contract_package_hash, entry_points, named_keys);
+    store_contract_version(contract_name, contract_hash, contract_version);
+
+    if disable_previous {
+        storage::disable_contract_version(contract_package_hash, previous_contract_hash)
+            .unwrap_or_revert();
+    }
+
+    contract_hash
+}
@@ -8,19 +8,24 @@ use contract::{
         runtime,
         storage::{self},
     },
+    ext_ffi,
     unwrap_or_revert::UnwrapOrRevert,
 };
 use core::convert::TryInto;
 use types::{
-    bytesrepr::{FromBytes, ToBytes},
+    account::AccountHash,
+    api_error,
+    bytesrepr::{self, FromBytes, ToBytes},
     system::CallStackElement,
-    CLType, CLTyped, CLValue, ContractPackageHash, EntryPoint, EntryPointAccess, EntryPointType,
-    Key, Parameter, URef,
+    ApiError, CLType, CLTyped, CLValue, ContractHash, ContractPackageHash, EntryPoint,
+    EntryPointAccess, EntryPointType, Key, Parameter, URef,
 };
 
 use crate::error::Error;
 
 pub const CONTRACT_PACKAGE_HASH_KEY: &str = "contract_package_hash";
+pub const CALLER_IDENTITY_KEY: &str = "_caller_identity";
+pub const TRUSTED_FORWARDERS_KEY: &str = "_trusted_forwarders";
 
 pub fn ret<T: CLTyped + ToBytes>(value: T) {
     runtime::ret(CLValue::from_t(value).unwrap_or_revert())
@@ -60,7 +65,15 @@ pub fn set_key<T: ToBytes + CLTyped>(name: &str, value: T) {
 }
 
 /// Returns the immediate caller address, whether it's an account or a contract.
+///
+/// Same as [`immediate_caller`].
 pub fn get_caller() -> Key {
+    immediate_caller()
+}
+
+/// Returns the immediate caller address: the package hash of the calling contract, or the
+/// account hash when called from session code, stored session code included.
+pub fn immediate_caller() -> Key {
     let mut callstack = runtime::get_call_stack();
     callstack.pop();
     match callstack
@@ -81,6 +94,166 @@ pub fn get_caller() -> Key {
     }
 }
 
+/// Returns the account which signed the deploy, whatever contracts the call went through.
+pub fn origin_account() -> Key {
+    match runtime::get_call_stack()
+        .first()
+        .ok_or(Error::InvalidContext)
+        .unwrap_or_revert()
+    {
+        CallStackElement::Session { account_hash }
+        | CallStackElement::StoredSession {
+            account_hash,
+            contract_package_hash: _,
+            contract_hash: _,
+        } => (*account_hash).into(),
+        CallStackElement::StoredContract { .. } => runtime::revert(Error::InvalidContext),
+    }
+}
+
+/// Returns the hash of the contract version directly calling the current entry point, `None`
+/// when called from session code.
+pub fn calling_contract_hash() -> Option<ContractHash> {
+    let mut callstack = runtime::get_call_stack();
+    callstack.pop();
+    match callstack
+        .last()
+        .ok_or(Error::InvalidContext)
+        .unwrap_or_revert()
+    {
+        CallStackElement::Session { account_hash: _ } => None,
+        CallStackElement::StoredSession {
+            account_hash: _,
+            contract_package_hash: _,
+            contract_hash,
+        }
+        | CallStackElement::StoredContract {
+            contract_package_hash: _,
+            contract_hash,
+        } => Some(*contract_hash),
+    }
+}
+
+/// Identity authorized by the entry points of a contract, recorded under the
+/// [`CALLER_IDENTITY_KEY`] named key.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallerIdentity {
+    /// The account or contract package directly calling the entry point, see
+    /// [`immediate_caller`]. This is the default.
+    ImmediateCaller = 0,
+    /// The account which signed the deploy, see [`origin_account`], when the entry point is
+    /// called through one of the [`trusted_forwarders`] by the session code of that account.
+    /// Any other caller is authorized as itself, otherwise any contract the account calls could
+    /// move its funds.
+    OriginAccount = 1,
+}
+
+impl CallerIdentity {
+    pub fn from_u8(value: u8) -> Option<CallerIdentity> {
+        match value {
+            0 => Some(CallerIdentity::ImmediateCaller),
+            1 => Some(CallerIdentity::OriginAccount),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the identity authorized by the current contract.
+pub fn caller_identity() -> CallerIdentity {
+    CallerIdentity::from_u8(get_key(CALLER_IDENTITY_KEY))
+        .ok_or(ApiError::InvalidArgument)
+        .unwrap_or_revert()
+}
+
+pub fn set_caller_identity(identity: CallerIdentity) {
+    set_key(CALLER_IDENTITY_KEY, identity as u8);
+}
+
+/// Returns the package hashes of the contracts forwarding calls on behalf of the account which
+/// signed the deploy, recorded under the [`TRUSTED_FORWARDERS_KEY`] named key.
+pub fn trusted_forwarders() -> Vec<Key> {
+    get_key(TRUSTED_FORWARDERS_KEY)
+}
+
+/// Returns whether the current entry point is called by a trusted forwarder, itself directly
+/// called by session code.
+fn is_called_by_trusted_forwarder() -> bool {
+    match runtime::get_call_stack().as_slice() {
+        [_, CallStackElement::StoredContract {
+            contract_package_hash,
+            contract_hash: _,
+        }, _] => trusted_forwarders().contains(&Key::from(*contract_package_hash)),
+        _ => false,
+    }
+}
+
+/// Returns the caller address following the identity configured by the current contract.
+pub fn authorized_caller() -> Key {
+    match caller_identity() {
+        CallerIdentity::OriginAccount if is_called_by_trusted_forwarder() => origin_account(),
+        _ => immediate_caller(),
+    }
+}
+
+/// Returns the value of the named argument `name`, `None` if it was not passed.
+pub fn get_optional_named_arg<T: FromBytes>(name: &str) -> Option<T> {
+    let mut arg_size: usize = 0;
+    let ret = unsafe {
+        ext_ffi::casper_get_named_arg_size(
+            name.as_bytes().as_ptr(),
+            name.len(),
+            &mut arg_size as *mut usize,
+        )
+    };
+    match api_error::result_from(ret) {
+        Ok(()) => {}
+        Err(ApiError::MissingArgument) => return None,
+        Err(error) => runtime::revert(error),
+    }
+
+    let mut arg_bytes = vec![0u8; arg_size];
+    if arg_size > 0 {
+        let ret = unsafe {
+            ext_ffi::casper_get_named_arg(
+                name.as_bytes().as_ptr(),
+                name.len(),
+                arg_bytes.as_mut_ptr(),
+                arg_size,
+            )
+        };
+        api_error::result_from(ret).unwrap_or_revert();
+    }
+    Some(bytesrepr::deserialize(arg_bytes).unwrap_or_revert_with(ApiError::InvalidArgument))
+}
+
+/// Returns whether `key` is the zero address of its variant: an account, contract or `URef`
+/// address made of zero bytes.
+pub fn is_zero_key(key: &Key) -> bool {
+    match key {
+        Key::Account(account_hash) => *account_hash == AccountHash::default(),
+        Key::Hash(hash) => *hash == [0u8; 32],
+        Key::URef(uref) => uref.addr() == [0u8; 32],
+        _ => false,
+    }
+}
+
+/// Returns whether `key` is a variant able to hold tokens, ownership or roles, i.e. an account
+/// or a contract as returned by [`get_caller`].
+pub fn is_holder_key(key: &Key) -> bool {
+    matches!(key, Key::Account(_) | Key::Hash(_))
+}
+
+/// Reverts unless `key` is a non-zero account or contract key.
+pub fn require_holder_key(key: &Key) {
+    if is_zero_key(key) {
+        runtime::revert(Error::ZeroAddress);
+    }
+    if !is_holder_key(key) {
+        runtime::revert(Error::InvalidKeyVariant);
+    }
+}
+
 pub fn contract_package_hash() -> ContractPackageHash {
     get_key::<ContractPackageHash>(CONTRACT_PACKAGE_HASH_KEY)
 }
@@ -98,12 +271,8 @@ pub fn endpoint(name: &str, param: Vec<Parameter>, ret: CLType) -> EntryPoint {
 fn get_dictionary_seed_uref(name: &str) -> URef {
     match runtime::get_key(name) {
         Some(key) => key.into_uref().unwrap_or_revert(),
-        None => {
-            let new_dict = storage::new_dictionary(name).unwrap_or_revert();
-            let key = storage::new_uref(new_dict).into();
-            runtime::put_key(name, key);
-            new_dict
-        }
+        // `new_dictionary` also stores the seed `URef` under the `name` named key.
+        None => storage::new_dictionary(name).unwrap_or_revert(),
     }
 }
 
@@ -119,7 +288,9 @@ pub fn set<T: ToBytes + CLTyped>(dictionary_name: &str, key: &str, value: T) {
     storage::dictionary_put(dictionary_seed_uref, key, value)
 }
 
-pub fn key_to_str(key: &Key) -> String {
-    let preimage = key.to_bytes().unwrap_or_revert();
-    base64::encode(&preimage)
+/// Derives a dictionary item key from any serializable value, such as a `Key` or a tuple of
+/// values: the hex-encoded blake2b hash of its bytes, always 64 characters long.
+pub fn make_dictionary_item_key<T: ToBytes>(value: &T) -> String {
+    let preimage = value.to_bytes().unwrap_or_revert();
+    hex::encode(runtime::blake2b(preimage))
 }
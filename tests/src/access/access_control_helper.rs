@@ -0,0 +1,233 @@
+use crate::{
+    caller_identity::proxy_helper::Proxy,
+    utilities::{make_dictionary_item_key, CasperHelper, Hash, Sender},
+};
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U256};
+use libs::access::AccessControl as AccessControlLib;
+use std::path::PathBuf;
+
+// contains methods that can simulate a real-world deployment (storing the contract in the blockchain)
+// and transactions to invoke the methods in the contract.
+pub const ACCESS_CONTROL_CONTRACT_KEY_NAME: &str = "AccessControl";
+
+pub struct AccessControl {
+    pub helper: CasperHelper,
+    pub hash: Hash,
+    pub ali: AccountHash,
+    pub bob: AccountHash,
+    pub joe: AccountHash,
+}
+
+impl AccessControl {
+    pub fn deployed() -> AccessControl {
+        AccessControl::deployed_with_role_names(vec![])
+    }
+
+    /// Deploys the AccessControl contract, defining roles with the given names.
+    pub fn deployed_with_role_names(role_names: Vec<String>) -> AccessControl {
+        let mut helper = CasperHelper::new();
+
+        // ====================== CONTRACT DEPLOYMENT ======================
+        let session_code = PathBuf::from("access_control.wasm");
+        let session_args = runtime_args! {
+            "default_admin" => Key::Account(helper.accounts[0]),
+            "role_names" => role_names
+        };
+
+        let hash = helper.deploy_contract(
+            session_code,
+            session_args,
+            helper.keys[0].clone(),
+            ACCESS_CONTROL_CONTRACT_KEY_NAME.to_string(),
+        );
+
+        // ====================== FUNCTION RETURN ======================
+        AccessControl {
+            helper: helper.clone(),
+            hash,
+            ali: helper.accounts[0].clone(),
+            bob: helper.accounts[1].clone(),
+            joe: helper.accounts[2].clone(),
+        }
+    }
+
+    pub fn get_role_names(&self) -> Vec<String> {
+        self.helper
+            .query_contract(
+                ACCESS_CONTROL_CONTRACT_KEY_NAME.to_string(),
+                AccessControlLib::ACCESS_ROLE_NAME_LIST_KEY,
+            )
+            .unwrap_or_default()
+    }
+
+    pub fn get_role_name(&self, role: U256) -> Option<String> {
+        self.helper.query_dictionary_value(
+            self.hash,
+            AccessControlLib::ACCESS_ROLE_NAMES_KEY,
+            make_dictionary_item_key(&role),
+        )
+    }
+
+    /// Returns the membership entry of `account` for `role`, whatever the expiration of the grant:
+    /// see [`AccessControl::has_role_at`] for whether the role is held at a given time.
+    pub fn is_member(&self, role: U256, account: Key) -> bool {
+        self.helper
+            .query_dictionary_value(
+                self.hash,
+                AccessControlLib::ACCESS_ROLE_MEMBER_KEY,
+                make_dictionary_item_key(&(role, account)),
+            )
+            .unwrap_or_default()
+    }
+
+    /// Returns what the `has_role` entry point answers, called through `proxy` in a block with
+    /// the given `block_time`.
+    pub fn has_role_at(
+        &mut self,
+        proxy: &Proxy,
+        role: U256,
+        account: Key,
+        block_time: u64,
+    ) -> bool {
+        proxy.forward_query_at(
+            &mut self.helper,
+            self.hash,
+            "has_role",
+            runtime_args! {
+                "role" => role,
+                "account" => account
+            },
+            block_time,
+        )
+    }
+
+    pub fn grant_role(&mut self, role: U256, account: Key, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "grant_role",
+            runtime_args! {
+                "role" => role,
+                "account" => account
+            },
+        );
+    }
+
+    /// Grants a role in a block with the given `block_time`.
+    pub fn grant_role_at(&mut self, role: U256, account: Key, block_time: u64, sender: Sender) {
+        self.helper.call_at(
+            self.hash,
+            sender,
+            "grant_role",
+            runtime_args! {
+                "role" => role,
+                "account" => account
+            },
+            block_time,
+        );
+    }
+
+    pub fn grant_role_until(&mut self, role: U256, account: Key, expires_at: u64, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "grant_role_until",
+            runtime_args! {
+                "role" => role,
+                "account" => account,
+                "expires_at" => expires_at
+            },
+        );
+    }
+
+    pub fn get_role_expiration(&self, role: U256, account: Key) -> u64 {
+        self.helper
+            .query_dictionary_value(
+                self.hash,
+                AccessControlLib::ACCESS_ROLE_EXPIRATION_KEY,
+                make_dictionary_item_key(&(role, account)),
+            )
+            .unwrap_or_default()
+    }
+
+    pub fn grant_roles(&mut self, role: U256, accounts: Vec<Key>, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "grant_roles",
+            runtime_args! {
+                "role" => role,
+                "accounts" => accounts
+            },
+        );
+    }
+
+    pub fn revoke_roles(&mut self, role: U256, accounts: Vec<Key>, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "revoke_roles",
+            runtime_args! {
+                "role" => role,
+                "accounts" => accounts
+            },
+        );
+    }
+
+    pub fn grant_role_batch(&mut self, grants: Vec<(U256, Key)>, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "grant_role_batch",
+            runtime_args! {
+                "grants" => grants
+            },
+        );
+    }
+
+    pub fn revoke_role(&mut self, role: U256, account: Key, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "revoke_role",
+            runtime_args! {
+                "role" => role,
+                "account" => account
+            },
+        );
+    }
+
+    pub fn renounce_role(&mut self, role: U256, account: Key, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "renounce_role",
+            runtime_args! {
+                "role" => role,
+                "account" => account
+            },
+        );
+    }
+
+    pub fn get_role_admin(&self, role: U256) -> U256 {
+        self.helper
+            .query_dictionary_value(
+                self.hash,
+                AccessControlLib::ACCESS_ROLE_ADMIN_KEY,
+                make_dictionary_item_key(&role),
+            )
+            .unwrap_or_default()
+    }
+
+    pub fn set_role_admin(&mut self, role: U256, admin_role: U256, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "set_role_admin",
+            runtime_args! {
+                "role" => role,
+                "admin_role" => admin_role
+            },
+        );
+    }
+}
@@ -0,0 +1,545 @@
+use casper_types::{
+    account::AccountHash, runtime_args, AccessRights, Key, RuntimeArgs, URef, U256,
+};
+use libs::{
+    access::{error::AccessControlError, AccessControl as AccessControlLib},
+    error::Error,
+};
+use std::path::PathBuf;
+
+use crate::{
+    access::{
+        access_control_helper::AccessControl,
+        access_manager_helper::ACCESS_MANAGER_CONTRACT_KEY_NAME,
+    },
+    caller_identity::proxy_helper::Proxy,
+    utilities::{blake2b256, make_dictionary_item_key, to_key, CasperHelper, Sender},
+};
+
+// ------------ START - AccessControl Tests ------------
+
+#[test]
+fn should_deploy_access_control() {
+    let contract = AccessControl::deployed();
+    assert!(contract.is_member(AccessControlLib::DEFAULT_ADMIN_ROLE, to_key(contract.ali)));
+    assert!(!contract.is_member(AccessControlLib::DEFAULT_ADMIN_ROLE, to_key(contract.bob)));
+}
+
+#[test]
+fn should_not_deploy_access_control_with_zero_default_admin() {
+    let mut helper = CasperHelper::new();
+    helper.deploy_contract_expect_error(
+        PathBuf::from("access_control.wasm"),
+        runtime_args! {
+            "default_admin" => Key::Account(AccountHash::default())
+        },
+        helper.keys[0].clone(),
+        Error::ZeroAddress,
+    );
+}
+
+#[test]
+fn should_install_another_access_control_contract_from_the_same_account() {
+    let mut contract = AccessControl::deployed();
+    let ali = contract.ali;
+    let manager_hash = contract.helper.deploy_contract(
+        PathBuf::from("access_manager.wasm"),
+        runtime_args! {
+            "default_admin" => Key::Account(ali)
+        },
+        contract.helper.keys[0].clone(),
+        ACCESS_MANAGER_CONTRACT_KEY_NAME.to_string(),
+    );
+
+    assert_ne!(manager_hash, contract.hash);
+    assert!(contract.is_member(AccessControlLib::DEFAULT_ADMIN_ROLE, to_key(ali)));
+}
+
+#[test]
+fn should_store_members_under_access_control_members_key() {
+    let mut contract = AccessControl::deployed();
+    let role = U256::from(1);
+    let item_key = make_dictionary_item_key(&(role, to_key(contract.bob)));
+    let is_member = |contract: &AccessControl| -> Option<bool> {
+        contract.helper.query_dictionary_value(
+            contract.hash,
+            "_access_control_members",
+            item_key.clone(),
+        )
+    };
+    assert_eq!(is_member(&contract), None);
+
+    contract.grant_role(role, to_key(contract.bob), Sender(contract.ali));
+    assert_eq!(is_member(&contract), Some(true));
+
+    contract.revoke_role(role, to_key(contract.bob), Sender(contract.ali));
+    assert_eq!(is_member(&contract), Some(false));
+}
+
+#[test]
+fn should_grant_role() {
+    let mut contract = AccessControl::deployed();
+    let role = U256::from(1);
+    contract.grant_role(role, to_key(contract.bob), Sender(contract.ali));
+    assert!(contract.is_member(role, to_key(contract.bob)));
+    assert!(!contract.is_member(role, to_key(contract.joe)));
+
+    // Granting a role twice keeps the account a member.
+    contract.grant_role(role, to_key(contract.bob), Sender(contract.ali));
+    assert!(contract.is_member(role, to_key(contract.bob)));
+}
+
+#[test]
+fn should_revoke_role() {
+    let mut contract = AccessControl::deployed();
+    let role = U256::from(1);
+    contract.grant_role(role, to_key(contract.bob), Sender(contract.ali));
+    contract.revoke_role(role, to_key(contract.bob), Sender(contract.ali));
+    assert!(!contract.is_member(role, to_key(contract.bob)));
+}
+
+#[test]
+fn should_not_grant_role_by_unauthorized_caller() {
+    let mut contract = AccessControl::deployed();
+    let role = U256::from(1);
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.bob),
+        "grant_role",
+        runtime_args! {
+            "role" => role,
+            "account" => to_key(contract.joe)
+        },
+        AccessControlError::MissingRole,
+    );
+    assert!(!contract.is_member(role, to_key(contract.joe)));
+}
+
+#[test]
+fn should_not_revoke_role_by_unauthorized_caller() {
+    let mut contract = AccessControl::deployed();
+    let role = U256::from(1);
+    contract.grant_role(role, to_key(contract.joe), Sender(contract.ali));
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.bob),
+        "revoke_role",
+        runtime_args! {
+            "role" => role,
+            "account" => to_key(contract.joe)
+        },
+        AccessControlError::MissingRole,
+    );
+    assert!(contract.is_member(role, to_key(contract.joe)));
+}
+
+#[test]
+fn should_renounce_role() {
+    let mut contract = AccessControl::deployed();
+    let role = U256::from(1);
+    contract.grant_role(role, to_key(contract.bob), Sender(contract.ali));
+    contract.renounce_role(role, to_key(contract.bob), Sender(contract.bob));
+    assert!(!contract.is_member(role, to_key(contract.bob)));
+}
+
+#[test]
+fn should_renounce_default_admin_role() {
+    let mut contract = AccessControl::deployed();
+    contract.renounce_role(
+        AccessControlLib::DEFAULT_ADMIN_ROLE,
+        to_key(contract.ali),
+        Sender(contract.ali),
+    );
+    assert!(!contract.is_member(AccessControlLib::DEFAULT_ADMIN_ROLE, to_key(contract.ali)));
+
+    // The former admin can no longer grant roles.
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "grant_role",
+        runtime_args! {
+            "role" => U256::from(1),
+            "account" => to_key(contract.bob)
+        },
+        AccessControlError::MissingRole,
+    );
+}
+
+#[test]
+fn should_not_renounce_role_for_another_account() {
+    let mut contract = AccessControl::deployed();
+    let role = U256::from(1);
+    contract.grant_role(role, to_key(contract.bob), Sender(contract.ali));
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "renounce_role",
+        runtime_args! {
+            "role" => role,
+            "account" => to_key(contract.bob)
+        },
+        AccessControlError::CanOnlyRenounceForSelf,
+    );
+    assert!(contract.is_member(role, to_key(contract.bob)));
+}
+
+#[test]
+fn should_grant_and_revoke_large_role() {
+    let mut contract = AccessControl::deployed();
+    let role = U256::MAX;
+
+    contract.grant_role(role, to_key(contract.bob), Sender(contract.ali));
+    assert!(contract.is_member(role, to_key(contract.bob)));
+    assert!(!contract.is_member(role - 1, to_key(contract.bob)));
+
+    contract.revoke_role(role, to_key(contract.bob), Sender(contract.ali));
+    assert!(!contract.is_member(role, to_key(contract.bob)));
+}
+
+#[test]
+fn should_not_grant_role_to_invalid_key_variant() {
+    let mut contract = AccessControl::deployed();
+    let account = Key::URef(URef::new([1u8; 32], AccessRights::READ));
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "grant_role",
+        runtime_args! {
+            "role" => U256::one(),
+            "account" => account
+        },
+        Error::InvalidKeyVariant,
+    );
+    assert!(!contract.is_member(U256::one(), account));
+}
+
+#[test]
+fn should_set_role_admin() {
+    let mut contract = AccessControl::deployed();
+    let role = U256::from(1);
+    let admin_role = U256::from(2);
+    assert_eq!(
+        contract.get_role_admin(role),
+        AccessControlLib::DEFAULT_ADMIN_ROLE
+    );
+
+    contract.set_role_admin(role, admin_role, Sender(contract.ali));
+    assert_eq!(contract.get_role_admin(role), admin_role);
+
+    // Members of the new admin role can grant the role.
+    contract.grant_role(admin_role, to_key(contract.bob), Sender(contract.ali));
+    contract.grant_role(role, to_key(contract.joe), Sender(contract.bob));
+    assert!(contract.is_member(role, to_key(contract.joe)));
+
+    // Members of the previous admin role no longer can.
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "revoke_role",
+        runtime_args! {
+            "role" => role,
+            "account" => to_key(contract.joe)
+        },
+        AccessControlError::MissingRole,
+    );
+}
+
+#[test]
+fn should_set_role_admin_as_current_admin() {
+    let mut contract = AccessControl::deployed();
+    let role = U256::from(1);
+    let admin_role = U256::from(2);
+    let next_admin_role = U256::from(3);
+    contract.set_role_admin(role, admin_role, Sender(contract.ali));
+    contract.grant_role(admin_role, to_key(contract.bob), Sender(contract.ali));
+
+    contract.set_role_admin(role, next_admin_role, Sender(contract.bob));
+    assert_eq!(contract.get_role_admin(role), next_admin_role);
+}
+
+#[test]
+fn should_not_set_role_admin_without_admin_role() {
+    let mut contract = AccessControl::deployed();
+    let role = U256::from(1);
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.bob),
+        "set_role_admin",
+        runtime_args! {
+            "role" => role,
+            "admin_role" => U256::from(2)
+        },
+        AccessControlError::MissingRole,
+    );
+    assert_eq!(
+        contract.get_role_admin(role),
+        AccessControlLib::DEFAULT_ADMIN_ROLE
+    );
+}
+
+#[test]
+fn should_grant_role_until() {
+    let mut contract = AccessControl::deployed();
+    let role = U256::from(1);
+    contract.grant_role_until(role, to_key(contract.bob), 1_000, Sender(contract.ali));
+    assert!(contract.is_member(role, to_key(contract.bob)));
+    assert_eq!(
+        contract.get_role_expiration(role, to_key(contract.bob)),
+        1_000
+    );
+}
+
+#[test]
+fn should_expire_role_grant() {
+    let mut contract = AccessControl::deployed();
+    let role = U256::from(1);
+    contract.grant_role_until(
+        AccessControlLib::DEFAULT_ADMIN_ROLE,
+        to_key(contract.bob),
+        1_000,
+        Sender(contract.ali),
+    );
+
+    contract.grant_role_at(role, to_key(contract.joe), 999, Sender(contract.bob));
+    assert!(contract.is_member(role, to_key(contract.joe)));
+
+    let proxy = Proxy::deploy(&mut contract.helper);
+    let bob = to_key(contract.bob);
+    assert!(contract.has_role_at(&proxy, AccessControlLib::DEFAULT_ADMIN_ROLE, bob, 999));
+    assert!(!contract.has_role_at(&proxy, AccessControlLib::DEFAULT_ADMIN_ROLE, bob, 1_000));
+    // the membership entry of an expired grant is left in place.
+    assert!(contract.is_member(AccessControlLib::DEFAULT_ADMIN_ROLE, bob));
+
+    contract.helper.call_expect_error_at(
+        contract.hash,
+        Sender(contract.bob),
+        "revoke_role",
+        runtime_args! {
+            "role" => role,
+            "account" => to_key(contract.joe)
+        },
+        1_000,
+        AccessControlError::MissingRole,
+    );
+    // the grant to joe doesn't expire, so the expiry of bob's admin role left it in place.
+    assert!(contract.has_role_at(&proxy, role, to_key(contract.joe), 1_000));
+}
+
+#[test]
+fn should_not_grant_role_until_past_time() {
+    let mut contract = AccessControl::deployed();
+    let role = U256::from(1);
+
+    // calls without a block time run at time 0.
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "grant_role_until",
+        runtime_args! {
+            "role" => role,
+            "account" => to_key(contract.bob),
+            "expires_at" => 0u64
+        },
+        AccessControlError::ExpiryInPast,
+    );
+    assert!(!contract.is_member(role, to_key(contract.bob)));
+}
+
+#[test]
+fn should_not_grant_role_until_by_unauthorized_caller() {
+    let mut contract = AccessControl::deployed();
+    let role = U256::from(1);
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.bob),
+        "grant_role_until",
+        runtime_args! {
+            "role" => role,
+            "account" => to_key(contract.joe),
+            "expires_at" => 1_000u64
+        },
+        AccessControlError::MissingRole,
+    );
+    assert!(!contract.is_member(role, to_key(contract.joe)));
+}
+
+#[test]
+fn should_make_time_bounded_grant_permanent() {
+    let mut contract = AccessControl::deployed();
+    contract.grant_role_until(
+        AccessControlLib::DEFAULT_ADMIN_ROLE,
+        to_key(contract.bob),
+        1_000,
+        Sender(contract.ali),
+    );
+    contract.grant_role(
+        AccessControlLib::DEFAULT_ADMIN_ROLE,
+        to_key(contract.bob),
+        Sender(contract.ali),
+    );
+    assert_eq!(
+        contract.get_role_expiration(AccessControlLib::DEFAULT_ADMIN_ROLE, to_key(contract.bob)),
+        0
+    );
+
+    contract.grant_role_at(
+        U256::from(1),
+        to_key(contract.joe),
+        2_000,
+        Sender(contract.bob),
+    );
+    assert!(contract.is_member(U256::from(1), to_key(contract.joe)));
+}
+
+#[test]
+fn should_revoke_time_bounded_grant() {
+    let mut contract = AccessControl::deployed();
+    let role = U256::from(1);
+    contract.grant_role_until(role, to_key(contract.bob), 1_000, Sender(contract.ali));
+    contract.revoke_role(role, to_key(contract.bob), Sender(contract.ali));
+    assert!(!contract.is_member(role, to_key(contract.bob)));
+    assert_eq!(contract.get_role_expiration(role, to_key(contract.bob)), 0);
+}
+
+#[test]
+fn should_derive_role_ids_from_names() {
+    assert_eq!(
+        AccessControlLib::role_id(AccessControlLib::DEFAULT_ADMIN_ROLE_NAME),
+        AccessControlLib::DEFAULT_ADMIN_ROLE
+    );
+    let minter_role = AccessControlLib::role_id("MINTER_ROLE");
+    assert_eq!(
+        minter_role,
+        U256::from_big_endian(&blake2b256("MINTER_ROLE".as_bytes()))
+    );
+    assert_ne!(minter_role, AccessControlLib::DEFAULT_ADMIN_ROLE);
+    assert_eq!(minter_role, AccessControlLib::role_id("MINTER_ROLE"));
+    assert_ne!(minter_role, AccessControlLib::role_id("PAUSER_ROLE"));
+}
+
+#[test]
+fn should_register_role_names() {
+    let contract = AccessControl::deployed_with_role_names(vec![
+        "MINTER_ROLE".to_string(),
+        "PAUSER_ROLE".to_string(),
+        "MINTER_ROLE".to_string(),
+    ]);
+    assert_eq!(
+        contract.get_role_names(),
+        vec!["DEFAULT_ADMIN_ROLE", "MINTER_ROLE", "PAUSER_ROLE"]
+    );
+    assert_eq!(
+        contract.get_role_name(AccessControlLib::DEFAULT_ADMIN_ROLE),
+        Some("DEFAULT_ADMIN_ROLE".to_string())
+    );
+    assert_eq!(
+        contract.get_role_name(AccessControlLib::role_id("MINTER_ROLE")),
+        Some("MINTER_ROLE".to_string())
+    );
+    assert_eq!(
+        contract.get_role_name(AccessControlLib::role_id("BURNER_ROLE")),
+        None
+    );
+}
+
+#[test]
+fn should_register_default_admin_role_name_only() {
+    let contract = AccessControl::deployed();
+    assert_eq!(contract.get_role_names(), vec!["DEFAULT_ADMIN_ROLE"]);
+}
+
+#[test]
+fn should_grant_named_role() {
+    let mut contract = AccessControl::deployed_with_role_names(vec!["MINTER_ROLE".to_string()]);
+    let minter_role = AccessControlLib::role_id("MINTER_ROLE");
+    contract.grant_role(minter_role, to_key(contract.bob), Sender(contract.ali));
+    assert!(contract.is_member(minter_role, to_key(contract.bob)));
+}
+
+#[test]
+fn should_grant_and_revoke_roles() {
+    let mut contract = AccessControl::deployed();
+    let role = U256::from(1);
+    let accounts = vec![to_key(contract.bob), to_key(contract.joe)];
+
+    contract.grant_roles(role, accounts.clone(), Sender(contract.ali));
+    assert!(contract.is_member(role, to_key(contract.bob)));
+    assert!(contract.is_member(role, to_key(contract.joe)));
+
+    contract.revoke_roles(role, accounts, Sender(contract.ali));
+    assert!(!contract.is_member(role, to_key(contract.bob)));
+    assert!(!contract.is_member(role, to_key(contract.joe)));
+}
+
+#[test]
+fn should_not_grant_roles_by_unauthorized_caller() {
+    let mut contract = AccessControl::deployed();
+    let role = U256::from(1);
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.bob),
+        "grant_roles",
+        runtime_args! {
+            "role" => role,
+            "accounts" => vec![to_key(contract.bob), to_key(contract.joe)]
+        },
+        AccessControlError::MissingRole,
+    );
+    assert!(!contract.is_member(role, to_key(contract.bob)));
+}
+
+#[test]
+fn should_not_revoke_roles_by_unauthorized_caller() {
+    let mut contract = AccessControl::deployed();
+    let role = U256::from(1);
+    contract.grant_role(role, to_key(contract.joe), Sender(contract.ali));
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.bob),
+        "revoke_roles",
+        runtime_args! {
+            "role" => role,
+            "accounts" => vec![to_key(contract.joe)]
+        },
+        AccessControlError::MissingRole,
+    );
+    assert!(contract.is_member(role, to_key(contract.joe)));
+}
+
+#[test]
+fn should_grant_role_batch() {
+    let mut contract = AccessControl::deployed();
+    contract.grant_role_batch(
+        vec![
+            (U256::from(1), to_key(contract.bob)),
+            (U256::from(2), to_key(contract.joe)),
+        ],
+        Sender(contract.ali),
+    );
+    assert!(contract.is_member(U256::from(1), to_key(contract.bob)));
+    assert!(contract.is_member(U256::from(2), to_key(contract.joe)));
+    assert!(!contract.is_member(U256::from(2), to_key(contract.bob)));
+}
+
+#[test]
+fn should_not_grant_role_batch_without_every_admin_role() {
+    let mut contract = AccessControl::deployed();
+    let admin_role = U256::from(3);
+    contract.set_role_admin(U256::from(2), admin_role, Sender(contract.ali));
+
+    // ali is not a member of the admin role of the second grant, so none is applied.
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "grant_role_batch",
+        runtime_args! {
+            "grants" => vec![
+                (U256::from(1), to_key(contract.bob)),
+                (U256::from(2), to_key(contract.joe)),
+            ]
+        },
+        AccessControlError::MissingRole,
+    );
+    assert!(!contract.is_member(U256::from(1), to_key(contract.bob)));
+    assert!(!contract.is_member(U256::from(2), to_key(contract.joe)));
+}
@@ -0,0 +1,159 @@
+use crate::utilities::{make_dictionary_item_key, CasperHelper, Hash, Sender};
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U256};
+use libs::access::AccessManager as AccessManagerLib;
+use std::path::PathBuf;
+
+// contains methods that can simulate a real-world deployment (storing the contract in the blockchain)
+// and transactions to invoke the methods in the contract.
+pub const ACCESS_MANAGER_CONTRACT_KEY_NAME: &str = "AccessManager";
+pub const ACCESS_MANAGED_CONTRACT_KEY_NAME: &str = "AccessManaged";
+pub const ACCESS_MANAGED_PACKAGE_HASH_KEY_NAME: &str = "AccessManaged_package_hash";
+
+pub struct AccessManager {
+    pub helper: CasperHelper,
+    pub hash: Hash,
+    pub managed_hash: Hash,
+    pub managed_package_hash: Hash,
+    pub ali: AccountHash,
+    pub bob: AccountHash,
+    pub joe: AccountHash,
+}
+
+impl AccessManager {
+    /// Deploys an access manager administered by `ali` and a contract managed by it.
+    pub fn deployed() -> AccessManager {
+        let mut helper = CasperHelper::new();
+        let ali = helper.accounts[0];
+
+        // ====================== CONTRACT DEPLOYMENT ======================
+        let session_code = PathBuf::from("access_manager.wasm");
+        let session_args = runtime_args! {
+            "default_admin" => Key::Account(ali)
+        };
+
+        let hash = helper.deploy_contract(
+            session_code,
+            session_args,
+            helper.keys[0].clone(),
+            ACCESS_MANAGER_CONTRACT_KEY_NAME.to_string(),
+        );
+
+        let session_code = PathBuf::from("access_managed.wasm");
+        let session_args = runtime_args! {
+            "authority" => Key::Hash(hash)
+        };
+
+        let managed_hash = helper.deploy_contract(
+            session_code,
+            session_args,
+            helper.keys[0].clone(),
+            ACCESS_MANAGED_CONTRACT_KEY_NAME.to_string(),
+        );
+        let managed_package_hash =
+            helper.get_contract_hash(ali, ACCESS_MANAGED_PACKAGE_HASH_KEY_NAME);
+
+        // ====================== FUNCTION RETURN ======================
+        AccessManager {
+            helper: helper.clone(),
+            hash,
+            managed_hash,
+            managed_package_hash,
+            ali,
+            bob: helper.accounts[1].clone(),
+            joe: helper.accounts[2].clone(),
+        }
+    }
+
+    /// Returns the address under which the managed contract is registered as a target.
+    pub fn target(&self) -> Key {
+        Key::Hash(self.managed_package_hash)
+    }
+
+    pub fn counter(&self) -> u64 {
+        self.helper
+            .query_contract(ACCESS_MANAGED_CONTRACT_KEY_NAME.to_string(), "counter")
+            .unwrap_or_default()
+    }
+
+    pub fn get_target_function_role(&self, selector: &str) -> U256 {
+        self.helper
+            .query_dictionary_value(
+                self.hash,
+                AccessManagerLib::FUNCTION_ROLES_KEY,
+                make_dictionary_item_key(&(self.target(), selector.to_string())),
+            )
+            .unwrap_or_default()
+    }
+
+    pub fn get_schedule(&self, caller: Key, selector: &str) -> u64 {
+        self.helper
+            .query_dictionary_value(
+                self.hash,
+                AccessManagerLib::SCHEDULES_KEY,
+                make_dictionary_item_key(&(caller, self.target(), selector.to_string())),
+            )
+            .unwrap_or_default()
+    }
+
+    pub fn grant_role(&mut self, role: U256, account: Key, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "grant_role",
+            runtime_args! {
+                "role" => role,
+                "account" => account
+            },
+        );
+    }
+
+    pub fn set_target_function_role(&mut self, selector: &str, role: U256, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "set_target_function_role",
+            runtime_args! {
+                "target" => self.target(),
+                "selector" => selector.to_string(),
+                "role" => role
+            },
+        );
+    }
+
+    pub fn set_role_execution_delay(&mut self, role: U256, delay: u64, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "set_role_execution_delay",
+            runtime_args! {
+                "role" => role,
+                "delay" => delay
+            },
+        );
+    }
+
+    /// Schedules a call in a block with the given `block_time`.
+    pub fn schedule_at(&mut self, selector: &str, block_time: u64, sender: Sender) {
+        self.helper.call_at(
+            self.hash,
+            sender,
+            "schedule",
+            runtime_args! {
+                "target" => self.target(),
+                "selector" => selector.to_string()
+            },
+            block_time,
+        );
+    }
+
+    /// Calls an entry point of the managed contract in a block with the given `block_time`.
+    pub fn call_managed_at(&mut self, selector: &str, block_time: u64, sender: Sender) {
+        self.helper.call_at(
+            self.managed_hash,
+            sender,
+            selector,
+            runtime_args! {},
+            block_time,
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use casper_types::{runtime_args, RuntimeArgs, U256};
+use libs::access::{
+    error::{AccessControlError, AccessManagerError},
+    AccessManager as AccessManagerLib,
+};
+
+use crate::{
+    access::access_manager_helper::AccessManager,
+    utilities::{to_key, Sender},
+};
+
+const OPERATOR_ROLE: U256 = U256([1, 0, 0, 0]);
+const DELAY: u64 = 1_000;
+
+// ------------ START - AccessManager Tests ------------
+
+#[test]
+fn should_restrict_entry_points_to_admins_by_default() {
+    let mut contract = AccessManager::deployed();
+    contract.call_managed_at("increment", 0, Sender(contract.ali));
+    assert_eq!(contract.counter(), 1);
+
+    contract.helper.call_expect_error(
+        contract.managed_hash,
+        Sender(contract.bob),
+        "increment",
+        runtime_args! {},
+        AccessManagerError::UnauthorizedCall,
+    );
+    assert_eq!(contract.counter(), 1);
+}
+
+#[test]
+fn should_set_target_function_role() {
+    let mut contract = AccessManager::deployed();
+    contract.set_target_function_role("increment", OPERATOR_ROLE, Sender(contract.ali));
+    assert_eq!(
+        contract.get_target_function_role("increment"),
+        OPERATOR_ROLE
+    );
+
+    contract.grant_role(OPERATOR_ROLE, to_key(contract.bob), Sender(contract.ali));
+    contract.call_managed_at("increment", 0, Sender(contract.bob));
+    assert_eq!(contract.counter(), 1);
+
+    // Other entry points still require the admin role.
+    contract.helper.call_expect_error(
+        contract.managed_hash,
+        Sender(contract.bob),
+        "reset",
+        runtime_args! {},
+        AccessManagerError::UnauthorizedCall,
+    );
+}
+
+#[test]
+fn should_not_call_without_target_function_role() {
+    let mut contract = AccessManager::deployed();
+    contract.set_target_function_role("increment", OPERATOR_ROLE, Sender(contract.ali));
+
+    // Admins are not members of the function role.
+    contract.helper.call_expect_error(
+        contract.managed_hash,
+        Sender(contract.ali),
+        "increment",
+        runtime_args! {},
+        AccessManagerError::UnauthorizedCall,
+    );
+    assert_eq!(contract.counter(), 0);
+}
+
+#[test]
+fn should_let_anyone_call_public_entry_points() {
+    let mut contract = AccessManager::deployed();
+    contract.set_target_function_role(
+        "increment",
+        AccessManagerLib::PUBLIC_ROLE,
+        Sender(contract.ali),
+    );
+    contract.call_managed_at("increment", 0, Sender(contract.joe));
+    assert_eq!(contract.counter(), 1);
+}
+
+#[test]
+fn should_not_set_target_function_role_by_non_admin() {
+    let mut contract = AccessManager::deployed();
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.bob),
+        "set_target_function_role",
+        runtime_args! {
+            "target" => contract.target(),
+            "selector" => "increment".to_string(),
+            "role" => AccessManagerLib::PUBLIC_ROLE
+        },
+        AccessControlError::MissingRole,
+    );
+}
+
+#[test]
+fn should_not_set_role_execution_delay_by_non_admin() {
+    let mut contract = AccessManager::deployed();
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.bob),
+        "set_role_execution_delay",
+        runtime_args! {
+            "role" => OPERATOR_ROLE,
+            "delay" => DELAY
+        },
+        AccessControlError::MissingRole,
+    );
+}
+
+#[test]
+fn should_call_after_execution_delay() {
+    let mut contract = AccessManager::deployed();
+    contract.set_target_function_role("increment", OPERATOR_ROLE, Sender(contract.ali));
+    contract.set_role_execution_delay(OPERATOR_ROLE, DELAY, Sender(contract.ali));
+    contract.grant_role(OPERATOR_ROLE, to_key(contract.bob), Sender(contract.ali));
+
+    contract.schedule_at("increment", 0, Sender(contract.bob));
+    assert_eq!(
+        contract.get_schedule(to_key(contract.bob), "increment"),
+        DELAY
+    );
+
+    // calls without a block time run at time 0.
+    contract.helper.call_expect_error(
+        contract.managed_hash,
+        Sender(contract.bob),
+        "increment",
+        runtime_args! {},
+        AccessManagerError::NotReady,
+    );
+
+    contract.call_managed_at("increment", DELAY, Sender(contract.bob));
+    assert_eq!(contract.counter(), 1);
+    assert_eq!(contract.get_schedule(to_key(contract.bob), "increment"), 0);
+}
+
+#[test]
+fn should_not_call_delayed_entry_point_without_schedule() {
+    let mut contract = AccessManager::deployed();
+    contract.set_target_function_role("increment", OPERATOR_ROLE, Sender(contract.ali));
+    contract.set_role_execution_delay(OPERATOR_ROLE, DELAY, Sender(contract.ali));
+    contract.grant_role(OPERATOR_ROLE, to_key(contract.bob), Sender(contract.ali));
+
+    contract.helper.call_expect_error(
+        contract.managed_hash,
+        Sender(contract.bob),
+        "increment",
+        runtime_args! {},
+        AccessManagerError::NotScheduled,
+    );
+}
+
+#[test]
+fn should_consume_scheduled_call_once() {
+    let mut contract = AccessManager::deployed();
+    contract.set_target_function_role("increment", OPERATOR_ROLE, Sender(contract.ali));
+    contract.set_role_execution_delay(OPERATOR_ROLE, DELAY, Sender(contract.ali));
+    contract.grant_role(OPERATOR_ROLE, to_key(contract.bob), Sender(contract.ali));
+
+    contract.schedule_at("increment", 0, Sender(contract.bob));
+    contract.call_managed_at("increment", DELAY, Sender(contract.bob));
+
+    contract.helper.call_expect_error(
+        contract.managed_hash,
+        Sender(contract.bob),
+        "increment",
+        runtime_args! {},
+        AccessManagerError::NotScheduled,
+    );
+    assert_eq!(contract.counter(), 1);
+}
+
+#[test]
+fn should_not_schedule_without_execution_delay() {
+    let mut contract = AccessManager::deployed();
+    contract.set_target_function_role("increment", OPERATOR_ROLE, Sender(contract.ali));
+    contract.grant_role(OPERATOR_ROLE, to_key(contract.bob), Sender(contract.ali));
+
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.bob),
+        "schedule",
+        runtime_args! {
+            "target" => contract.target(),
+            "selector" => "increment".to_string()
+        },
+        AccessManagerError::UnauthorizedCall,
+    );
+}
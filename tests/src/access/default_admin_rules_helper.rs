@@ -0,0 +1,147 @@
+use crate::utilities::{make_dictionary_item_key, CasperHelper, Hash, Sender};
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U256};
+use libs::access::{AccessControl as AccessControlLib, AccessControlDefaultAdminRules};
+use std::path::PathBuf;
+
+// contains methods that can simulate a real-world deployment (storing the contract in the blockchain)
+// and transactions to invoke the methods in the contract.
+pub const DEFAULT_ADMIN_RULES_CONTRACT_KEY_NAME: &str = "DefaultAdminRules";
+
+pub struct DefaultAdminRules {
+    pub helper: CasperHelper,
+    pub hash: Hash,
+    pub ali: AccountHash,
+    pub bob: AccountHash,
+    pub joe: AccountHash,
+}
+
+impl DefaultAdminRules {
+    /// Deploys the contract with `ali` as default admin and the given default admin delay.
+    pub fn deployed(default_admin_delay: u64) -> DefaultAdminRules {
+        let mut helper = CasperHelper::new();
+
+        // ====================== CONTRACT DEPLOYMENT ======================
+        let session_code = PathBuf::from("default_admin_rules.wasm");
+        let session_args = runtime_args! {
+            "default_admin" => Key::Account(helper.accounts[0]),
+            "default_admin_delay" => default_admin_delay
+        };
+
+        let hash = helper.deploy_contract(
+            session_code,
+            session_args,
+            helper.keys[0].clone(),
+            DEFAULT_ADMIN_RULES_CONTRACT_KEY_NAME.to_string(),
+        );
+
+        // ====================== FUNCTION RETURN ======================
+        DefaultAdminRules {
+            helper: helper.clone(),
+            hash,
+            ali: helper.accounts[0].clone(),
+            bob: helper.accounts[1].clone(),
+            joe: helper.accounts[2].clone(),
+        }
+    }
+
+    /// Returns the membership entry of `account` for `role`, whatever the expiration of the grant.
+    pub fn is_member(&self, role: U256, account: Key) -> bool {
+        self.helper
+            .query_dictionary_value(
+                self.hash,
+                AccessControlLib::ACCESS_ROLE_MEMBER_KEY,
+                make_dictionary_item_key(&(role, account)),
+            )
+            .unwrap_or_default()
+    }
+
+    pub fn default_admin(&self) -> Key {
+        self.helper
+            .query_contract(
+                DEFAULT_ADMIN_RULES_CONTRACT_KEY_NAME.to_string(),
+                AccessControlDefaultAdminRules::DEFAULT_ADMIN_KEY,
+            )
+            .unwrap()
+    }
+
+    pub fn pending_default_admin(&self) -> (Key, u64) {
+        (
+            self.helper
+                .query_contract(
+                    DEFAULT_ADMIN_RULES_CONTRACT_KEY_NAME.to_string(),
+                    AccessControlDefaultAdminRules::PENDING_DEFAULT_ADMIN_KEY,
+                )
+                .unwrap_or_else(|| Key::Account(AccountHash::default())),
+            self.helper
+                .query_contract(
+                    DEFAULT_ADMIN_RULES_CONTRACT_KEY_NAME.to_string(),
+                    AccessControlDefaultAdminRules::PENDING_DEFAULT_ADMIN_SCHEDULE_KEY,
+                )
+                .unwrap_or_default(),
+        )
+    }
+
+    pub fn grant_role(&mut self, role: U256, account: Key, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "grant_role",
+            runtime_args! {
+                "role" => role,
+                "account" => account
+            },
+        );
+    }
+
+    /// Begins a default admin transfer in a block with the given `block_time`.
+    pub fn begin_default_admin_transfer_at(
+        &mut self,
+        new_admin: Key,
+        block_time: u64,
+        sender: Sender,
+    ) {
+        self.helper.call_at(
+            self.hash,
+            sender,
+            "begin_default_admin_transfer",
+            runtime_args! {
+                "new_admin" => new_admin
+            },
+            block_time,
+        );
+    }
+
+    /// Accepts the pending default admin transfer in a block with the given `block_time`.
+    pub fn accept_default_admin_transfer_at(&mut self, block_time: u64, sender: Sender) {
+        self.helper.call_at(
+            self.hash,
+            sender,
+            "accept_default_admin_transfer",
+            runtime_args! {},
+            block_time,
+        );
+    }
+
+    pub fn cancel_default_admin_transfer(&mut self, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "cancel_default_admin_transfer",
+            runtime_args! {},
+        );
+    }
+
+    /// Renounces a role in a block with the given `block_time`.
+    pub fn renounce_role_at(&mut self, role: U256, account: Key, block_time: u64, sender: Sender) {
+        self.helper.call_at(
+            self.hash,
+            sender,
+            "renounce_role",
+            runtime_args! {
+                "role" => role,
+                "account" => account
+            },
+            block_time,
+        );
+    }
+}
@@ -0,0 +1,245 @@
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U256};
+use libs::access::{error::AccessControlError, AccessControl as AccessControlLib};
+use std::path::PathBuf;
+
+use crate::{
+    access::{
+        access_control_helper::ACCESS_CONTROL_CONTRACT_KEY_NAME,
+        default_admin_rules_helper::DefaultAdminRules,
+    },
+    utilities::{to_key, Sender},
+};
+
+const DELAY: u64 = 1_000;
+const DEFAULT_ADMIN_ROLE: U256 = AccessControlLib::DEFAULT_ADMIN_ROLE;
+
+// ------------ START - DefaultAdminRules Tests ------------
+
+#[test]
+fn should_deploy_default_admin_rules() {
+    let contract = DefaultAdminRules::deployed(DELAY);
+    assert_eq!(contract.default_admin(), to_key(contract.ali));
+    assert!(contract.is_member(DEFAULT_ADMIN_ROLE, to_key(contract.ali)));
+    assert_eq!(
+        contract.pending_default_admin(),
+        (Key::Account(AccountHash::default()), 0)
+    );
+}
+
+#[test]
+fn should_install_another_access_control_contract_from_the_same_account() {
+    let mut contract = DefaultAdminRules::deployed(DELAY);
+    let ali = contract.ali;
+    let access_control_hash = contract.helper.deploy_contract(
+        PathBuf::from("access_control.wasm"),
+        runtime_args! {
+            "default_admin" => Key::Account(ali)
+        },
+        contract.helper.keys[0].clone(),
+        ACCESS_CONTROL_CONTRACT_KEY_NAME.to_string(),
+    );
+
+    assert_ne!(access_control_hash, contract.hash);
+    assert!(contract.is_member(DEFAULT_ADMIN_ROLE, to_key(ali)));
+}
+
+#[test]
+fn should_grant_other_roles() {
+    let mut contract = DefaultAdminRules::deployed(DELAY);
+    contract.grant_role(U256::one(), to_key(contract.bob), Sender(contract.ali));
+    assert!(contract.is_member(U256::one(), to_key(contract.bob)));
+}
+
+#[test]
+fn should_not_grant_default_admin_role() {
+    let mut contract = DefaultAdminRules::deployed(DELAY);
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "grant_role",
+        runtime_args! {
+            "role" => DEFAULT_ADMIN_ROLE,
+            "account" => to_key(contract.bob)
+        },
+        AccessControlError::EnforcedDefaultAdminRules,
+    );
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "grant_role_until",
+        runtime_args! {
+            "role" => DEFAULT_ADMIN_ROLE,
+            "account" => to_key(contract.bob),
+            "expires_at" => DELAY
+        },
+        AccessControlError::EnforcedDefaultAdminRules,
+    );
+    assert!(!contract.is_member(DEFAULT_ADMIN_ROLE, to_key(contract.bob)));
+}
+
+#[test]
+fn should_not_revoke_default_admin_role() {
+    let mut contract = DefaultAdminRules::deployed(DELAY);
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "revoke_role",
+        runtime_args! {
+            "role" => DEFAULT_ADMIN_ROLE,
+            "account" => to_key(contract.ali)
+        },
+        AccessControlError::EnforcedDefaultAdminRules,
+    );
+    assert!(contract.is_member(DEFAULT_ADMIN_ROLE, to_key(contract.ali)));
+}
+
+#[test]
+fn should_transfer_default_admin_after_delay() {
+    let mut contract = DefaultAdminRules::deployed(DELAY);
+    contract.begin_default_admin_transfer_at(to_key(contract.bob), 0, Sender(contract.ali));
+    assert_eq!(
+        contract.pending_default_admin(),
+        (to_key(contract.bob), DELAY)
+    );
+
+    contract.helper.call_expect_error_at(
+        contract.hash,
+        Sender(contract.bob),
+        "accept_default_admin_transfer",
+        runtime_args! {},
+        DELAY - 1,
+        AccessControlError::EnforcedDefaultAdminDelay,
+    );
+
+    contract.accept_default_admin_transfer_at(DELAY, Sender(contract.bob));
+    assert_eq!(contract.default_admin(), to_key(contract.bob));
+    assert!(contract.is_member(DEFAULT_ADMIN_ROLE, to_key(contract.bob)));
+    assert!(!contract.is_member(DEFAULT_ADMIN_ROLE, to_key(contract.ali)));
+    assert_eq!(
+        contract.pending_default_admin(),
+        (Key::Account(AccountHash::default()), 0)
+    );
+}
+
+#[test]
+fn should_not_accept_default_admin_transfer_by_other_account() {
+    let mut contract = DefaultAdminRules::deployed(DELAY);
+    contract.begin_default_admin_transfer_at(to_key(contract.bob), 0, Sender(contract.ali));
+    contract.helper.call_expect_error_at(
+        contract.hash,
+        Sender(contract.joe),
+        "accept_default_admin_transfer",
+        runtime_args! {},
+        DELAY,
+        AccessControlError::InvalidDefaultAdmin,
+    );
+    assert_eq!(contract.default_admin(), to_key(contract.ali));
+}
+
+#[test]
+fn should_not_begin_default_admin_transfer_by_non_admin() {
+    let mut contract = DefaultAdminRules::deployed(DELAY);
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.bob),
+        "begin_default_admin_transfer",
+        runtime_args! {
+            "new_admin" => to_key(contract.bob)
+        },
+        AccessControlError::MissingRole,
+    );
+}
+
+#[test]
+fn should_cancel_default_admin_transfer() {
+    let mut contract = DefaultAdminRules::deployed(DELAY);
+    contract.begin_default_admin_transfer_at(to_key(contract.bob), 0, Sender(contract.ali));
+    contract.cancel_default_admin_transfer(Sender(contract.ali));
+    assert_eq!(
+        contract.pending_default_admin(),
+        (Key::Account(AccountHash::default()), 0)
+    );
+
+    contract.helper.call_expect_error_at(
+        contract.hash,
+        Sender(contract.bob),
+        "accept_default_admin_transfer",
+        runtime_args! {},
+        DELAY,
+        AccessControlError::InvalidDefaultAdmin,
+    );
+    assert_eq!(contract.default_admin(), to_key(contract.ali));
+}
+
+#[test]
+fn should_not_renounce_default_admin_role_instantly() {
+    let mut contract = DefaultAdminRules::deployed(DELAY);
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "renounce_role",
+        runtime_args! {
+            "role" => DEFAULT_ADMIN_ROLE,
+            "account" => to_key(contract.ali)
+        },
+        AccessControlError::EnforcedDefaultAdminRules,
+    );
+    assert!(contract.is_member(DEFAULT_ADMIN_ROLE, to_key(contract.ali)));
+}
+
+#[test]
+fn should_renounce_default_admin_role_after_delay() {
+    let mut contract = DefaultAdminRules::deployed(DELAY);
+    let zero_admin = Key::Account(AccountHash::default());
+    contract.begin_default_admin_transfer_at(zero_admin, 0, Sender(contract.ali));
+
+    contract.helper.call_expect_error_at(
+        contract.hash,
+        Sender(contract.ali),
+        "renounce_role",
+        runtime_args! {
+            "role" => DEFAULT_ADMIN_ROLE,
+            "account" => to_key(contract.ali)
+        },
+        DELAY - 1,
+        AccessControlError::EnforcedDefaultAdminDelay,
+    );
+
+    contract.renounce_role_at(
+        DEFAULT_ADMIN_ROLE,
+        to_key(contract.ali),
+        DELAY,
+        Sender(contract.ali),
+    );
+    assert_eq!(contract.default_admin(), zero_admin);
+    assert!(!contract.is_member(DEFAULT_ADMIN_ROLE, to_key(contract.ali)));
+}
+
+#[test]
+fn should_not_grant_default_admin_role_in_batches() {
+    let mut contract = DefaultAdminRules::deployed(DELAY);
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "grant_roles",
+        runtime_args! {
+            "role" => DEFAULT_ADMIN_ROLE,
+            "accounts" => vec![to_key(contract.bob)]
+        },
+        AccessControlError::EnforcedDefaultAdminRules,
+    );
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "grant_role_batch",
+        runtime_args! {
+            "grants" => vec![
+                (U256::one(), to_key(contract.joe)),
+                (DEFAULT_ADMIN_ROLE, to_key(contract.bob)),
+            ]
+        },
+        AccessControlError::EnforcedDefaultAdminRules,
+    );
+    assert!(!contract.is_member(DEFAULT_ADMIN_ROLE, to_key(contract.bob)));
+    assert!(!contract.is_member(U256::one(), to_key(contract.joe)));
+}
@@ -1,2 +1,8 @@
+pub mod access_control_helper;
+pub mod access_control_test;
+pub mod access_manager_helper;
+pub mod access_manager_test;
+pub mod default_admin_rules_helper;
+pub mod default_admin_rules_test;
 pub mod ownable_helper;
 pub mod ownable_test;
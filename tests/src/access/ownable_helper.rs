@@ -1,5 +1,6 @@
 use crate::utilities::{CasperHelper, Hash, Sender};
 use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs};
+use libs::utils::CallerIdentity;
 use std::path::PathBuf;
 
 // contains methods that can simulate a real-world deployment (storing the contract in the blockchain)
@@ -16,12 +17,26 @@ pub struct Ownable {
 
 impl Ownable {
     pub fn deployed() -> Ownable {
-        let mut helper = CasperHelper::new();
+        Ownable::deployed_with_caller_identity(
+            CasperHelper::new(),
+            CallerIdentity::ImmediateCaller,
+            vec![],
+        )
+    }
 
+    /// Deploys the Ownable contract next to the contracts already deployed by `helper`,
+    /// authorizing the given caller identity through the given trusted forwarders.
+    pub fn deployed_with_caller_identity(
+        mut helper: CasperHelper,
+        caller_identity: CallerIdentity,
+        trusted_forwarders: Vec<Key>,
+    ) -> Ownable {
         // ====================== CONTRACT DEPLOYMENT ======================
         let session_code = PathBuf::from("ownable.wasm");
         let session_args = runtime_args! {
-            "owner" => Key::Account(helper.accounts[0])
+            "owner" => Key::Account(helper.accounts[0]),
+            "caller_identity" => caller_identity as u8,
+            "trusted_forwarders" => trusted_forwarders
         };
 
         let hash = helper.deploy_contract(
@@ -1,4 +1,5 @@
-use casper_types::{account::AccountHash, Key};
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs};
+use libs::{access::error::OwnableError, error::Error};
 
 use crate::{
     access::ownable_helper::Ownable,
@@ -27,10 +28,39 @@ fn should_transfer_ownership() {
 }
 
 #[test]
-#[should_panic = "65535"]
 fn should_not_transfer_ownership_by_invalid_owner() {
     let mut contract = Ownable::deployed();
-    contract.transfer_ownership(to_key(contract.bob), Sender(contract.joe));
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.joe),
+        "transfer_ownership",
+        runtime_args! {
+            "new_owner" => to_key(contract.bob)
+        },
+        OwnableError::CallerNotOwner,
+    );
+    assert_eq!(
+        contract.owner().to_string(),
+        Key::Account(contract.ali).to_string()
+    );
+}
+
+#[test]
+fn should_not_transfer_ownership_to_invalid_key_variant() {
+    let mut contract = Ownable::deployed();
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "transfer_ownership",
+        runtime_args! {
+            "new_owner" => Key::Dictionary([1u8; 32])
+        },
+        Error::InvalidKeyVariant,
+    );
+    assert_eq!(
+        contract.owner().to_string(),
+        Key::Account(contract.ali).to_string()
+    );
 }
 
 #[test]
@@ -44,8 +74,13 @@ fn should_renounce_ownership() {
 }
 
 #[test]
-#[should_panic = "65535"]
 fn should_not_renounce_ownership_by_invalid_owner() {
     let mut contract = Ownable::deployed();
-    contract.renounce_ownership(Sender(contract.joe));
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.joe),
+        "renounce_ownership",
+        runtime_args! {},
+        OwnableError::CallerNotOwner,
+    );
 }
@@ -0,0 +1,201 @@
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+use libs::{access::error::OwnableError, token::erc20::error::Erc20Error, utils::CallerIdentity};
+
+use crate::{
+    access::ownable_helper::Ownable,
+    caller_identity::proxy_helper::Proxy,
+    token::erc20::erc20_helper::{token_cfg, Erc20},
+    utilities::{to_key, CasperHelper, Sender},
+};
+
+/// Deploys the proxy, then the ERC20 contract trusting it as a forwarder when `trusted` is set.
+fn deploy_erc20(caller_identity: CallerIdentity, trusted: bool) -> (Erc20, Proxy) {
+    let mut helper = CasperHelper::new();
+    let proxy = Proxy::deploy(&mut helper);
+    let trusted_forwarders = if trusted { vec![proxy.key()] } else { vec![] };
+    let contract = Erc20::deployed_with_caller_identity(
+        token_cfg::NAME.to_string(),
+        token_cfg::SYMBOL.to_string(),
+        token_cfg::DECIMALS,
+        token_cfg::total_supply(),
+        helper,
+        caller_identity,
+        trusted_forwarders,
+    );
+    (contract, proxy)
+}
+
+/// Deploys the proxy, then the Ownable contract trusting it as a forwarder when `trusted` is set.
+fn deploy_ownable(caller_identity: CallerIdentity, trusted: bool) -> (Ownable, Proxy) {
+    let mut helper = CasperHelper::new();
+    let proxy = Proxy::deploy(&mut helper);
+    let trusted_forwarders = if trusted { vec![proxy.key()] } else { vec![] };
+    let contract =
+        Ownable::deployed_with_caller_identity(helper, caller_identity, trusted_forwarders);
+    (contract, proxy)
+}
+
+// ------------ START - Caller Identity Tests ------------
+
+#[test]
+fn should_transfer_proxy_tokens_as_immediate_caller() {
+    let (mut contract, proxy) = deploy_erc20(CallerIdentity::ImmediateCaller, false);
+
+    contract.transfer(proxy.key(), U256::from(100u64), Sender(contract.ali));
+    proxy.forward(
+        &mut contract.helper,
+        contract.hash,
+        "transfer",
+        runtime_args! {
+            "to" => to_key(contract.bob),
+            "amount" => U256::from(40u64)
+        },
+        Sender(contract.ali),
+    );
+
+    assert_eq!(contract.balance_of(proxy.key()), U256::from(60u64));
+    assert_eq!(contract.balance_of(to_key(contract.bob)), U256::from(40u64));
+    assert_eq!(
+        contract.balance_of(to_key(contract.ali)),
+        token_cfg::total_supply() - U256::from(100u64)
+    );
+}
+
+#[test]
+fn should_not_transfer_account_tokens_through_trusted_forwarder_as_immediate_caller() {
+    let (mut contract, proxy) = deploy_erc20(CallerIdentity::ImmediateCaller, true);
+
+    proxy.forward_expect_error(
+        &mut contract.helper,
+        contract.hash,
+        "transfer",
+        runtime_args! {
+            "to" => to_key(contract.bob),
+            "amount" => U256::from(40u64)
+        },
+        Sender(contract.ali),
+        Erc20Error::InsufficientBalance,
+    );
+}
+
+#[test]
+fn should_not_transfer_account_tokens_through_untrusted_proxy_as_origin_account() {
+    let (mut contract, proxy) = deploy_erc20(CallerIdentity::OriginAccount, false);
+
+    proxy.forward_expect_error(
+        &mut contract.helper,
+        contract.hash,
+        "transfer",
+        runtime_args! {
+            "to" => to_key(contract.bob),
+            "amount" => U256::from(40u64)
+        },
+        Sender(contract.ali),
+        Erc20Error::InsufficientBalance,
+    );
+    assert_eq!(
+        contract.balance_of(to_key(contract.ali)),
+        token_cfg::total_supply()
+    );
+}
+
+#[test]
+fn should_transfer_account_tokens_through_trusted_forwarder_as_origin_account() {
+    let (mut contract, proxy) = deploy_erc20(CallerIdentity::OriginAccount, true);
+
+    proxy.forward(
+        &mut contract.helper,
+        contract.hash,
+        "transfer",
+        runtime_args! {
+            "to" => to_key(contract.bob),
+            "amount" => U256::from(40u64)
+        },
+        Sender(contract.ali),
+    );
+
+    assert_eq!(contract.balance_of(to_key(contract.bob)), U256::from(40u64));
+    assert_eq!(
+        contract.balance_of(to_key(contract.ali)),
+        token_cfg::total_supply() - U256::from(40u64)
+    );
+}
+
+#[test]
+fn should_transfer_proxy_tokens_through_untrusted_proxy_as_origin_account() {
+    let (mut contract, proxy) = deploy_erc20(CallerIdentity::OriginAccount, false);
+
+    contract.transfer(proxy.key(), U256::from(100u64), Sender(contract.ali));
+    proxy.forward(
+        &mut contract.helper,
+        contract.hash,
+        "transfer",
+        runtime_args! {
+            "to" => to_key(contract.bob),
+            "amount" => U256::from(40u64)
+        },
+        Sender(contract.ali),
+    );
+
+    assert_eq!(contract.balance_of(proxy.key()), U256::from(60u64));
+    assert_eq!(contract.balance_of(to_key(contract.bob)), U256::from(40u64));
+}
+
+#[test]
+fn should_not_transfer_ownership_through_trusted_forwarder_as_immediate_caller() {
+    let (mut contract, proxy) = deploy_ownable(CallerIdentity::ImmediateCaller, true);
+
+    proxy.forward_expect_error(
+        &mut contract.helper,
+        contract.hash,
+        "transfer_ownership",
+        runtime_args! {
+            "new_owner" => to_key(contract.bob)
+        },
+        Sender(contract.ali),
+        OwnableError::CallerNotOwner,
+    );
+    assert_eq!(
+        contract.owner().to_string(),
+        Key::Account(contract.ali).to_string()
+    );
+}
+
+#[test]
+fn should_not_transfer_ownership_through_untrusted_proxy_as_origin_account() {
+    let (mut contract, proxy) = deploy_ownable(CallerIdentity::OriginAccount, false);
+
+    proxy.forward_expect_error(
+        &mut contract.helper,
+        contract.hash,
+        "transfer_ownership",
+        runtime_args! {
+            "new_owner" => to_key(contract.bob)
+        },
+        Sender(contract.ali),
+        OwnableError::CallerNotOwner,
+    );
+    assert_eq!(
+        contract.owner().to_string(),
+        Key::Account(contract.ali).to_string()
+    );
+}
+
+#[test]
+fn should_transfer_ownership_through_trusted_forwarder_as_origin_account() {
+    let (mut contract, proxy) = deploy_ownable(CallerIdentity::OriginAccount, true);
+
+    proxy.forward(
+        &mut contract.helper,
+        contract.hash,
+        "transfer_ownership",
+        runtime_args! {
+            "new_owner" => to_key(contract.bob)
+        },
+        Sender(contract.ali),
+    );
+    assert_eq!(
+        contract.owner().to_string(),
+        Key::Account(contract.bob).to_string()
+    );
+}
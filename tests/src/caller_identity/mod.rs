@@ -0,0 +1,2 @@
+pub mod caller_identity_test;
+pub mod proxy_helper;
@@ -0,0 +1,103 @@
+use crate::utilities::{CasperHelper, Hash, Sender};
+use casper_types::{
+    bytesrepr::{Bytes, ToBytes},
+    runtime_args, ApiError, Key, RuntimeArgs,
+};
+use std::path::PathBuf;
+
+// contains methods that can simulate a real-world deployment (storing the contract in the blockchain)
+// and transactions to invoke the methods in the contract.
+pub const PROXY_CONTRACT_KEY_NAME: &str = "Proxy";
+pub const PROXY_PACKAGE_HASH_KEY_NAME: &str = "Proxy_package_hash";
+
+/// Intermediary contract forwarding calls to other contracts.
+pub struct Proxy {
+    pub hash: Hash,
+    pub package_hash: Hash,
+}
+
+impl Proxy {
+    /// Deploys the proxy contract next to the contracts already deployed by `helper`.
+    pub fn deploy(helper: &mut CasperHelper) -> Proxy {
+        let hash = helper.deploy_contract(
+            PathBuf::from("proxy.wasm"),
+            runtime_args! {},
+            helper.keys[0].clone(),
+            PROXY_CONTRACT_KEY_NAME.to_string(),
+        );
+        let package_hash =
+            helper.get_contract_hash(helper.accounts[0], PROXY_PACKAGE_HASH_KEY_NAME);
+
+        Proxy { hash, package_hash }
+    }
+
+    /// Address under which the proxy is seen by the contracts it calls.
+    pub fn key(&self) -> Key {
+        Key::Hash(self.package_hash)
+    }
+
+    fn forward_args(target: Hash, entry_point: &str, args: RuntimeArgs) -> RuntimeArgs {
+        runtime_args! {
+            "target" => Key::Hash(target),
+            "entry_point" => entry_point.to_string(),
+            "args" => Bytes::from(args.to_bytes().unwrap())
+        }
+    }
+
+    pub fn forward(
+        &self,
+        helper: &mut CasperHelper,
+        target: Hash,
+        entry_point: &str,
+        args: RuntimeArgs,
+        sender: Sender,
+    ) {
+        helper.call(
+            self.hash,
+            sender,
+            "forward",
+            Proxy::forward_args(target, entry_point, args),
+        );
+    }
+
+    pub fn forward_expect_error<E: Into<ApiError>>(
+        &self,
+        helper: &mut CasperHelper,
+        target: Hash,
+        entry_point: &str,
+        args: RuntimeArgs,
+        sender: Sender,
+        expected: E,
+    ) {
+        helper.call_expect_error(
+            self.hash,
+            sender,
+            "forward",
+            Proxy::forward_args(target, entry_point, args),
+            expected,
+        );
+    }
+
+    /// Returns what the `entry_point` of `target` answers when called through the proxy in a
+    /// block with the given `block_time`.
+    pub fn forward_query_at(
+        &self,
+        helper: &mut CasperHelper,
+        target: Hash,
+        entry_point: &str,
+        args: RuntimeArgs,
+        block_time: u64,
+    ) -> bool {
+        let sender = Sender(helper.accounts[0]);
+        helper.call_at(
+            self.hash,
+            sender,
+            "forward_query",
+            Proxy::forward_args(target, entry_point, args),
+            block_time,
+        );
+        helper
+            .query_contract(PROXY_CONTRACT_KEY_NAME.to_string(), "result")
+            .unwrap()
+    }
+}
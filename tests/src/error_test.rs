@@ -0,0 +1,56 @@
+use casper_types::ApiError;
+use libs::{
+    access::error::{AccessControlError, OwnableError},
+    error::{error_name, Error, ERROR_RANGES, USER_ERROR_MAX},
+    token::erc20::error::Erc20Error,
+};
+
+// ------------ START - Error Tests ------------
+
+#[test]
+fn should_convert_errors_within_their_range() {
+    assert_eq!(
+        ApiError::from(Erc20Error::InsufficientBalance),
+        ApiError::User(Erc20Error::RANGE.start)
+    );
+    assert_eq!(
+        ApiError::from(OwnableError::CallerNotOwner),
+        ApiError::User(OwnableError::RANGE.start)
+    );
+    assert_eq!(ApiError::from(Error::User(42)), ApiError::User(42));
+}
+
+#[test]
+fn should_keep_error_ranges_disjoint() {
+    for pair in ERROR_RANGES.windows(2) {
+        assert!(pair[0].end < pair[1].start);
+    }
+}
+
+#[test]
+fn should_reserve_one_hundred_codes_per_module() {
+    let module_ranges = &ERROR_RANGES[1..ERROR_RANGES.len() - 1];
+    assert_eq!(module_ranges[0].start, USER_ERROR_MAX + 1);
+    for range in module_ranges {
+        assert_eq!(range.end - range.start, 99, "{} range", range.name);
+    }
+    for pair in module_ranges.windows(2) {
+        assert_eq!(pair[0].end + 1, pair[1].start);
+    }
+    assert_eq!(module_ranges.last().unwrap().end + 1, Error::RANGE.start);
+}
+
+#[test]
+fn should_name_library_errors() {
+    assert_eq!(
+        error_name(Erc20Error::InsufficientAllowance.into()),
+        Some("Erc20Error::InsufficientAllowance")
+    );
+    assert_eq!(
+        error_name(AccessControlError::MissingRole.into()),
+        Some("AccessControlError::MissingRole")
+    );
+    assert_eq!(error_name(Error::Locked.into()), Some("Error::Locked"));
+    assert_eq!(error_name(ApiError::User(42)), None);
+    assert_eq!(error_name(ApiError::InvalidArgument), None);
+}
@@ -0,0 +1,6 @@
+pub mod payment_splitter_helper;
+pub mod payment_splitter_test;
+pub mod token_timelock_helper;
+pub mod token_timelock_test;
+pub mod vesting_wallet_helper;
+pub mod vesting_wallet_test;
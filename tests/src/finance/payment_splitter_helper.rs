@@ -0,0 +1,146 @@
+use crate::{
+    token::erc20::erc20_helper::{token_cfg, Erc20},
+    utilities::{make_dictionary_item_key, to_key, Hash, Sender},
+};
+use casper_types::{
+    account::AccountHash, runtime_args, ApiError, Key, RuntimeArgs, URef, U256, U512,
+};
+use libs::finance::PaymentSplitter as PaymentSplitterLib;
+use std::path::PathBuf;
+
+// contains methods that can simulate a real-world deployment (storing the contract in the blockchain)
+// and transactions to invoke the methods in the contract.
+pub const PAYMENT_SPLITTER_CONTRACT_KEY_NAME: &str = "PaymentSplitter";
+pub const PAYMENT_SPLITTER_PACKAGE_HASH_KEY_NAME: &str = "PaymentSplitter_package_hash";
+
+pub struct PaymentSplitter {
+    pub token: Erc20,
+    pub hash: Hash,
+    pub package_hash: Hash,
+    pub purse: URef,
+    pub ali: AccountHash,
+    pub bob: AccountHash,
+    pub joe: AccountHash,
+}
+
+impl PaymentSplitter {
+    /// Deploys an ERC20 token and a splitter sharing payments between `bob` (1 share) and `joe`
+    /// (3 shares).
+    pub fn deployed() -> PaymentSplitter {
+        let mut token = Erc20::deployed(
+            token_cfg::NAME.to_string(),
+            token_cfg::SYMBOL.to_string(),
+            token_cfg::DECIMALS,
+            token_cfg::total_supply(),
+        );
+        let (ali, bob, joe) = (token.ali, token.bob, token.joe);
+
+        // ====================== CONTRACT DEPLOYMENT ======================
+        let session_code = PathBuf::from("payment_splitter.wasm");
+        let session_args = runtime_args! {
+            "payees" => vec![to_key(bob), to_key(joe)],
+            "shares" => vec![U256::from(1u64), U256::from(3u64)]
+        };
+
+        let hash = token.helper.deploy_contract(
+            session_code,
+            session_args,
+            token.helper.keys[0].clone(),
+            PAYMENT_SPLITTER_CONTRACT_KEY_NAME.to_string(),
+        );
+        let package_hash = token
+            .helper
+            .get_contract_hash(ali, PAYMENT_SPLITTER_PACKAGE_HASH_KEY_NAME);
+        let purse = token
+            .helper
+            .get_contract_named_key(hash, PaymentSplitterLib::PURSE_KEY)
+            .into_uref()
+            .expect("should have purse");
+
+        // ====================== FUNCTION RETURN ======================
+        PaymentSplitter {
+            token,
+            hash,
+            package_hash,
+            purse,
+            ali,
+            bob,
+            joe,
+        }
+    }
+
+    /// Deploys an ERC20 token and runs the session code installing a splitter between `payees`,
+    /// expecting it to revert with the `expected` error.
+    pub fn deploy_expect_error<E: Into<ApiError>>(
+        payees: Vec<Key>,
+        shares: Vec<U256>,
+        expected: E,
+    ) {
+        let mut token = Erc20::deployed(
+            token_cfg::NAME.to_string(),
+            token_cfg::SYMBOL.to_string(),
+            token_cfg::DECIMALS,
+            token_cfg::total_supply(),
+        );
+
+        token.helper.deploy_contract_expect_error(
+            PathBuf::from("payment_splitter.wasm"),
+            runtime_args! {
+                "payees" => payees,
+                "shares" => shares
+            },
+            token.helper.keys[0].clone(),
+            expected,
+        );
+    }
+
+    /// Sends `amount` motes from `ali` to the purse of the splitter.
+    pub fn receive_cspr(&mut self, amount: U512) {
+        let ali = self.ali;
+        self.token
+            .helper
+            .transfer_to_purse(Sender(ali), self.purse, amount);
+    }
+
+    /// Sends `amount` tokens from `ali` to the splitter.
+    pub fn receive_tokens(&mut self, amount: U256) {
+        let ali = self.ali;
+        self.token
+            .transfer(Key::Hash(self.package_hash), amount, Sender(ali));
+    }
+
+    pub fn released(&self, account: Key) -> U512 {
+        self.token
+            .helper
+            .query_dictionary_value(
+                self.hash,
+                PaymentSplitterLib::RELEASED_KEY,
+                make_dictionary_item_key(&account),
+            )
+            .unwrap_or_default()
+    }
+
+    pub fn release(&mut self, account: Key, sender: Sender) {
+        self.token.helper.call(
+            self.hash,
+            sender,
+            "release",
+            runtime_args! {
+                "account" => account
+            },
+        );
+    }
+
+    pub fn release_token(&mut self, account: Key, sender: Sender) {
+        let token = Key::Hash(self.token.hash);
+        self.token.helper.call(
+            self.hash,
+            sender,
+            "release_token",
+            runtime_args! {
+                "token" => token,
+                "account" => account
+            },
+        );
+    }
+}
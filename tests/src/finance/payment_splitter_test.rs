@@ -0,0 +1,123 @@
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U256, U512};
+use libs::finance::error::PaymentSplitterError;
+
+use crate::{
+    finance::payment_splitter_helper::PaymentSplitter,
+    utilities::{to_key, Sender},
+};
+
+const CSPR: u64 = 1_000_000_000;
+
+// ------------ START - PaymentSplitter Tests ------------
+
+#[test]
+fn should_split_cspr_by_shares() {
+    let mut contract = PaymentSplitter::deployed();
+    contract.receive_cspr(U512::from(1_000 * CSPR));
+
+    let bob_balance = contract.token.helper.get_balance(contract.bob);
+    let joe_balance = contract.token.helper.get_balance(contract.joe);
+
+    // releases are paid for by `ali` so that payee balances only change by the payments.
+    contract.release(to_key(contract.bob), Sender(contract.ali));
+    contract.release(to_key(contract.joe), Sender(contract.ali));
+
+    assert_eq!(
+        contract.token.helper.get_balance(contract.bob),
+        bob_balance + U512::from(250 * CSPR)
+    );
+    assert_eq!(
+        contract.token.helper.get_balance(contract.joe),
+        joe_balance + U512::from(750 * CSPR)
+    );
+    assert_eq!(
+        contract.released(to_key(contract.bob)),
+        U512::from(250 * CSPR)
+    );
+}
+
+#[test]
+fn should_release_cspr_received_after_previous_release() {
+    let mut contract = PaymentSplitter::deployed();
+    contract.receive_cspr(U512::from(1_000 * CSPR));
+    contract.release(to_key(contract.bob), Sender(contract.ali));
+
+    contract.receive_cspr(U512::from(400 * CSPR));
+    let bob_balance = contract.token.helper.get_balance(contract.bob);
+    contract.release(to_key(contract.bob), Sender(contract.ali));
+
+    assert_eq!(
+        contract.token.helper.get_balance(contract.bob),
+        bob_balance + U512::from(100 * CSPR)
+    );
+    assert_eq!(
+        contract.released(to_key(contract.bob)),
+        U512::from(350 * CSPR)
+    );
+}
+
+#[test]
+fn should_split_tokens_by_shares() {
+    let mut contract = PaymentSplitter::deployed();
+    contract.receive_tokens(U256::from(400u64));
+
+    contract.release_token(to_key(contract.joe), Sender(contract.bob));
+    assert_eq!(
+        contract.token.balance_of(to_key(contract.joe)),
+        U256::from(300u64)
+    );
+
+    contract.receive_tokens(U256::from(400u64));
+    contract.release_token(to_key(contract.bob), Sender(contract.bob));
+    contract.release_token(to_key(contract.joe), Sender(contract.bob));
+    assert_eq!(
+        contract.token.balance_of(to_key(contract.bob)),
+        U256::from(200u64)
+    );
+    assert_eq!(
+        contract.token.balance_of(to_key(contract.joe)),
+        U256::from(600u64)
+    );
+}
+
+#[test]
+fn should_not_release_to_account_without_shares() {
+    let mut contract = PaymentSplitter::deployed();
+    contract.receive_cspr(U512::from(1_000 * CSPR));
+
+    contract.token.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "release",
+        runtime_args! {
+            "account" => to_key(contract.ali)
+        },
+        PaymentSplitterError::AccountHasNoShares,
+    );
+}
+
+#[test]
+fn should_not_release_without_payment_due() {
+    let mut contract = PaymentSplitter::deployed();
+    contract.receive_cspr(U512::from(1_000 * CSPR));
+    contract.release(to_key(contract.bob), Sender(contract.ali));
+
+    contract.token.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "release",
+        runtime_args! {
+            "account" => to_key(contract.bob)
+        },
+        PaymentSplitterError::NoPaymentDue,
+    );
+}
+
+#[test]
+fn should_not_install_with_contract_payee() {
+    PaymentSplitter::deploy_expect_error(
+        vec![to_key(AccountHash::new([1u8; 32])), Key::Hash([2u8; 32])],
+        vec![U256::from(1u64), U256::from(1u64)],
+        PaymentSplitterError::NonAccountPayee,
+    );
+}
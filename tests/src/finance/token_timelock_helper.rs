@@ -0,0 +1,126 @@
+use crate::{
+    token::erc20::erc20_helper::{token_cfg, Erc20},
+    utilities::{make_dictionary_item_key, Hash, Sender},
+};
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U256};
+use libs::finance::TokenTimelock as TokenTimelockLib;
+use std::path::PathBuf;
+
+// contains methods that can simulate a real-world deployment (storing the contract in the blockchain)
+// and transactions to invoke the methods in the contract.
+pub const TOKEN_TIMELOCK_CONTRACT_KEY_NAME: &str = "TokenTimelock";
+pub const TOKEN_TIMELOCK_PACKAGE_HASH_KEY_NAME: &str = "TokenTimelock_package_hash";
+
+pub struct TokenTimelock {
+    pub token: Erc20,
+    pub hash: Hash,
+    pub package_hash: Hash,
+    pub ali: AccountHash,
+    pub bob: AccountHash,
+    pub joe: AccountHash,
+}
+
+impl TokenTimelock {
+    /// Deploys an ERC20 token and a timelock contract `ali` can lock `allowance` tokens into.
+    pub fn deployed(allowance: U256) -> TokenTimelock {
+        let mut token = Erc20::deployed(
+            token_cfg::NAME.to_string(),
+            token_cfg::SYMBOL.to_string(),
+            token_cfg::DECIMALS,
+            token_cfg::total_supply(),
+        );
+        let (ali, bob, joe) = (token.ali, token.bob, token.joe);
+
+        // ====================== CONTRACT DEPLOYMENT ======================
+        let session_code = PathBuf::from("token_timelock.wasm");
+        let session_args = runtime_args! {
+            "token" => Key::Hash(token.hash)
+        };
+
+        let hash = token.helper.deploy_contract(
+            session_code,
+            session_args,
+            token.helper.keys[0].clone(),
+            TOKEN_TIMELOCK_CONTRACT_KEY_NAME.to_string(),
+        );
+        let package_hash = token
+            .helper
+            .get_contract_hash(ali, TOKEN_TIMELOCK_PACKAGE_HASH_KEY_NAME);
+
+        token.approve(Key::Hash(package_hash), allowance, Sender(ali));
+
+        // ====================== FUNCTION RETURN ======================
+        TokenTimelock {
+            token,
+            hash,
+            package_hash,
+            ali,
+            bob,
+            joe,
+        }
+    }
+
+    pub fn lock_count(&self) -> u64 {
+        self.token
+            .helper
+            .query_contract(
+                TOKEN_TIMELOCK_CONTRACT_KEY_NAME.to_string(),
+                TokenTimelockLib::LOCK_COUNT_KEY,
+            )
+            .unwrap_or_default()
+    }
+
+    pub fn beneficiary_lock_count(&self, beneficiary: Key) -> u64 {
+        self.token
+            .helper
+            .query_dictionary_value(
+                self.hash,
+                TokenTimelockLib::BENEFICIARY_LOCK_COUNTS_KEY,
+                make_dictionary_item_key(&beneficiary),
+            )
+            .unwrap_or_default()
+    }
+
+    pub fn beneficiary_lock(&self, beneficiary: Key, index: u64) -> Option<u64> {
+        self.token.helper.query_dictionary_value(
+            self.hash,
+            TokenTimelockLib::BENEFICIARY_LOCKS_KEY,
+            make_dictionary_item_key(&(beneficiary, index)),
+        )
+    }
+
+    /// Locks tokens in a block with the given `block_time`.
+    pub fn lock_at(
+        &mut self,
+        beneficiary: Key,
+        amount: U256,
+        release_time: u64,
+        block_time: u64,
+        sender: Sender,
+    ) {
+        self.token.helper.call_at(
+            self.hash,
+            sender,
+            "lock",
+            runtime_args! {
+                "beneficiary" => beneficiary,
+                "amount" => amount,
+                "release_time" => release_time
+            },
+            block_time,
+        );
+    }
+
+    /// Releases a lock in a block with the given `block_time`.
+    pub fn release_at(&mut self, lock_id: u64, block_time: u64, sender: Sender) {
+        self.token.helper.call_at(
+            self.hash,
+            sender,
+            "release",
+            runtime_args! {
+                "lock_id" => lock_id
+            },
+            block_time,
+        );
+    }
+}
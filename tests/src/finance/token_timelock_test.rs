@@ -0,0 +1,177 @@
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+use libs::{finance::error::TokenTimelockError, token::erc20::error::Erc20Error};
+
+use crate::{
+    finance::token_timelock_helper::TokenTimelock,
+    utilities::{to_key, Sender},
+};
+
+const NOW: u64 = 1_000_000;
+const RELEASE_TIME: u64 = 2_000_000;
+
+fn locked_for_bob() -> TokenTimelock {
+    let mut contract = TokenTimelock::deployed(U256::from(1_000u64));
+    contract.lock_at(
+        to_key(contract.bob),
+        U256::from(100u64),
+        RELEASE_TIME,
+        NOW,
+        Sender(contract.ali),
+    );
+    contract
+}
+
+// ------------ START - TokenTimelock Tests ------------
+
+#[test]
+fn should_track_multiple_locks_per_beneficiary() {
+    let mut contract = locked_for_bob();
+    contract.lock_at(
+        to_key(contract.joe),
+        U256::from(200u64),
+        RELEASE_TIME,
+        NOW,
+        Sender(contract.ali),
+    );
+    contract.lock_at(
+        to_key(contract.bob),
+        U256::from(300u64),
+        RELEASE_TIME * 2,
+        NOW,
+        Sender(contract.ali),
+    );
+
+    assert_eq!(contract.lock_count(), 3);
+    assert_eq!(contract.beneficiary_lock_count(to_key(contract.bob)), 2);
+    assert_eq!(contract.beneficiary_lock_count(to_key(contract.joe)), 1);
+    assert_eq!(contract.beneficiary_lock(to_key(contract.bob), 0), Some(0));
+    assert_eq!(contract.beneficiary_lock(to_key(contract.bob), 1), Some(2));
+    assert_eq!(contract.beneficiary_lock(to_key(contract.joe), 0), Some(1));
+    assert_eq!(
+        contract.token.balance_of(Key::Hash(contract.package_hash)),
+        U256::from(600u64)
+    );
+}
+
+#[test]
+fn should_release_lock_after_release_time() {
+    let mut contract = locked_for_bob();
+    contract.release_at(0, RELEASE_TIME, Sender(contract.joe));
+
+    assert_eq!(
+        contract.token.balance_of(to_key(contract.bob)),
+        U256::from(100u64)
+    );
+    assert_eq!(
+        contract.token.balance_of(Key::Hash(contract.package_hash)),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_release_locks_independently() {
+    let mut contract = locked_for_bob();
+    contract.lock_at(
+        to_key(contract.bob),
+        U256::from(300u64),
+        RELEASE_TIME * 2,
+        NOW,
+        Sender(contract.ali),
+    );
+
+    contract.release_at(0, RELEASE_TIME, Sender(contract.bob));
+    assert_eq!(
+        contract.token.balance_of(to_key(contract.bob)),
+        U256::from(100u64)
+    );
+
+    contract.release_at(1, RELEASE_TIME * 2, Sender(contract.bob));
+    assert_eq!(
+        contract.token.balance_of(to_key(contract.bob)),
+        U256::from(400u64)
+    );
+}
+
+#[test]
+fn should_not_release_lock_before_release_time() {
+    let mut contract = locked_for_bob();
+
+    // calls without a block time run at time 0.
+    contract.token.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.bob),
+        "release",
+        runtime_args! {
+            "lock_id" => 0u64
+        },
+        TokenTimelockError::NotReleasable,
+    );
+    assert_eq!(
+        contract.token.balance_of(to_key(contract.bob)),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_not_release_lock_twice() {
+    let mut contract = locked_for_bob();
+    contract.release_at(0, RELEASE_TIME, Sender(contract.bob));
+
+    contract.token.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.bob),
+        "release",
+        runtime_args! {
+            "lock_id" => 0u64
+        },
+        TokenTimelockError::AlreadyReleased,
+    );
+}
+
+#[test]
+fn should_not_release_unknown_lock() {
+    let mut contract = locked_for_bob();
+    contract.token.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.bob),
+        "release",
+        runtime_args! {
+            "lock_id" => 1u64
+        },
+        TokenTimelockError::UnknownLock,
+    );
+}
+
+#[test]
+fn should_not_lock_with_past_release_time() {
+    let mut contract = TokenTimelock::deployed(U256::from(1_000u64));
+
+    // calls without a block time run at time 0.
+    contract.token.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "lock",
+        runtime_args! {
+            "beneficiary" => to_key(contract.bob),
+            "amount" => U256::from(100u64),
+            "release_time" => 0u64
+        },
+        TokenTimelockError::ReleaseTimeInPast,
+    );
+}
+
+#[test]
+fn should_not_lock_more_than_allowance() {
+    let mut contract = TokenTimelock::deployed(U256::from(1_000u64));
+    contract.token.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "lock",
+        runtime_args! {
+            "beneficiary" => to_key(contract.bob),
+            "amount" => U256::from(1_001u64),
+            "release_time" => RELEASE_TIME
+        },
+        Erc20Error::InsufficientAllowance,
+    );
+}
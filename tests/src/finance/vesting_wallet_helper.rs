@@ -0,0 +1,105 @@
+use crate::{
+    token::erc20::erc20_helper::{token_cfg, Erc20},
+    utilities::{to_key, Hash, Sender},
+};
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U256};
+use libs::finance::VestingWallet as VestingWalletLib;
+use std::path::PathBuf;
+
+// contains methods that can simulate a real-world deployment (storing the contract in the blockchain)
+// and transactions to invoke the methods in the contract.
+pub const VESTING_WALLET_CONTRACT_KEY_NAME: &str = "VestingWallet";
+pub const VESTING_WALLET_PACKAGE_HASH_KEY_NAME: &str = "VestingWallet_package_hash";
+
+pub mod vesting_cfg {
+    use super::*;
+    pub const START: u64 = 1_000_000;
+    pub const CLIFF: u64 = 1_000;
+    pub const DURATION: u64 = 10_000;
+    pub fn allocation() -> U256 {
+        U256::from(1_000u64)
+    }
+}
+
+pub struct VestingWallet {
+    pub token: Erc20,
+    pub hash: Hash,
+    pub package_hash: Hash,
+    pub ali: AccountHash,
+    pub bob: AccountHash,
+    pub joe: AccountHash,
+}
+
+impl VestingWallet {
+    /// Deploys an ERC20 token and a wallet owned by `ali` vesting an allocation of it to `bob`.
+    pub fn deployed(revocable: bool) -> VestingWallet {
+        let mut token = Erc20::deployed(
+            token_cfg::NAME.to_string(),
+            token_cfg::SYMBOL.to_string(),
+            token_cfg::DECIMALS,
+            token_cfg::total_supply(),
+        );
+        let (ali, bob, joe) = (token.ali, token.bob, token.joe);
+
+        // ====================== CONTRACT DEPLOYMENT ======================
+        let session_code = PathBuf::from("vesting_wallet.wasm");
+        let session_args = runtime_args! {
+            "token" => Key::Hash(token.hash),
+            "beneficiary" => to_key(bob),
+            "start" => vesting_cfg::START,
+            "cliff" => vesting_cfg::CLIFF,
+            "duration" => vesting_cfg::DURATION,
+            "revocable" => revocable
+        };
+
+        let hash = token.helper.deploy_contract(
+            session_code,
+            session_args,
+            token.helper.keys[0].clone(),
+            VESTING_WALLET_CONTRACT_KEY_NAME.to_string(),
+        );
+        let package_hash = token
+            .helper
+            .get_contract_hash(ali, VESTING_WALLET_PACKAGE_HASH_KEY_NAME);
+
+        token.transfer(
+            Key::Hash(package_hash),
+            vesting_cfg::allocation(),
+            Sender(ali),
+        );
+
+        // ====================== FUNCTION RETURN ======================
+        VestingWallet {
+            token,
+            hash,
+            package_hash,
+            ali,
+            bob,
+            joe,
+        }
+    }
+
+    pub fn released(&self) -> U256 {
+        self.token
+            .helper
+            .query_contract(
+                VESTING_WALLET_CONTRACT_KEY_NAME.to_string(),
+                VestingWalletLib::RELEASED_KEY,
+            )
+            .unwrap_or_default()
+    }
+
+    /// Releases the vested tokens in a block with the given `block_time`.
+    pub fn release_at(&mut self, block_time: u64, sender: Sender) {
+        self.token
+            .helper
+            .call_at(self.hash, sender, "release", runtime_args! {}, block_time);
+    }
+
+    /// Revokes the wallet in a block with the given `block_time`.
+    pub fn revoke_at(&mut self, block_time: u64, sender: Sender) {
+        self.token
+            .helper
+            .call_at(self.hash, sender, "revoke", runtime_args! {}, block_time);
+    }
+}
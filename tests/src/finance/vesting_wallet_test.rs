@@ -0,0 +1,135 @@
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+use libs::{access::error::OwnableError, finance::error::VestingWalletError};
+
+use crate::{
+    finance::vesting_wallet_helper::{vesting_cfg, VestingWallet},
+    token::erc20::erc20_helper::token_cfg,
+    utilities::{to_key, Sender},
+};
+
+// ------------ START - VestingWallet Tests ------------
+
+#[test]
+fn should_not_release_before_cliff() {
+    let mut contract = VestingWallet::deployed(false);
+    contract.release_at(
+        vesting_cfg::START + vesting_cfg::CLIFF - 1,
+        Sender(contract.joe),
+    );
+
+    assert_eq!(
+        contract.token.balance_of(to_key(contract.bob)),
+        U256::zero()
+    );
+    assert_eq!(contract.released(), U256::zero());
+}
+
+#[test]
+fn should_release_linearly_after_cliff() {
+    let mut contract = VestingWallet::deployed(false);
+
+    // half of the duration vested.
+    contract.release_at(
+        vesting_cfg::START + vesting_cfg::DURATION / 2,
+        Sender(contract.bob),
+    );
+    assert_eq!(
+        contract.token.balance_of(to_key(contract.bob)),
+        vesting_cfg::allocation() / 2
+    );
+    assert_eq!(contract.released(), vesting_cfg::allocation() / 2);
+
+    // three quarters of the duration vested, only the difference is released.
+    contract.release_at(
+        vesting_cfg::START + vesting_cfg::DURATION * 3 / 4,
+        Sender(contract.bob),
+    );
+    assert_eq!(
+        contract.token.balance_of(to_key(contract.bob)),
+        vesting_cfg::allocation() * 3 / 4
+    );
+    assert_eq!(
+        contract.token.balance_of(Key::Hash(contract.package_hash)),
+        vesting_cfg::allocation() / 4
+    );
+}
+
+#[test]
+fn should_release_everything_after_duration() {
+    let mut contract = VestingWallet::deployed(false);
+    contract.release_at(
+        vesting_cfg::START + vesting_cfg::DURATION + 1,
+        Sender(contract.joe),
+    );
+
+    assert_eq!(
+        contract.token.balance_of(to_key(contract.bob)),
+        vesting_cfg::allocation()
+    );
+    assert_eq!(
+        contract.token.balance_of(Key::Hash(contract.package_hash)),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_revoke_unvested_tokens() {
+    let mut contract = VestingWallet::deployed(true);
+    contract.revoke_at(
+        vesting_cfg::START + vesting_cfg::DURATION / 4,
+        Sender(contract.ali),
+    );
+
+    // the unvested tokens went back to the owner.
+    assert_eq!(
+        contract.token.balance_of(to_key(contract.ali)),
+        token_cfg::total_supply() - vesting_cfg::allocation() / 4
+    );
+
+    // the vested tokens stay releasable, nothing vests anymore.
+    contract.release_at(
+        vesting_cfg::START + vesting_cfg::DURATION,
+        Sender(contract.bob),
+    );
+    assert_eq!(
+        contract.token.balance_of(to_key(contract.bob)),
+        vesting_cfg::allocation() / 4
+    );
+}
+
+#[test]
+fn should_not_revoke_irrevocable_wallet() {
+    let mut contract = VestingWallet::deployed(false);
+    contract.token.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "revoke",
+        runtime_args! {},
+        VestingWalletError::NotRevocable,
+    );
+}
+
+#[test]
+fn should_not_revoke_wallet_twice() {
+    let mut contract = VestingWallet::deployed(true);
+    contract.revoke_at(vesting_cfg::START, Sender(contract.ali));
+    contract.token.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "revoke",
+        runtime_args! {},
+        VestingWalletError::AlreadyRevoked,
+    );
+}
+
+#[test]
+fn should_not_revoke_wallet_by_invalid_owner() {
+    let mut contract = VestingWallet::deployed(true);
+    contract.token.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.bob),
+        "revoke",
+        runtime_args! {},
+        OwnableError::CallerNotOwner,
+    );
+}
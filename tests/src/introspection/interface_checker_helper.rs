@@ -0,0 +1,61 @@
+use crate::utilities::{CasperHelper, Hash, Sender};
+use casper_types::{runtime_args, Key, RuntimeArgs};
+use std::path::PathBuf;
+
+// contains methods that can simulate a real-world deployment (storing the contract in the blockchain)
+// and transactions to invoke the methods in the contract.
+pub const INTERFACE_CHECKER_CONTRACT_KEY_NAME: &str = "InterfaceChecker";
+
+/// Contract calling the `supports_interface` entry point of other contracts, itself supporting
+/// the Ownable interface until upgraded without it.
+pub struct InterfaceChecker {
+    pub hash: Hash,
+}
+
+impl InterfaceChecker {
+    /// Deploys the checker contract next to the contracts already deployed by `helper`.
+    pub fn deploy(helper: &mut CasperHelper) -> InterfaceChecker {
+        let hash = helper.deploy_contract(
+            PathBuf::from("interface_checker.wasm"),
+            runtime_args! {},
+            helper.keys[0].clone(),
+            INTERFACE_CHECKER_CONTRACT_KEY_NAME.to_string(),
+        );
+
+        InterfaceChecker { hash }
+    }
+
+    /// Deploys a new version of the checker which drops the Ownable interface.
+    pub fn upgrade_without_ownable(&mut self, helper: &mut CasperHelper) {
+        self.hash = helper.upgrade_contract(
+            PathBuf::from("interface_checker.wasm"),
+            runtime_args! {
+                "ownable" => false
+            },
+            helper.keys[0].clone(),
+            INTERFACE_CHECKER_CONTRACT_KEY_NAME.to_string(),
+        );
+    }
+
+    /// Returns what the `supports_interface` entry point of `target` answers for `interface_id`.
+    pub fn supports_interface(
+        &self,
+        helper: &mut CasperHelper,
+        target: Hash,
+        interface_id: u32,
+    ) -> bool {
+        let sender = Sender(helper.accounts[0]);
+        helper.call(
+            self.hash,
+            sender,
+            "check_interface",
+            runtime_args! {
+                "target" => Key::Hash(target),
+                "interface_id" => interface_id
+            },
+        );
+        helper
+            .query_contract(INTERFACE_CHECKER_CONTRACT_KEY_NAME.to_string(), "supported")
+            .unwrap()
+    }
+}
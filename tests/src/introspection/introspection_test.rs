@@ -0,0 +1,115 @@
+use casper_types::{CLType, U256};
+use libs::{
+    access::{AccessControl as AccessControlLib, Ownable as OwnableLib},
+    introspection::{Introspection, INVALID_INTERFACE_ID},
+    token::erc20::ERC20,
+};
+
+use crate::{
+    access::{access_control_helper::AccessControl, ownable_helper::Ownable},
+    introspection::interface_checker_helper::InterfaceChecker,
+    token::erc20::erc20_helper::{token_cfg, Erc20},
+    utilities::{interface_id, CasperHelper},
+};
+
+// ------------ START - Introspection Tests ------------
+
+#[test]
+fn should_derive_interface_ids_from_standard_entry_points() {
+    assert_eq!(
+        Introspection::interface_id(),
+        interface_id(&[("supports_interface", vec![CLType::U32])])
+    );
+    assert_eq!(
+        OwnableLib::interface_id(),
+        interface_id(&[
+            ("owner", vec![]),
+            ("transfer_ownership", vec![CLType::Key]),
+            ("renounce_ownership", vec![]),
+        ])
+    );
+    assert_eq!(
+        ERC20::interface_id(),
+        interface_id(&[
+            ("name", vec![]),
+            ("symbol", vec![]),
+            ("decimals", vec![]),
+            ("total_supply", vec![]),
+            ("balance_of", vec![CLType::Key]),
+            ("allowance", vec![CLType::Key, CLType::Key]),
+            ("approve", vec![CLType::Key, CLType::U256]),
+            ("transfer", vec![CLType::Key, CLType::U256]),
+            (
+                "transfer_from",
+                vec![CLType::Key, CLType::Key, CLType::U256],
+            ),
+        ])
+    );
+    assert_eq!(
+        AccessControlLib::interface_id(),
+        interface_id(&[
+            ("has_role", vec![CLType::U256, CLType::Key]),
+            ("get_role_admin", vec![CLType::U256]),
+            ("grant_role", vec![CLType::U256, CLType::Key]),
+            ("revoke_role", vec![CLType::U256, CLType::Key]),
+            ("renounce_role", vec![CLType::U256, CLType::Key]),
+        ])
+    );
+}
+
+#[test]
+fn should_support_ownable_interface() {
+    let mut contract = Ownable::deployed();
+    let checker = InterfaceChecker::deploy(&mut contract.helper);
+    let helper = &mut contract.helper;
+
+    assert!(checker.supports_interface(helper, contract.hash, OwnableLib::interface_id()));
+    assert!(checker.supports_interface(helper, contract.hash, Introspection::interface_id()));
+    assert!(!checker.supports_interface(helper, contract.hash, ERC20::interface_id()));
+}
+
+#[test]
+fn should_support_erc20_interface() {
+    let mut contract = Erc20::deployed(
+        token_cfg::NAME.to_string(),
+        token_cfg::SYMBOL.to_string(),
+        token_cfg::DECIMALS,
+        U256::from(1000),
+    );
+    let checker = InterfaceChecker::deploy(&mut contract.helper);
+    let helper = &mut contract.helper;
+
+    assert!(checker.supports_interface(helper, contract.hash, ERC20::interface_id()));
+    assert!(checker.supports_interface(helper, contract.hash, OwnableLib::interface_id()));
+    assert!(checker.supports_interface(helper, contract.hash, Introspection::interface_id()));
+    assert!(!checker.supports_interface(helper, contract.hash, AccessControlLib::interface_id()));
+}
+
+#[test]
+fn should_support_access_control_interface() {
+    let mut contract = AccessControl::deployed();
+    let checker = InterfaceChecker::deploy(&mut contract.helper);
+    let helper = &mut contract.helper;
+
+    assert!(checker.supports_interface(helper, contract.hash, AccessControlLib::interface_id()));
+    assert!(!checker.supports_interface(helper, contract.hash, OwnableLib::interface_id()));
+}
+
+#[test]
+fn should_not_support_invalid_interface() {
+    let mut contract = Ownable::deployed();
+    let checker = InterfaceChecker::deploy(&mut contract.helper);
+
+    assert!(!checker.supports_interface(&mut contract.helper, contract.hash, INVALID_INTERFACE_ID));
+}
+
+#[test]
+fn should_not_support_interface_dropped_by_upgrade() {
+    let mut helper = CasperHelper::new();
+    let mut checker = InterfaceChecker::deploy(&mut helper);
+    assert!(checker.supports_interface(&mut helper, checker.hash, OwnableLib::interface_id()));
+
+    checker.upgrade_without_ownable(&mut helper);
+    assert!(!checker.supports_interface(&mut helper, checker.hash, OwnableLib::interface_id()));
+    assert!(checker.supports_interface(&mut helper, checker.hash, Introspection::interface_id()));
+}
@@ -0,0 +1,2 @@
+pub mod interface_checker_helper;
+pub mod introspection_test;
@@ -1,6 +1,27 @@
 #[cfg(test)]
 pub mod access;
 
+#[cfg(test)]
+pub mod caller_identity;
+
+#[cfg(test)]
+pub mod error_test;
+
+#[cfg(test)]
+pub mod finance;
+
+#[cfg(test)]
+pub mod introspection;
+
+#[cfg(test)]
+pub mod migration;
+
+#[cfg(test)]
+pub mod security;
+
+#[cfg(test)]
+pub mod storage;
+
 #[cfg(test)]
 pub mod token;
 
@@ -0,0 +1,89 @@
+use crate::utilities::{CasperHelper, Hash, Sender};
+use casper_types::{account::AccountHash, runtime_args, RuntimeArgs};
+use libs::migration::STORAGE_LAYOUT_VERSION_KEY;
+use std::path::PathBuf;
+
+// contains methods that can simulate a real-world deployment (storing the contract in the blockchain)
+// and transactions to invoke the methods in the contract.
+pub const MIGRATION_CONTRACT_KEY_NAME: &str = "Migration";
+pub const MIGRATION_OUT_OF_ORDER_CONTRACT_KEY_NAME: &str = "MigrationOutOfOrder";
+
+pub struct Migration {
+    pub helper: CasperHelper,
+    pub hash: Hash,
+    pub ali: AccountHash,
+    pub bob: AccountHash,
+}
+
+impl Migration {
+    pub fn deployed() -> Migration {
+        let mut helper = CasperHelper::new();
+
+        // ====================== CONTRACT DEPLOYMENT ======================
+        let hash = helper.deploy_contract(
+            PathBuf::from("migration.wasm"),
+            runtime_args! {},
+            helper.keys[0].clone(),
+            MIGRATION_CONTRACT_KEY_NAME.to_string(),
+        );
+
+        // ====================== FUNCTION RETURN ======================
+        Migration {
+            helper: helper.clone(),
+            hash,
+            ali: helper.accounts[0].clone(),
+            bob: helper.accounts[1].clone(),
+        }
+    }
+
+    /// Deploys the session code again, adding a new version to the contract package.
+    pub fn upgrade(&mut self) {
+        self.hash = self.helper.upgrade_contract(
+            PathBuf::from("migration.wasm"),
+            runtime_args! {},
+            self.helper.keys[0].clone(),
+            MIGRATION_CONTRACT_KEY_NAME.to_string(),
+        );
+    }
+
+    pub fn counter(&self) -> u64 {
+        self.helper
+            .query_contract(MIGRATION_CONTRACT_KEY_NAME.to_string(), "counter")
+            .unwrap()
+    }
+
+    pub fn storage_layout_version(&self) -> u32 {
+        self.helper
+            .query_contract(
+                MIGRATION_CONTRACT_KEY_NAME.to_string(),
+                STORAGE_LAYOUT_VERSION_KEY,
+            )
+            .unwrap()
+    }
+
+    pub fn migrate(&mut self, sender: Sender) {
+        self.helper
+            .call(self.hash, sender, "migrate", runtime_args! {});
+    }
+
+    pub fn migrate_to(&mut self, target_version: u32, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "migrate_to",
+            runtime_args! {
+                "target_version" => target_version
+            },
+        );
+    }
+
+    /// Deploys the fixture contract registering its migration steps out of order.
+    pub fn deploy_out_of_order(&mut self) -> Hash {
+        self.helper.deploy_contract(
+            PathBuf::from("migration_out_of_order.wasm"),
+            runtime_args! {},
+            self.helper.keys[0].clone(),
+            MIGRATION_OUT_OF_ORDER_CONTRACT_KEY_NAME.to_string(),
+        )
+    }
+}
@@ -0,0 +1,118 @@
+use casper_types::{runtime_args, RuntimeArgs};
+use libs::{access::error::OwnableError, migration::MigrationError};
+
+use crate::{migration::migration_helper::Migration, utilities::Sender};
+
+// ------------ START - Migration Tests ------------
+
+#[test]
+fn should_deploy_with_initial_layout() {
+    let contract = Migration::deployed();
+    assert_eq!(contract.storage_layout_version(), 0);
+    assert_eq!(contract.counter(), 1);
+}
+
+#[test]
+fn should_run_two_step_migration() {
+    let mut contract = Migration::deployed();
+    contract.migrate(Sender(contract.ali));
+    assert_eq!(contract.storage_layout_version(), 2);
+    // (1 * 2) + 10, the steps ran in order.
+    assert_eq!(contract.counter(), 12);
+}
+
+#[test]
+fn should_run_migration_steps_one_by_one() {
+    let mut contract = Migration::deployed();
+    contract.migrate_to(1, Sender(contract.ali));
+    assert_eq!(contract.storage_layout_version(), 1);
+    assert_eq!(contract.counter(), 2);
+
+    contract.migrate_to(2, Sender(contract.ali));
+    assert_eq!(contract.storage_layout_version(), 2);
+    assert_eq!(contract.counter(), 12);
+}
+
+#[test]
+fn should_not_run_migration_steps_twice() {
+    let mut contract = Migration::deployed();
+    contract.migrate(Sender(contract.ali));
+    contract.migrate(Sender(contract.ali));
+    assert_eq!(contract.storage_layout_version(), 2);
+    assert_eq!(contract.counter(), 12);
+}
+
+#[test]
+fn should_migrate_on_upgrade() {
+    let mut contract = Migration::deployed();
+    contract.upgrade();
+    assert_eq!(contract.storage_layout_version(), 2);
+    assert_eq!(contract.counter(), 12);
+}
+
+#[test]
+fn should_not_downgrade_storage_layout() {
+    let mut contract = Migration::deployed();
+    contract.migrate_to(2, Sender(contract.ali));
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "migrate_to",
+        runtime_args! {
+            "target_version" => 1u32
+        },
+        MigrationError::OutOfOrder,
+    );
+    assert_eq!(contract.storage_layout_version(), 2);
+}
+
+#[test]
+fn should_not_migrate_to_unknown_version() {
+    let mut contract = Migration::deployed();
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "migrate_to",
+        runtime_args! {
+            "target_version" => 3u32
+        },
+        MigrationError::MissingStep,
+    );
+    assert_eq!(contract.storage_layout_version(), 0);
+}
+
+#[test]
+fn should_not_migrate_as_non_owner() {
+    let mut contract = Migration::deployed();
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.bob),
+        "migrate",
+        runtime_args! {},
+        OwnableError::CallerNotOwner,
+    );
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.bob),
+        "migrate_to",
+        runtime_args! {
+            "target_version" => 1u32
+        },
+        OwnableError::CallerNotOwner,
+    );
+    assert_eq!(contract.storage_layout_version(), 0);
+    assert_eq!(contract.counter(), 1);
+}
+
+#[test]
+fn should_not_register_migration_steps_out_of_order() {
+    let mut contract = Migration::deployed();
+    let fixture_hash = contract.deploy_out_of_order();
+    contract.helper.call_expect_error(
+        fixture_hash,
+        Sender(contract.ali),
+        "register_out_of_order",
+        runtime_args! {},
+        MigrationError::OutOfOrder,
+    );
+}
@@ -0,0 +1,2 @@
+pub mod migration_helper;
+pub mod migration_test;
@@ -0,0 +1,2 @@
+pub mod reentrancy_guard_helper;
+pub mod reentrancy_guard_test;
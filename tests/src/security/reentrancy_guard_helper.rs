@@ -0,0 +1,71 @@
+use crate::utilities::{CasperHelper, Hash, Sender};
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs};
+use std::path::PathBuf;
+
+// contains methods that can simulate a real-world deployment (storing the contract in the blockchain)
+// and transactions to invoke the methods in the contract.
+pub const REENTRANCY_GUARD_CONTRACT_KEY_NAME: &str = "ReentrancyGuard";
+pub const REENTRANCY_ATTACKER_CONTRACT_KEY_NAME: &str = "ReentrancyAttacker";
+
+pub struct ReentrancyGuard {
+    pub helper: CasperHelper,
+    pub hash: Hash,
+    pub attacker_hash: Hash,
+    pub ali: AccountHash,
+    pub bob: AccountHash,
+}
+
+impl ReentrancyGuard {
+    pub fn deployed() -> ReentrancyGuard {
+        let mut helper = CasperHelper::new();
+
+        // ====================== CONTRACT DEPLOYMENT ======================
+        let hash = helper.deploy_contract(
+            PathBuf::from("reentrancy_guard.wasm"),
+            runtime_args! {},
+            helper.keys[0].clone(),
+            REENTRANCY_GUARD_CONTRACT_KEY_NAME.to_string(),
+        );
+
+        let attacker_hash = helper.deploy_contract(
+            PathBuf::from("reentrancy_attacker.wasm"),
+            runtime_args! {
+                "target" => Key::Hash(hash)
+            },
+            helper.keys[0].clone(),
+            REENTRANCY_ATTACKER_CONTRACT_KEY_NAME.to_string(),
+        );
+
+        // ====================== FUNCTION RETURN ======================
+        ReentrancyGuard {
+            helper: helper.clone(),
+            hash,
+            attacker_hash,
+            ali: helper.accounts[0].clone(),
+            bob: helper.accounts[1].clone(),
+        }
+    }
+
+    pub fn counter(&self) -> u64 {
+        self.helper
+            .query_contract(REENTRANCY_GUARD_CONTRACT_KEY_NAME.to_string(), "counter")
+            .unwrap()
+    }
+
+    pub fn guarded_increment(&mut self, sender: Sender) {
+        self.helper
+            .call(self.hash, sender, "guarded_increment", runtime_args! {});
+    }
+
+    pub fn guarded_callback(&mut self, entry_point: &str, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "guarded_callback",
+            runtime_args! {
+                "callback" => Key::Hash(self.attacker_hash),
+                "entry_point" => entry_point.to_string()
+            },
+        );
+    }
+}
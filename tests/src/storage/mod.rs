@@ -0,0 +1,2 @@
+pub mod storage_helper;
+pub mod storage_test;
@@ -0,0 +1,82 @@
+use crate::utilities::{CasperHelper, Hash, Sender};
+use casper_types::{account::AccountHash, runtime_args, RuntimeArgs};
+use std::path::PathBuf;
+
+// contains methods that can simulate a real-world deployment (storing the contract in the blockchain)
+// and transactions to invoke the methods in the contract.
+pub const STORAGE_CONTRACT_KEY_NAME: &str = "Storage";
+
+pub struct Storage {
+    pub helper: CasperHelper,
+    pub hash: Hash,
+    pub ali: AccountHash,
+}
+
+impl Storage {
+    pub fn deployed() -> Storage {
+        let mut helper = CasperHelper::new();
+
+        // ====================== CONTRACT DEPLOYMENT ======================
+        let hash = helper.deploy_contract(
+            PathBuf::from("storage.wasm"),
+            runtime_args! {},
+            helper.keys[0].clone(),
+            STORAGE_CONTRACT_KEY_NAME.to_string(),
+        );
+
+        // ====================== FUNCTION RETURN ======================
+        Storage {
+            helper: helper.clone(),
+            hash,
+            ali: helper.accounts[0].clone(),
+        }
+    }
+
+    /// Returns the result of the latest `read_value` or `read_entry` call.
+    pub fn last_read(&self) -> Option<u64> {
+        self.helper
+            .query_contract::<Option<u64>>(STORAGE_CONTRACT_KEY_NAME.to_string(), "last_read")
+            .unwrap()
+    }
+
+    pub fn set_value(&mut self, value: u64, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "set_value",
+            runtime_args! {
+                "value" => value
+            },
+        );
+    }
+
+    pub fn read_value(&mut self, sender: Sender) -> Option<u64> {
+        self.helper
+            .call(self.hash, sender, "read_value", runtime_args! {});
+        self.last_read()
+    }
+
+    pub fn set_entry(&mut self, key: &str, value: u64, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "set_entry",
+            runtime_args! {
+                "key" => key.to_string(),
+                "value" => value
+            },
+        );
+    }
+
+    pub fn read_entry(&mut self, key: &str, sender: Sender) -> Option<u64> {
+        self.helper.call(
+            self.hash,
+            sender,
+            "read_entry",
+            runtime_args! {
+                "key" => key.to_string()
+            },
+        );
+        self.last_read()
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{storage::storage_helper::Storage, utilities::Sender};
+
+// ------------ START - Storage Tests ------------
+
+#[test]
+fn should_read_missing_variable_as_none() {
+    let mut contract = Storage::deployed();
+    assert_eq!(contract.read_value(Sender(contract.ali)), None);
+}
+
+#[test]
+fn should_read_variable_set_to_default_value() {
+    let mut contract = Storage::deployed();
+    contract.set_value(0, Sender(contract.ali));
+    assert_eq!(contract.read_value(Sender(contract.ali)), Some(0));
+
+    contract.set_value(7, Sender(contract.ali));
+    assert_eq!(contract.read_value(Sender(contract.ali)), Some(7));
+}
+
+#[test]
+fn should_read_entry_of_missing_mapping_as_none() {
+    let mut contract = Storage::deployed();
+    assert_eq!(contract.read_entry("first", Sender(contract.ali)), None);
+}
+
+#[test]
+fn should_read_missing_entry_as_none() {
+    let mut contract = Storage::deployed();
+    contract.set_entry("first", 7, Sender(contract.ali));
+    assert_eq!(contract.read_entry("second", Sender(contract.ali)), None);
+}
+
+#[test]
+fn should_read_entry_set_to_default_value() {
+    let mut contract = Storage::deployed();
+    contract.set_entry("first", 0, Sender(contract.ali));
+    assert_eq!(contract.read_entry("first", Sender(contract.ali)), Some(0));
+    assert_eq!(contract.read_entry("second", Sender(contract.ali)), None);
+}
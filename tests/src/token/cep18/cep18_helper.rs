@@ -0,0 +1,281 @@
+use crate::utilities::{make_dictionary_item_key, CasperHelper, Hash, Sender};
+
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{Bytes, ToBytes},
+    runtime_args, Key, RuntimeArgs, U256,
+};
+use libs::token::erc20::{cep18::SecurityBadge, CEP18, ERC20};
+use std::path::PathBuf;
+
+pub mod token_cfg {
+    use super::*;
+    pub const NAME: &str = "CEP18";
+    pub const SYMBOL: &str = "CEP";
+    pub const DECIMALS: u8 = 8;
+    pub fn total_supply() -> U256 {
+        U256::from(1000_000_000_000_000u128)
+    }
+}
+
+pub const CEP18_CONTRACT_KEY_NAME: &str = "Cep18";
+
+/// Item key of `key` in the `balances` and `security_badges` dictionaries, as CEP-18 clients
+/// compute it: the base64-encoded bytes of the key.
+pub fn cep18_item_key(key: &Key) -> String {
+    base64::encode(key.to_bytes().unwrap())
+}
+
+pub struct Cep18 {
+    pub helper: CasperHelper,
+    pub hash: Hash,
+    pub ali: AccountHash,
+    pub bob: AccountHash,
+    pub joe: AccountHash,
+}
+
+impl Cep18 {
+    /// Deploys the CEP-18 contract with minting and burning enabled, `bob` being a minter.
+    pub fn deployed() -> Cep18 {
+        let helper = CasperHelper::new();
+        let bob = helper.accounts[1];
+        Cep18::deployed_with(helper, 1, vec![], vec![Key::Account(bob)])
+    }
+
+    /// Deploys the CEP-18 contract with minting and burning disabled.
+    pub fn deployed_without_mint_burn() -> Cep18 {
+        Cep18::deployed_with(CasperHelper::new(), 0, vec![], vec![])
+    }
+
+    fn deployed_with(
+        mut helper: CasperHelper,
+        enable_mint_burn: u8,
+        admin_list: Vec<Key>,
+        minter_list: Vec<Key>,
+    ) -> Cep18 {
+        // ====================== CONTRACT DEPLOYMENT ======================
+        let session_code = PathBuf::from("cep18.wasm");
+        let session_args = runtime_args! {
+            "name" => token_cfg::NAME.to_string(),
+            "symbol" => token_cfg::SYMBOL.to_string(),
+            "decimals" => token_cfg::DECIMALS,
+            "total_supply" => token_cfg::total_supply(),
+            "enable_mint_burn" => enable_mint_burn,
+            "admin_list" => admin_list,
+            "minter_list" => minter_list,
+        };
+
+        let hash = helper.deploy_contract(
+            session_code,
+            session_args,
+            helper.keys[0].clone(),
+            CEP18_CONTRACT_KEY_NAME.to_string(),
+        );
+
+        // ====================== FUNCTION RETURN ======================
+        Cep18 {
+            helper: helper.clone(),
+            hash,
+            ali: helper.accounts[0].clone(),
+            bob: helper.accounts[1].clone(),
+            joe: helper.accounts[2].clone(),
+        }
+    }
+
+    pub fn name(&self) -> String {
+        self.helper
+            .query_contract(CEP18_CONTRACT_KEY_NAME.to_string(), ERC20::ERC20_NAME_KEY)
+            .unwrap()
+    }
+
+    pub fn symbol(&self) -> String {
+        self.helper
+            .query_contract(CEP18_CONTRACT_KEY_NAME.to_string(), ERC20::ERC20_SYMBOL_KEY)
+            .unwrap()
+    }
+
+    pub fn decimals(&self) -> u8 {
+        self.helper
+            .query_contract(
+                CEP18_CONTRACT_KEY_NAME.to_string(),
+                ERC20::ERC20_DECIMALS_KEY,
+            )
+            .unwrap()
+    }
+
+    pub fn total_supply(&self) -> U256 {
+        self.helper
+            .query_contract(
+                CEP18_CONTRACT_KEY_NAME.to_string(),
+                ERC20::ERC20_TOTAL_SUPPLY_KEY,
+            )
+            .unwrap()
+    }
+
+    pub fn enable_mint_burn(&self) -> u8 {
+        self.helper
+            .query_contract(
+                CEP18_CONTRACT_KEY_NAME.to_string(),
+                CEP18::CEP18_ENABLE_MINT_BURN_KEY,
+            )
+            .unwrap()
+    }
+
+    pub fn balance_of(&self, address: Key) -> U256 {
+        self.helper
+            .query_dictionary_value(
+                self.hash,
+                CEP18::CEP18_BALANCES_KEY,
+                cep18_item_key(&address),
+            )
+            .unwrap_or_default()
+    }
+
+    pub fn allowance(&self, owner: Key, spender: Key) -> U256 {
+        self.helper
+            .query_dictionary_value(
+                self.hash,
+                CEP18::CEP18_ALLOWANCES_KEY,
+                make_dictionary_item_key(&(owner, spender)),
+            )
+            .unwrap_or_default()
+    }
+
+    pub fn security_badge(&self, account: Key) -> Option<SecurityBadge> {
+        self.helper
+            .query_dictionary_value::<u8>(
+                self.hash,
+                CEP18::CEP18_SECURITY_BADGES_KEY,
+                cep18_item_key(&account),
+            )
+            .and_then(SecurityBadge::from_u8)
+    }
+
+    pub fn events_length(&self) -> u32 {
+        self.helper
+            .query_contract(
+                CEP18_CONTRACT_KEY_NAME.to_string(),
+                CEP18::CEP18_EVENTS_LENGTH_KEY,
+            )
+            .unwrap()
+    }
+
+    pub fn events_schema(&self) -> Bytes {
+        self.helper
+            .query_contract(
+                CEP18_CONTRACT_KEY_NAME.to_string(),
+                CEP18::CEP18_EVENTS_SCHEMA_KEY,
+            )
+            .unwrap()
+    }
+
+    /// Returns the serialized event recorded at `index`.
+    pub fn event(&self, index: u32) -> Option<Bytes> {
+        self.helper
+            .query_dictionary_value(self.hash, CEP18::CEP18_EVENTS_KEY, index.to_string())
+    }
+
+    pub fn transfer(&mut self, recipient: Key, amount: U256, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "transfer",
+            runtime_args! {
+                "recipient" => recipient,
+                "amount" => amount
+            },
+        );
+    }
+
+    pub fn transfer_from(&mut self, owner: Key, recipient: Key, amount: U256, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "transfer_from",
+            runtime_args! {
+                "owner" => owner,
+                "recipient" => recipient,
+                "amount" => amount
+            },
+        );
+    }
+
+    pub fn approve(&mut self, spender: Key, amount: U256, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "approve",
+            runtime_args! {
+                "spender" => spender,
+                "amount" => amount
+            },
+        );
+    }
+
+    pub fn increase_allowance(&mut self, spender: Key, amount: U256, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "increase_allowance",
+            runtime_args! {
+                "spender" => spender,
+                "amount" => amount
+            },
+        );
+    }
+
+    pub fn decrease_allowance(&mut self, spender: Key, amount: U256, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "decrease_allowance",
+            runtime_args! {
+                "spender" => spender,
+                "amount" => amount
+            },
+        );
+    }
+
+    pub fn mint(&mut self, owner: Key, amount: U256, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "mint",
+            runtime_args! {
+                "owner" => owner,
+                "amount" => amount
+            },
+        );
+    }
+
+    pub fn burn(&mut self, owner: Key, amount: U256, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "burn",
+            runtime_args! {
+                "owner" => owner,
+                "amount" => amount
+            },
+        );
+    }
+
+    pub fn change_security(
+        &mut self,
+        admin_list: Vec<Key>,
+        minter_list: Vec<Key>,
+        none_list: Vec<Key>,
+        sender: Sender,
+    ) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "change_security",
+            runtime_args! {
+                "admin_list" => admin_list,
+                "minter_list" => minter_list,
+                "none_list" => none_list
+            },
+        );
+    }
+}
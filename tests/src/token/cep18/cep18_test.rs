@@ -0,0 +1,293 @@
+use casper_types::{
+    bytesrepr::{Bytes, ToBytes},
+    runtime_args, Key, RuntimeArgs, U256,
+};
+use libs::token::erc20::{
+    cep18::{CEP18Event, SecurityBadge},
+    error::Erc20Error,
+    CEP18,
+};
+
+use crate::{
+    token::cep18::cep18_helper::{token_cfg, Cep18},
+    utilities::{to_key, Sender},
+};
+
+// ------------ START - CEP18 Tests ------------
+
+#[test]
+fn should_deploy_with_cep18_named_keys() {
+    let contract = Cep18::deployed();
+
+    assert_eq!(contract.name(), token_cfg::NAME);
+    assert_eq!(contract.symbol(), token_cfg::SYMBOL);
+    assert_eq!(contract.decimals(), token_cfg::DECIMALS);
+    assert_eq!(contract.total_supply(), token_cfg::total_supply());
+    assert_eq!(contract.enable_mint_burn(), 1);
+
+    assert_eq!(
+        contract.balance_of(to_key(contract.ali)),
+        token_cfg::total_supply()
+    );
+    assert_eq!(
+        contract.security_badge(to_key(contract.ali)),
+        Some(SecurityBadge::Admin)
+    );
+    assert_eq!(
+        contract.security_badge(to_key(contract.bob)),
+        Some(SecurityBadge::Minter)
+    );
+    assert_eq!(contract.security_badge(to_key(contract.joe)), None);
+
+    assert_eq!(contract.events_schema(), CEP18::events_schema());
+    assert_eq!(contract.events_length(), 0);
+}
+
+#[test]
+fn should_run_cep18_client_call_sequence() {
+    let mut contract = Cep18::deployed();
+    let (ali, bob, joe) = (
+        to_key(contract.ali),
+        to_key(contract.bob),
+        to_key(contract.joe),
+    );
+    let amount = U256::from(1000_000_000_000u128);
+
+    contract.transfer(bob, amount, Sender(contract.ali));
+    assert_eq!(contract.balance_of(bob), amount);
+    assert_eq!(contract.balance_of(ali), token_cfg::total_supply() - amount);
+
+    contract.approve(joe, amount, Sender(contract.bob));
+    assert_eq!(contract.allowance(bob, joe), amount);
+    contract.increase_allowance(joe, amount, Sender(contract.bob));
+    assert_eq!(contract.allowance(bob, joe), amount * 2);
+    contract.decrease_allowance(joe, amount, Sender(contract.bob));
+    assert_eq!(contract.allowance(bob, joe), amount);
+
+    contract.transfer_from(bob, joe, amount, Sender(contract.joe));
+    assert_eq!(contract.allowance(bob, joe), U256::zero());
+    assert_eq!(contract.balance_of(bob), U256::zero());
+    assert_eq!(contract.balance_of(joe), amount);
+
+    contract.mint(joe, amount, Sender(contract.bob));
+    assert_eq!(contract.balance_of(joe), amount * 2);
+    assert_eq!(contract.total_supply(), token_cfg::total_supply() + amount);
+
+    contract.burn(joe, amount, Sender(contract.joe));
+    assert_eq!(contract.balance_of(joe), amount);
+    assert_eq!(contract.total_supply(), token_cfg::total_supply());
+}
+
+#[test]
+fn should_record_cep18_events() {
+    let mut contract = Cep18::deployed();
+    let (ali, bob) = (to_key(contract.ali), to_key(contract.bob));
+    let amount = U256::from(10);
+
+    contract.transfer(bob, amount, Sender(contract.ali));
+    contract.approve(ali, amount, Sender(contract.bob));
+
+    assert_eq!(contract.events_length(), 2);
+    // the `event_` prefixed name of the event, then its fields in the order of the schema.
+    let mut transfer = "event_Transfer".to_string().to_bytes().unwrap();
+    transfer.extend(ali.to_bytes().unwrap());
+    transfer.extend(bob.to_bytes().unwrap());
+    transfer.extend(amount.to_bytes().unwrap());
+    assert_eq!(contract.event(0), Some(Bytes::from(transfer)));
+    assert_eq!(
+        contract.event(1),
+        Some(Bytes::from(
+            CEP18Event::SetAllowance {
+                owner: bob,
+                spender: ali,
+                allowance: amount
+            }
+            .to_ces_bytes()
+        ))
+    );
+}
+
+#[test]
+fn should_decrease_allowance_down_to_zero() {
+    let mut contract = Cep18::deployed();
+    let (ali, bob) = (to_key(contract.ali), to_key(contract.bob));
+
+    contract.approve(bob, U256::from(10), Sender(contract.ali));
+    contract.decrease_allowance(bob, U256::from(20), Sender(contract.ali));
+
+    assert_eq!(contract.allowance(ali, bob), U256::zero());
+}
+
+#[test]
+fn should_not_transfer_from_more_than_allowed() {
+    let mut contract = Cep18::deployed();
+    let (ali, bob) = (to_key(contract.ali), to_key(contract.bob));
+    contract.approve(bob, U256::from(10), Sender(contract.ali));
+
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.bob),
+        "transfer_from",
+        runtime_args! {
+            "owner" => ali,
+            "recipient" => bob,
+            "amount" => U256::from(11)
+        },
+        Erc20Error::InsufficientAllowance,
+    );
+}
+
+#[test]
+fn should_not_transfer_to_self() {
+    let mut contract = Cep18::deployed();
+    let ali = to_key(contract.ali);
+
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "transfer",
+        runtime_args! {
+            "recipient" => ali,
+            "amount" => U256::from(1)
+        },
+        Erc20Error::CannotTargetSelfUser,
+    );
+}
+
+#[test]
+fn should_not_transfer_from_owner_to_itself() {
+    let mut contract = Cep18::deployed();
+    let (ali, bob) = (to_key(contract.ali), to_key(contract.bob));
+    contract.approve(bob, U256::from(10), Sender(contract.ali));
+
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.bob),
+        "transfer_from",
+        runtime_args! {
+            "owner" => ali,
+            "recipient" => ali,
+            "amount" => U256::from(1)
+        },
+        Erc20Error::CannotTargetSelfUser,
+    );
+}
+
+#[test]
+fn should_not_mint_without_badge() {
+    let mut contract = Cep18::deployed();
+    let joe = to_key(contract.joe);
+
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.joe),
+        "mint",
+        runtime_args! {
+            "owner" => joe,
+            "amount" => U256::from(1)
+        },
+        Erc20Error::InsufficientRights,
+    );
+}
+
+#[test]
+fn should_not_mint_or_burn_when_disabled() {
+    let mut contract = Cep18::deployed_without_mint_burn();
+    let ali = to_key(contract.ali);
+
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "mint",
+        runtime_args! {
+            "owner" => ali,
+            "amount" => U256::from(1)
+        },
+        Erc20Error::MintBurnDisabled,
+    );
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "burn",
+        runtime_args! {
+            "owner" => ali,
+            "amount" => U256::from(1)
+        },
+        Erc20Error::MintBurnDisabled,
+    );
+}
+
+#[test]
+fn should_not_burn_tokens_of_others() {
+    let mut contract = Cep18::deployed();
+    let ali = to_key(contract.ali);
+
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.bob),
+        "burn",
+        runtime_args! {
+            "owner" => ali,
+            "amount" => U256::from(1)
+        },
+        Erc20Error::InvalidBurnTarget,
+    );
+}
+
+#[test]
+fn should_change_security() {
+    let mut contract = Cep18::deployed();
+    let (bob, joe) = (to_key(contract.bob), to_key(contract.joe));
+
+    contract.change_security(vec![], vec![joe], vec![bob], Sender(contract.ali));
+    assert_eq!(contract.security_badge(bob), Some(SecurityBadge::None));
+    assert_eq!(contract.security_badge(joe), Some(SecurityBadge::Minter));
+
+    contract.mint(joe, U256::from(1), Sender(contract.joe));
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.bob),
+        "mint",
+        runtime_args! {
+            "owner" => bob,
+            "amount" => U256::from(1)
+        },
+        Erc20Error::InsufficientRights,
+    );
+}
+
+#[test]
+fn should_not_change_security_as_minter() {
+    let mut contract = Cep18::deployed();
+    let joe = to_key(contract.joe);
+
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.bob),
+        "change_security",
+        runtime_args! {
+            "admin_list" => vec![joe],
+            "minter_list" => Vec::<Key>::new(),
+            "none_list" => Vec::<Key>::new()
+        },
+        Erc20Error::InsufficientRights,
+    );
+}
+
+#[test]
+fn should_not_strip_own_admin_badge() {
+    let mut contract = Cep18::deployed();
+    let ali = to_key(contract.ali);
+
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "change_security",
+        runtime_args! {
+            "admin_list" => Vec::<Key>::new(),
+            "minter_list" => Vec::<Key>::new(),
+            "none_list" => vec![ali]
+        },
+        Erc20Error::CannotTargetSelfUser,
+    );
+}
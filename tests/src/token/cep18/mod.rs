@@ -0,0 +1,2 @@
+pub mod cep18_helper;
+pub mod cep18_test;
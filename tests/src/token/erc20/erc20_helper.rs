@@ -1,9 +1,9 @@
-use crate::utilities::{key_to_str, to_key, two_key_to_str, CasperHelper, Hash, Sender};
+use crate::utilities::{make_dictionary_item_key, to_key, CasperHelper, Hash, Sender};
 
 use casper_types::{
     account::AccountHash, runtime_args, CLTyped, ContractHash, Key, Motes, RuntimeArgs, U256,
 };
-use libs::token::erc20::ERC20;
+use libs::{token::erc20::ERC20, utils::CallerIdentity};
 use std::path::PathBuf;
 
 pub mod token_cfg {
@@ -29,8 +29,28 @@ pub struct Erc20 {
 
 impl Erc20 {
     pub fn deployed(name: String, symbol: String, decimals: u8, total_supply: U256) -> Erc20 {
-        let mut helper = CasperHelper::new();
+        Erc20::deployed_with_caller_identity(
+            name,
+            symbol,
+            decimals,
+            total_supply,
+            CasperHelper::new(),
+            CallerIdentity::ImmediateCaller,
+            vec![],
+        )
+    }
 
+    /// Deploys the ERC20 contract next to the contracts already deployed by `helper`,
+    /// authorizing the given caller identity through the given trusted forwarders.
+    pub fn deployed_with_caller_identity(
+        name: String,
+        symbol: String,
+        decimals: u8,
+        total_supply: U256,
+        mut helper: CasperHelper,
+        caller_identity: CallerIdentity,
+        trusted_forwarders: Vec<Key>,
+    ) -> Erc20 {
         // ====================== CONTRACT DEPLOYMENT ======================
         let session_code = PathBuf::from("erc20.wasm");
         let session_args = runtime_args! {
@@ -38,6 +58,8 @@ impl Erc20 {
             "symbol" => symbol,
             "decimals" => decimals,
             "total_supply" => total_supply,
+            "caller_identity" => caller_identity as u8,
+            "trusted_forwarders" => trusted_forwarders,
         };
 
         let hash = helper.deploy_contract(
@@ -57,6 +79,16 @@ impl Erc20 {
         }
     }
 
+    /// Deploys the ERC20 session code again, adding a new version to the contract package.
+    pub fn upgrade(&mut self) {
+        self.hash = self.helper.upgrade_contract(
+            PathBuf::from("erc20.wasm"),
+            runtime_args! {},
+            self.helper.keys[0].clone(),
+            ERC20_TOKEN_CONTRACT_KEY_NAME.to_string(),
+        );
+    }
+
     pub fn name(&self) -> String {
         self.helper
             .query_contract(
@@ -95,7 +127,11 @@ impl Erc20 {
 
     pub fn balance_of(&self, account: Key) -> U256 {
         self.helper
-            .query_dictionary_value(self.hash, ERC20::ERC20_BALANCE_KEY, key_to_str(&account))
+            .query_dictionary_value(
+                self.hash,
+                ERC20::ERC20_BALANCE_KEY,
+                make_dictionary_item_key(&account),
+            )
             .unwrap_or_default()
     }
 
@@ -104,7 +140,7 @@ impl Erc20 {
             .query_dictionary_value(
                 self.hash,
                 ERC20::ERC20_ALLOWANCE_KEY,
-                two_key_to_str(owner, spender),
+                make_dictionary_item_key(&(owner, spender)),
             )
             .unwrap_or_default()
     }
@@ -121,6 +157,41 @@ impl Erc20 {
         );
     }
 
+    pub fn transfer_batch(&mut self, recipients: Vec<Key>, amounts: Vec<U256>, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "transfer_batch",
+            runtime_args! {
+                "recipients" => recipients,
+                "amounts" => amounts
+            },
+        );
+    }
+
+    pub fn mint_batch(&mut self, recipients: Vec<Key>, amounts: Vec<U256>, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "mint_batch",
+            runtime_args! {
+                "recipients" => recipients,
+                "amounts" => amounts
+            },
+        );
+    }
+
+    pub fn transfer_ownership(&mut self, new_owner: Key, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "transfer_ownership",
+            runtime_args! {
+                "new_owner" => new_owner
+            },
+        );
+    }
+
     pub fn approve(&mut self, spender: Key, amount: U256, sender: Sender) {
         self.helper.call(
             self.hash,
@@ -132,4 +203,35 @@ impl Erc20 {
             },
         );
     }
+
+    pub fn approve_from(
+        &mut self,
+        spender: Key,
+        current_amount: U256,
+        new_amount: U256,
+        sender: Sender,
+    ) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "approve_from",
+            runtime_args! {
+                "spender" => spender,
+                "current_amount" => current_amount,
+                "new_amount" => new_amount
+            },
+        );
+    }
+
+    pub fn increase_allowance(&mut self, spender: Key, amount: U256, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "increase_allowance",
+            runtime_args! {
+                "spender" => spender,
+                "amount" => amount
+            },
+        );
+    }
 }
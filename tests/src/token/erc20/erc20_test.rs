@@ -1,8 +1,13 @@
-use casper_types::{account::AccountHash, Key, U256};
+use casper_execution_engine::core::execution::Error as ExecError;
+use casper_types::{
+    account::AccountHash, runtime_args, AccessRights, ContractHash, Key, RuntimeArgs, URef, U256,
+    U512,
+};
+use libs::{access::error::OwnableError, error::Error, token::erc20::error::Erc20Error};
 
 use crate::{
     token::erc20::erc20_helper::{token_cfg, Erc20},
-    utilities::{key_to_str, to_key, Sender},
+    utilities::{to_key, Sender},
 };
 
 // ------------ START - ERC20 Tests ------------
@@ -42,7 +47,23 @@ fn should_transfer_token() {
 }
 
 #[test]
-#[should_panic = "65530"]
+fn should_transfer_token_to_self() {
+    let mut contract = Erc20::deployed(
+        token_cfg::NAME.to_string(),
+        token_cfg::SYMBOL.to_string(),
+        token_cfg::DECIMALS,
+        token_cfg::total_supply(),
+    );
+
+    let amount = U256::from(1000_000_000_000u128);
+    contract.transfer(to_key(contract.ali), amount, Sender(contract.ali));
+    assert_eq!(
+        contract.balance_of(to_key(contract.ali)),
+        token_cfg::total_supply()
+    );
+}
+
+#[test]
 fn should_not_transfer_token_to_zero_address() {
     let mut contract = Erc20::deployed(
         token_cfg::NAME.to_string(),
@@ -52,15 +73,67 @@ fn should_not_transfer_token_to_zero_address() {
     );
 
     let amount = U256::from(1000_000_000_000u128);
-    contract.transfer(
-        Key::Account(AccountHash::default()),
-        amount,
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "transfer",
+        runtime_args! {
+            "to" => Key::Account(AccountHash::default()),
+            "amount" => amount
+        },
+        Error::ZeroAddress,
+    );
+}
+
+#[test]
+fn should_not_transfer_token_to_zero_contract_hash() {
+    let mut contract = Erc20::deployed(
+        token_cfg::NAME.to_string(),
+        token_cfg::SYMBOL.to_string(),
+        token_cfg::DECIMALS,
+        token_cfg::total_supply(),
+    );
+
+    let amount = U256::from(1000_000_000_000u128);
+    contract.helper.call_expect_error(
+        contract.hash,
         Sender(contract.ali),
+        "transfer",
+        runtime_args! {
+            "to" => Key::Hash([0u8; 32]),
+            "amount" => amount
+        },
+        Error::ZeroAddress,
+    );
+}
+
+#[test]
+fn should_not_transfer_token_to_uref() {
+    let mut contract = Erc20::deployed(
+        token_cfg::NAME.to_string(),
+        token_cfg::SYMBOL.to_string(),
+        token_cfg::DECIMALS,
+        token_cfg::total_supply(),
+    );
+
+    let amount = U256::from(1000_000_000_000u128);
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "transfer",
+        runtime_args! {
+            "to" => Key::URef(URef::new([1u8; 32], AccessRights::READ)),
+            "amount" => amount
+        },
+        Error::InvalidKeyVariant,
+    );
+    assert_eq!(
+        contract.balance_of(to_key(contract.ali)),
+        token_cfg::total_supply()
     );
 }
 
 #[test]
-#[should_panic = "65529"]
 fn should_not_transfer_bigger_amount_than_balance() {
     let mut contract = Erc20::deployed(
         token_cfg::NAME.to_string(),
@@ -70,7 +143,16 @@ fn should_not_transfer_bigger_amount_than_balance() {
     );
 
     let amount = token_cfg::total_supply() + U256::from(10u128);
-    contract.transfer(to_key(contract.bob), amount, Sender(contract.ali));
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "transfer",
+        runtime_args! {
+            "to" => to_key(contract.bob),
+            "amount" => amount
+        },
+        Erc20Error::InsufficientBalance,
+    );
 }
 
 #[test]
@@ -91,7 +173,6 @@ fn should_approve_token() {
 }
 
 #[test]
-#[should_panic = "65530"]
 fn should_not_approve_token_to_zero_address() {
     let mut contract = Erc20::deployed(
         token_cfg::NAME.to_string(),
@@ -101,9 +182,338 @@ fn should_not_approve_token_to_zero_address() {
     );
 
     let amount = U256::from(1000_000_000_000u128);
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "approve",
+        runtime_args! {
+            "spender" => Key::Account(AccountHash::default()),
+            "amount" => amount
+        },
+        Error::ZeroAddress,
+    );
+}
+
+#[test]
+fn should_approve_from_current_allowance() {
+    let mut contract = Erc20::deployed(
+        token_cfg::NAME.to_string(),
+        token_cfg::SYMBOL.to_string(),
+        token_cfg::DECIMALS,
+        token_cfg::total_supply(),
+    );
+
     contract.approve(
-        Key::Account(AccountHash::default()),
-        amount,
+        to_key(contract.bob),
+        U256::from(100u64),
+        Sender(contract.ali),
+    );
+    contract.approve_from(
+        to_key(contract.bob),
+        U256::from(100u64),
+        U256::from(50u64),
+        Sender(contract.ali),
+    );
+    assert_eq!(
+        contract.allowance(to_key(contract.ali), to_key(contract.bob)),
+        U256::from(50u64)
+    );
+}
+
+#[test]
+fn should_not_approve_from_outdated_allowance() {
+    let mut contract = Erc20::deployed(
+        token_cfg::NAME.to_string(),
+        token_cfg::SYMBOL.to_string(),
+        token_cfg::DECIMALS,
+        token_cfg::total_supply(),
+    );
+
+    // the spender already used part of the allowance the owner expects to replace.
+    contract.approve(
+        to_key(contract.bob),
+        U256::from(100u64),
+        Sender(contract.ali),
+    );
+    contract.helper.call(
+        contract.hash,
+        Sender(contract.bob),
+        "transfer_from",
+        runtime_args! {
+            "from" => to_key(contract.ali),
+            "to" => to_key(contract.bob),
+            "amount" => U256::from(60u64)
+        },
+    );
+
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "approve_from",
+        runtime_args! {
+            "spender" => to_key(contract.bob),
+            "current_amount" => U256::from(100u64),
+            "new_amount" => U256::from(50u64)
+        },
+        Erc20Error::AllowanceMismatch,
+    );
+    assert_eq!(
+        contract.allowance(to_key(contract.ali), to_key(contract.bob)),
+        U256::from(40u64)
+    );
+}
+
+#[test]
+fn should_not_increase_allowance_past_max() {
+    let mut contract = Erc20::deployed(
+        token_cfg::NAME.to_string(),
+        token_cfg::SYMBOL.to_string(),
+        token_cfg::DECIMALS,
+        token_cfg::total_supply(),
+    );
+
+    contract.increase_allowance(to_key(contract.bob), U256::MAX, Sender(contract.ali));
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "increase_allowance",
+        runtime_args! {
+            "spender" => to_key(contract.bob),
+            "amount" => U256::one()
+        },
+        Error::Overflow,
+    );
+    assert_eq!(
+        contract.allowance(to_key(contract.ali), to_key(contract.bob)),
+        U256::MAX
+    );
+}
+
+fn batch_recipients(count: u8) -> Vec<Key> {
+    (1..=count)
+        .map(|i| Key::Account(AccountHash::new([i; 32])))
+        .collect()
+}
+
+#[test]
+fn should_transfer_batch() {
+    let mut contract = Erc20::deployed(
+        token_cfg::NAME.to_string(),
+        token_cfg::SYMBOL.to_string(),
+        token_cfg::DECIMALS,
+        token_cfg::total_supply(),
+    );
+
+    let recipients = vec![to_key(contract.bob), to_key(contract.joe)];
+    let amounts = vec![U256::from(10u64), U256::from(20u64)];
+    contract.transfer_batch(recipients, amounts, Sender(contract.ali));
+
+    assert_eq!(contract.balance_of(to_key(contract.bob)), U256::from(10u64));
+    assert_eq!(contract.balance_of(to_key(contract.joe)), U256::from(20u64));
+    assert_eq!(
+        contract.balance_of(to_key(contract.ali)),
+        token_cfg::total_supply() - U256::from(30u64)
+    );
+}
+
+#[test]
+fn should_not_transfer_batch_with_mismatched_lengths() {
+    let mut contract = Erc20::deployed(
+        token_cfg::NAME.to_string(),
+        token_cfg::SYMBOL.to_string(),
+        token_cfg::DECIMALS,
+        token_cfg::total_supply(),
+    );
+
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "transfer_batch",
+        runtime_args! {
+            "recipients" => vec![to_key(contract.bob), to_key(contract.joe)],
+            "amounts" => vec![U256::from(10u64)]
+        },
+        Erc20Error::BatchLengthMismatch,
+    );
+}
+
+#[test]
+fn should_not_transfer_batch_bigger_than_balance() {
+    let mut contract = Erc20::deployed(
+        token_cfg::NAME.to_string(),
+        token_cfg::SYMBOL.to_string(),
+        token_cfg::DECIMALS,
+        token_cfg::total_supply(),
+    );
+
+    // Every leg is affordable on its own, the whole batch is not.
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "transfer_batch",
+        runtime_args! {
+            "recipients" => vec![to_key(contract.bob), to_key(contract.joe)],
+            "amounts" => vec![token_cfg::total_supply(), U256::one()]
+        },
+        Erc20Error::InsufficientBalance,
+    );
+    assert_eq!(contract.balance_of(to_key(contract.bob)), U256::zero());
+}
+
+#[test]
+fn should_transfer_batch_for_less_gas_than_single_transfers() {
+    let mut contract = Erc20::deployed(
+        token_cfg::NAME.to_string(),
+        token_cfg::SYMBOL.to_string(),
+        token_cfg::DECIMALS,
+        token_cfg::total_supply(),
+    );
+
+    let recipients = batch_recipients(10);
+    let amounts = vec![U256::from(10u64); recipients.len()];
+
+    contract.transfer_batch(recipients.clone(), amounts.clone(), Sender(contract.ali));
+    let batch_gas = contract.helper.builder.last_exec_gas_cost().value();
+
+    let mut single_gas = U512::zero();
+    for (recipient, amount) in recipients.iter().zip(amounts) {
+        contract.transfer(*recipient, amount, Sender(contract.ali));
+        single_gas += contract.helper.builder.last_exec_gas_cost().value();
+    }
+
+    for recipient in recipients {
+        assert_eq!(contract.balance_of(recipient), U256::from(20u64));
+    }
+    assert!(
+        batch_gas < single_gas,
+        "batch transfer cost {} gas, single transfers cost {} gas",
+        batch_gas,
+        single_gas
+    );
+}
+
+#[test]
+fn should_mint_batch() {
+    let mut contract = Erc20::deployed(
+        token_cfg::NAME.to_string(),
+        token_cfg::SYMBOL.to_string(),
+        token_cfg::DECIMALS,
+        token_cfg::total_supply(),
+    );
+    let total_supply = contract.total_supply();
+
+    let recipients = batch_recipients(3);
+    let amounts = vec![U256::from(10u64); recipients.len()];
+    contract.mint_batch(recipients.clone(), amounts, Sender(contract.ali));
+
+    for recipient in recipients {
+        assert_eq!(contract.balance_of(recipient), U256::from(10u64));
+    }
+    assert_eq!(contract.total_supply(), total_supply + U256::from(30u64));
+}
+
+#[test]
+fn should_not_mint_batch_by_invalid_owner() {
+    let mut contract = Erc20::deployed(
+        token_cfg::NAME.to_string(),
+        token_cfg::SYMBOL.to_string(),
+        token_cfg::DECIMALS,
+        token_cfg::total_supply(),
+    );
+
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.bob),
+        "mint_batch",
+        runtime_args! {
+            "recipients" => vec![to_key(contract.bob)],
+            "amounts" => vec![U256::from(10u64)]
+        },
+        OwnableError::CallerNotOwner,
+    );
+}
+
+#[test]
+fn should_mint_batch_by_new_owner() {
+    let mut contract = Erc20::deployed(
+        token_cfg::NAME.to_string(),
+        token_cfg::SYMBOL.to_string(),
+        token_cfg::DECIMALS,
+        token_cfg::total_supply(),
+    );
+    contract.transfer_ownership(to_key(contract.bob), Sender(contract.ali));
+
+    contract.mint_batch(
+        vec![to_key(contract.joe)],
+        vec![U256::from(10u64)],
+        Sender(contract.bob),
+    );
+    assert_eq!(contract.balance_of(to_key(contract.joe)), U256::from(10u64));
+
+    contract.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.ali),
+        "mint_batch",
+        runtime_args! {
+            "recipients" => vec![to_key(contract.ali)],
+            "amounts" => vec![U256::from(10u64)]
+        },
+        OwnableError::CallerNotOwner,
+    );
+}
+
+#[test]
+fn should_preserve_balances_after_upgrade() {
+    let mut contract = Erc20::deployed(
+        token_cfg::NAME.to_string(),
+        token_cfg::SYMBOL.to_string(),
+        token_cfg::DECIMALS,
+        token_cfg::total_supply(),
+    );
+
+    let amount = U256::from(1000_000_000_000u128);
+    contract.transfer(to_key(contract.bob), amount, Sender(contract.ali));
+    contract.approve(to_key(contract.joe), amount, Sender(contract.ali));
+
+    contract.upgrade();
+
+    assert_eq!(contract.name(), token_cfg::NAME);
+    assert_eq!(contract.total_supply(), token_cfg::total_supply());
+    assert_eq!(contract.balance_of(to_key(contract.bob)), amount);
+    assert_eq!(
+        contract.balance_of(to_key(contract.ali)),
+        token_cfg::total_supply() - amount
+    );
+    assert_eq!(
+        contract.allowance(to_key(contract.ali), to_key(contract.joe)),
+        amount
+    );
+
+    contract.transfer(to_key(contract.joe), amount, Sender(contract.bob));
+    assert_eq!(contract.balance_of(to_key(contract.bob)), U256::zero());
+    assert_eq!(contract.balance_of(to_key(contract.joe)), amount);
+}
+
+#[test]
+fn should_not_call_disabled_version_after_upgrade() {
+    let mut contract = Erc20::deployed(
+        token_cfg::NAME.to_string(),
+        token_cfg::SYMBOL.to_string(),
+        token_cfg::DECIMALS,
+        token_cfg::total_supply(),
+    );
+
+    let previous_hash = contract.hash;
+    contract.upgrade();
+
+    contract.helper.call_expect_exec_error(
+        previous_hash,
         Sender(contract.ali),
+        "transfer",
+        runtime_args! {
+            "to" => to_key(contract.bob),
+            "amount" => U256::from(1000_000_000_000u128)
+        },
+        ExecError::DisabledContract(ContractHash::new(previous_hash)),
     );
 }
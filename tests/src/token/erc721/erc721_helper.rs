@@ -0,0 +1,288 @@
+use crate::utilities::{blake2b256, CasperHelper, Hash, Sender};
+
+use casper_types::{account::AccountHash, runtime_args, ApiError, Key, RuntimeArgs};
+use libs::token::erc721::{
+    erc721::{MetadataMutability, NFTIdentifierMode, NFTMetadataKind, OwnershipMode},
+    ERC721,
+};
+use std::path::PathBuf;
+
+pub mod token_cfg {
+    pub const COLLECTION_NAME: &str = "ERC721";
+    pub const COLLECTION_SYMBOL: &str = "NFT";
+    pub const TOTAL_TOKEN_SUPPLY: u64 = 10;
+    pub const NFT_KIND: u8 = 1;
+}
+
+pub const ERC721_CONTRACT_KEY_NAME: &str = "Erc721";
+
+/// Token identifier given to the entry points, depending on the identifier mode.
+#[derive(Clone, Debug)]
+pub enum TokenIdentifier {
+    Index(u64),
+    Hash(String),
+}
+
+impl TokenIdentifier {
+    /// Mirrors the identifier of a token minted in the `Hash` identifier mode.
+    pub fn hash_of(token_meta_data: &str) -> TokenIdentifier {
+        TokenIdentifier::Hash(hex::encode(blake2b256(token_meta_data.as_bytes())))
+    }
+
+    /// Returns the item key of the token in the CEP-78 dictionaries.
+    pub fn item_key(&self) -> String {
+        match self {
+            TokenIdentifier::Index(token_id) => token_id.to_string(),
+            TokenIdentifier::Hash(token_hash) => token_hash.clone(),
+        }
+    }
+
+    /// Returns the `token_id` or `token_hash` argument identifying the token.
+    pub fn args(&self) -> RuntimeArgs {
+        match self {
+            TokenIdentifier::Index(token_id) => runtime_args! { "token_id" => *token_id },
+            TokenIdentifier::Hash(token_hash) => {
+                runtime_args! { "token_hash" => token_hash.clone() }
+            }
+        }
+    }
+}
+
+/// Mirrors `ERC721::owner_item_key`.
+pub fn owner_item_key(owner: &Key) -> String {
+    match owner {
+        Key::Account(account_hash) => hex::encode(account_hash.value()),
+        Key::Hash(hash) => hex::encode(hash),
+        _ => panic!("not a token owner"),
+    }
+}
+
+pub fn cep78_metadata(name: &str) -> String {
+    format!(
+        r#"{{"name":"{}","token_uri":"https://www.example.com/{}","checksum":"940bffb3f2bba35f84313aa26da09ece3ad47045c6a1292c2bbd2df4ab1a55fb"}}"#,
+        name, name
+    )
+}
+
+pub struct Erc721 {
+    pub helper: CasperHelper,
+    pub hash: Hash,
+    pub ali: AccountHash,
+    pub bob: AccountHash,
+    pub joe: AccountHash,
+}
+
+impl Erc721 {
+    pub fn deployed(
+        identifier_mode: NFTIdentifierMode,
+        ownership_mode: OwnershipMode,
+        nft_metadata_kind: NFTMetadataKind,
+        metadata_mutability: MetadataMutability,
+    ) -> Erc721 {
+        let mut helper = CasperHelper::new();
+
+        // ====================== CONTRACT DEPLOYMENT ======================
+        let session_code = PathBuf::from("erc721.wasm");
+        let session_args = runtime_args! {
+            "collection_name" => token_cfg::COLLECTION_NAME.to_string(),
+            "collection_symbol" => token_cfg::COLLECTION_SYMBOL.to_string(),
+            "total_token_supply" => token_cfg::TOTAL_TOKEN_SUPPLY,
+            "ownership_mode" => ownership_mode as u8,
+            "nft_kind" => token_cfg::NFT_KIND,
+            "nft_metadata_kind" => nft_metadata_kind as u8,
+            "identifier_mode" => identifier_mode as u8,
+            "metadata_mutability" => metadata_mutability as u8,
+        };
+
+        let hash = helper.deploy_contract(
+            session_code,
+            session_args,
+            helper.keys[0].clone(),
+            ERC721_CONTRACT_KEY_NAME.to_string(),
+        );
+
+        // ====================== FUNCTION RETURN ======================
+        Erc721 {
+            helper: helper.clone(),
+            hash,
+            ali: helper.accounts[0].clone(),
+            bob: helper.accounts[1].clone(),
+            joe: helper.accounts[2].clone(),
+        }
+    }
+
+    pub fn collection_name(&self) -> String {
+        self.helper
+            .query_contract(
+                ERC721_CONTRACT_KEY_NAME.to_string(),
+                ERC721::COLLECTION_NAME_KEY,
+            )
+            .unwrap()
+    }
+
+    pub fn identifier_mode(&self) -> u8 {
+        self.helper
+            .query_contract(
+                ERC721_CONTRACT_KEY_NAME.to_string(),
+                ERC721::IDENTIFIER_MODE_KEY,
+            )
+            .unwrap()
+    }
+
+    pub fn number_of_minted_tokens(&self) -> u64 {
+        self.helper
+            .query_contract(
+                ERC721_CONTRACT_KEY_NAME.to_string(),
+                ERC721::NUMBER_OF_MINTED_TOKENS_KEY,
+            )
+            .unwrap()
+    }
+
+    pub fn balance_of(&self, token_owner: Key) -> u64 {
+        self.helper
+            .query_dictionary_value(
+                self.hash,
+                ERC721::BALANCES_KEY,
+                owner_item_key(&token_owner),
+            )
+            .unwrap_or_default()
+    }
+
+    pub fn owner_of(&self, token: &TokenIdentifier) -> Option<Key> {
+        self.helper
+            .query_dictionary_value(self.hash, ERC721::TOKEN_OWNERS_KEY, token.item_key())
+    }
+
+    pub fn is_burnt(&self, token: &TokenIdentifier) -> bool {
+        self.helper
+            .query_dictionary_value(self.hash, ERC721::BURNT_TOKENS_KEY, token.item_key())
+            .unwrap_or_default()
+    }
+
+    pub fn metadata(&self, kind: NFTMetadataKind, token: &TokenIdentifier) -> Option<String> {
+        self.helper
+            .query_dictionary_value(self.hash, kind.dictionary_name(), token.item_key())
+    }
+
+    pub fn mint(&mut self, token_owner: Key, token_meta_data: &str, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "mint",
+            runtime_args! {
+                "token_owner" => token_owner,
+                "token_meta_data" => token_meta_data.to_string()
+            },
+        );
+    }
+
+    pub fn mint_expect_error<E: Into<ApiError>>(
+        &mut self,
+        token_owner: Key,
+        token_meta_data: &str,
+        sender: Sender,
+        expected: E,
+    ) {
+        self.helper.call_expect_error(
+            self.hash,
+            sender,
+            "mint",
+            runtime_args! {
+                "token_owner" => token_owner,
+                "token_meta_data" => token_meta_data.to_string()
+            },
+            expected,
+        );
+    }
+
+    fn token_args(token: &TokenIdentifier, args: RuntimeArgs) -> RuntimeArgs {
+        let mut token_args = token.args();
+        for named_arg in args.named_args() {
+            token_args.insert_cl_value(named_arg.name(), named_arg.cl_value().clone());
+        }
+        token_args
+    }
+
+    /// Calls an entry point taking a token, with the token identifier added to `args`.
+    pub fn call_with_token(
+        &mut self,
+        method: &str,
+        token: &TokenIdentifier,
+        args: RuntimeArgs,
+        sender: Sender,
+    ) {
+        let args = Erc721::token_args(token, args);
+        self.helper.call(self.hash, sender, method, args);
+    }
+
+    /// Calls an entry point taking a token, expecting it to revert with the `expected` error.
+    pub fn call_with_token_expect_error<E: Into<ApiError>>(
+        &mut self,
+        method: &str,
+        token: &TokenIdentifier,
+        args: RuntimeArgs,
+        sender: Sender,
+        expected: E,
+    ) {
+        let args = Erc721::token_args(token, args);
+        self.helper
+            .call_expect_error(self.hash, sender, method, args, expected);
+    }
+
+    pub fn transfer(
+        &mut self,
+        token: &TokenIdentifier,
+        source_key: Key,
+        target_key: Key,
+        sender: Sender,
+    ) {
+        self.call_with_token(
+            "transfer",
+            token,
+            runtime_args! {
+                "source_key" => source_key,
+                "target_key" => target_key
+            },
+            sender,
+        );
+    }
+
+    pub fn approve(&mut self, token: &TokenIdentifier, operator: Key, sender: Sender) {
+        self.call_with_token(
+            "approve",
+            token,
+            runtime_args! { "operator" => operator },
+            sender,
+        );
+    }
+
+    pub fn set_approval_for_all(&mut self, approve_all: bool, operator: Key, sender: Sender) {
+        self.helper.call(
+            self.hash,
+            sender,
+            "set_approval_for_all",
+            runtime_args! {
+                "approve_all" => approve_all,
+                "operator" => operator
+            },
+        );
+    }
+
+    pub fn burn(&mut self, token: &TokenIdentifier, sender: Sender) {
+        self.call_with_token("burn", token, RuntimeArgs::new(), sender);
+    }
+
+    pub fn set_token_metadata(
+        &mut self,
+        token: &TokenIdentifier,
+        token_meta_data: &str,
+        sender: Sender,
+    ) {
+        self.call_with_token(
+            "set_token_metadata",
+            token,
+            runtime_args! { "token_meta_data" => token_meta_data.to_string() },
+            sender,
+        );
+    }
+}
@@ -0,0 +1,347 @@
+use casper_types::{runtime_args, ApiError, RuntimeArgs};
+use libs::token::erc721::{
+    erc721::{MetadataMutability, NFTIdentifierMode, NFTMetadataKind, OwnershipMode},
+    error::Erc721Error,
+    ERC721,
+};
+
+use crate::{
+    introspection::interface_checker_helper::InterfaceChecker,
+    token::erc721::erc721_helper::{cep78_metadata, token_cfg, Erc721, TokenIdentifier},
+    utilities::{to_key, Sender},
+};
+
+// ------------ START - ERC721 Tests ------------
+
+#[test]
+fn should_deploy_with_cep78_named_keys() {
+    let contract = Erc721::deployed(
+        NFTIdentifierMode::Hash,
+        OwnershipMode::Transferable,
+        NFTMetadataKind::Raw,
+        MetadataMutability::Immutable,
+    );
+
+    assert_eq!(contract.collection_name(), token_cfg::COLLECTION_NAME);
+    assert_eq!(contract.identifier_mode(), NFTIdentifierMode::Hash as u8);
+    assert_eq!(contract.number_of_minted_tokens(), 0);
+}
+
+#[test]
+fn should_support_interface_of_identifier_mode() {
+    let mut contract = Erc721::deployed(
+        NFTIdentifierMode::Hash,
+        OwnershipMode::Transferable,
+        NFTMetadataKind::Raw,
+        MetadataMutability::Immutable,
+    );
+    let checker = InterfaceChecker::deploy(&mut contract.helper);
+
+    assert!(checker.supports_interface(
+        &mut contract.helper,
+        contract.hash,
+        ERC721::interface_id(NFTIdentifierMode::Hash)
+    ));
+    assert!(!checker.supports_interface(
+        &mut contract.helper,
+        contract.hash,
+        ERC721::interface_id(NFTIdentifierMode::Ordinal)
+    ));
+}
+
+#[test]
+fn should_mint_transfer_and_burn_by_ordinal() {
+    let mut contract = Erc721::deployed(
+        NFTIdentifierMode::Ordinal,
+        OwnershipMode::Transferable,
+        NFTMetadataKind::CEP78,
+        MetadataMutability::Immutable,
+    );
+    let (ali, bob) = (to_key(contract.ali), to_key(contract.bob));
+
+    contract.mint(ali, &cep78_metadata("first"), Sender(contract.ali));
+    contract.mint(ali, &cep78_metadata("second"), Sender(contract.ali));
+    let (first, second) = (TokenIdentifier::Index(0), TokenIdentifier::Index(1));
+
+    assert_eq!(contract.number_of_minted_tokens(), 2);
+    assert_eq!(contract.owner_of(&first), Some(ali));
+    assert_eq!(contract.owner_of(&second), Some(ali));
+    assert_eq!(contract.balance_of(ali), 2);
+    assert_eq!(
+        contract.metadata(NFTMetadataKind::CEP78, &second),
+        Some(cep78_metadata("second"))
+    );
+
+    contract.transfer(&second, ali, bob, Sender(contract.ali));
+    assert_eq!(contract.owner_of(&second), Some(bob));
+    assert_eq!(contract.balance_of(ali), 1);
+    assert_eq!(contract.balance_of(bob), 1);
+
+    contract.burn(&first, Sender(contract.ali));
+    assert!(contract.is_burnt(&first));
+    assert_eq!(contract.balance_of(ali), 0);
+    contract.call_with_token_expect_error(
+        "transfer",
+        &first,
+        runtime_args! {
+            "source_key" => ali,
+            "target_key" => bob
+        },
+        Sender(contract.ali),
+        Erc721Error::PreviouslyBurntToken,
+    );
+}
+
+#[test]
+fn should_mint_transfer_and_burn_by_hash() {
+    let mut contract = Erc721::deployed(
+        NFTIdentifierMode::Hash,
+        OwnershipMode::Transferable,
+        NFTMetadataKind::CEP78,
+        MetadataMutability::Immutable,
+    );
+    let (ali, bob) = (to_key(contract.ali), to_key(contract.bob));
+    let metadata = cep78_metadata("hashed");
+
+    contract.mint(ali, &metadata, Sender(contract.ali));
+    let token = TokenIdentifier::hash_of(&metadata);
+
+    assert_eq!(contract.owner_of(&token), Some(ali));
+    assert_eq!(
+        contract.metadata(NFTMetadataKind::CEP78, &token),
+        Some(metadata)
+    );
+    assert_eq!(
+        contract.owner_of(&TokenIdentifier::Hash("0".to_string())),
+        None
+    );
+
+    contract.transfer(&token, ali, bob, Sender(contract.ali));
+    assert_eq!(contract.owner_of(&token), Some(bob));
+
+    contract.burn(&token, Sender(contract.bob));
+    assert!(contract.is_burnt(&token));
+    assert_eq!(contract.balance_of(bob), 0);
+}
+
+#[test]
+fn should_not_mint_duplicate_hash() {
+    let mut contract = Erc721::deployed(
+        NFTIdentifierMode::Hash,
+        OwnershipMode::Transferable,
+        NFTMetadataKind::Raw,
+        MetadataMutability::Immutable,
+    );
+    let ali = to_key(contract.ali);
+
+    contract.mint(ali, "unique", Sender(contract.ali));
+    contract.mint_expect_error(
+        ali,
+        "unique",
+        Sender(contract.ali),
+        Erc721Error::DuplicateIdentifier,
+    );
+}
+
+#[test]
+fn should_not_take_token_hash_in_ordinal_mode() {
+    let mut contract = Erc721::deployed(
+        NFTIdentifierMode::Ordinal,
+        OwnershipMode::Transferable,
+        NFTMetadataKind::Raw,
+        MetadataMutability::Immutable,
+    );
+    let ali = to_key(contract.ali);
+    contract.mint(ali, "raw", Sender(contract.ali));
+
+    contract.call_with_token_expect_error(
+        "burn",
+        &TokenIdentifier::hash_of("raw"),
+        RuntimeArgs::new(),
+        Sender(contract.ali),
+        ApiError::MissingArgument,
+    );
+}
+
+#[test]
+fn should_transfer_as_approved_operator() {
+    let mut contract = Erc721::deployed(
+        NFTIdentifierMode::Ordinal,
+        OwnershipMode::Transferable,
+        NFTMetadataKind::Raw,
+        MetadataMutability::Immutable,
+    );
+    let (ali, bob, joe) = (
+        to_key(contract.ali),
+        to_key(contract.bob),
+        to_key(contract.joe),
+    );
+    contract.mint(ali, "first", Sender(contract.ali));
+    contract.mint(ali, "second", Sender(contract.ali));
+    let (first, second) = (TokenIdentifier::Index(0), TokenIdentifier::Index(1));
+
+    contract.approve(&first, bob, Sender(contract.ali));
+    contract.transfer(&first, ali, joe, Sender(contract.bob));
+    assert_eq!(contract.owner_of(&first), Some(joe));
+
+    contract.call_with_token_expect_error(
+        "transfer",
+        &second,
+        runtime_args! {
+            "source_key" => ali,
+            "target_key" => bob
+        },
+        Sender(contract.bob),
+        Erc721Error::InvalidTokenOperator,
+    );
+    contract.set_approval_for_all(true, bob, Sender(contract.ali));
+    contract.transfer(&second, ali, bob, Sender(contract.bob));
+    assert_eq!(contract.owner_of(&second), Some(bob));
+}
+
+#[test]
+fn should_not_transfer_assigned_tokens() {
+    let mut contract = Erc721::deployed(
+        NFTIdentifierMode::Ordinal,
+        OwnershipMode::Assigned,
+        NFTMetadataKind::Raw,
+        MetadataMutability::Immutable,
+    );
+    let (ali, bob) = (to_key(contract.ali), to_key(contract.bob));
+
+    contract.mint(bob, "assigned", Sender(contract.ali));
+    assert_eq!(contract.owner_of(&TokenIdentifier::Index(0)), Some(bob));
+
+    contract.call_with_token_expect_error(
+        "transfer",
+        &TokenIdentifier::Index(0),
+        runtime_args! {
+            "source_key" => bob,
+            "target_key" => ali
+        },
+        Sender(contract.bob),
+        Erc721Error::NonTransferable,
+    );
+}
+
+#[test]
+fn should_only_mint_to_minter_in_minter_mode() {
+    let mut contract = Erc721::deployed(
+        NFTIdentifierMode::Ordinal,
+        OwnershipMode::Minter,
+        NFTMetadataKind::Raw,
+        MetadataMutability::Immutable,
+    );
+    let bob = to_key(contract.bob);
+
+    contract.mint_expect_error(
+        bob,
+        "minted",
+        Sender(contract.ali),
+        Erc721Error::InvalidTokenOwner,
+    );
+}
+
+#[test]
+fn should_only_mint_as_installer() {
+    let mut contract = Erc721::deployed(
+        NFTIdentifierMode::Ordinal,
+        OwnershipMode::Transferable,
+        NFTMetadataKind::Raw,
+        MetadataMutability::Immutable,
+    );
+    let bob = to_key(contract.bob);
+
+    contract.mint_expect_error(
+        bob,
+        "minted",
+        Sender(contract.bob),
+        Erc721Error::InvalidMinter,
+    );
+}
+
+#[test]
+fn should_validate_metadata_kind() {
+    let mut contract = Erc721::deployed(
+        NFTIdentifierMode::Ordinal,
+        OwnershipMode::Transferable,
+        NFTMetadataKind::NFT721,
+        MetadataMutability::Immutable,
+    );
+    let ali = to_key(contract.ali);
+
+    contract.mint_expect_error(
+        ali,
+        &cep78_metadata("not nft721"),
+        Sender(contract.ali),
+        Erc721Error::InvalidMetadata,
+    );
+
+    let metadata = r#"{"name":"token","symbol":"NFT","token_uri":"https://www.example.com"}"#;
+    contract.mint(ali, metadata, Sender(contract.ali));
+    assert_eq!(
+        contract.metadata(NFTMetadataKind::NFT721, &TokenIdentifier::Index(0)),
+        Some(metadata.to_string())
+    );
+}
+
+#[test]
+fn should_update_mutable_metadata() {
+    let mut contract = Erc721::deployed(
+        NFTIdentifierMode::Ordinal,
+        OwnershipMode::Transferable,
+        NFTMetadataKind::Raw,
+        MetadataMutability::Mutable,
+    );
+    let ali = to_key(contract.ali);
+    let token = TokenIdentifier::Index(0);
+
+    contract.mint(ali, "before", Sender(contract.ali));
+    contract.set_token_metadata(&token, "after", Sender(contract.ali));
+
+    assert_eq!(
+        contract.metadata(NFTMetadataKind::Raw, &token),
+        Some("after".to_string())
+    );
+}
+
+#[test]
+fn should_not_update_immutable_metadata() {
+    let mut contract = Erc721::deployed(
+        NFTIdentifierMode::Hash,
+        OwnershipMode::Transferable,
+        NFTMetadataKind::Raw,
+        MetadataMutability::Immutable,
+    );
+    let ali = to_key(contract.ali);
+    contract.mint(ali, "before", Sender(contract.ali));
+
+    contract.call_with_token_expect_error(
+        "set_token_metadata",
+        &TokenIdentifier::hash_of("before"),
+        runtime_args! { "token_meta_data" => "after".to_string() },
+        Sender(contract.ali),
+        Erc721Error::ImmutableMetadata,
+    );
+}
+
+#[test]
+fn should_not_mint_past_total_token_supply() {
+    let mut contract = Erc721::deployed(
+        NFTIdentifierMode::Ordinal,
+        OwnershipMode::Transferable,
+        NFTMetadataKind::Raw,
+        MetadataMutability::Immutable,
+    );
+    let ali = to_key(contract.ali);
+
+    for index in 0..token_cfg::TOTAL_TOKEN_SUPPLY {
+        contract.mint(ali, &index.to_string(), Sender(contract.ali));
+    }
+    contract.mint_expect_error(
+        ali,
+        "one too many",
+        Sender(contract.ali),
+        Erc721Error::TokenSupplyDepleted,
+    );
+}
@@ -0,0 +1,2 @@
+pub mod erc721_helper;
+pub mod erc721_test;
@@ -0,0 +1,127 @@
+use crate::{
+    token::{
+        erc20::erc20_helper::{token_cfg, Erc20},
+        merkle_airdrop::merkle_tree::{airdrop_leaf, Hash as MerkleHash, MerkleTree},
+    },
+    utilities::{make_dictionary_item_key, to_key, Hash, Sender},
+};
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U256};
+use libs::token::merkle_airdrop::MerkleAirdrop as MerkleAirdropLib;
+use std::path::PathBuf;
+
+// contains methods that can simulate a real-world deployment (storing the contract in the blockchain)
+// and transactions to invoke the methods in the contract.
+pub const MERKLE_AIRDROP_CONTRACT_KEY_NAME: &str = "MerkleAirdrop";
+pub const MERKLE_AIRDROP_PACKAGE_HASH_KEY_NAME: &str = "MerkleAirdrop_package_hash";
+
+pub struct MerkleAirdrop {
+    pub token: Erc20,
+    pub hash: Hash,
+    pub package_hash: Hash,
+    pub airdrops: Vec<(Key, U256)>,
+    pub tree: MerkleTree,
+    pub ali: AccountHash,
+    pub bob: AccountHash,
+    pub joe: AccountHash,
+}
+
+impl MerkleAirdrop {
+    /// Deploys an ERC20 token and an airdrop of it to five accounts, funded by `ali`.
+    pub fn deployed() -> MerkleAirdrop {
+        let mut token = Erc20::deployed(
+            token_cfg::NAME.to_string(),
+            token_cfg::SYMBOL.to_string(),
+            token_cfg::DECIMALS,
+            token_cfg::total_supply(),
+        );
+        let (ali, bob, joe) = (token.ali, token.bob, token.joe);
+
+        let airdrops: Vec<(Key, U256)> = vec![
+            (to_key(bob), U256::from(100u64)),
+            (to_key(joe), U256::from(200u64)),
+            (to_key(AccountHash::new([1u8; 32])), U256::from(300u64)),
+            (to_key(AccountHash::new([2u8; 32])), U256::from(400u64)),
+            (to_key(AccountHash::new([3u8; 32])), U256::from(500u64)),
+        ];
+        let tree = MerkleTree::new(
+            airdrops
+                .iter()
+                .enumerate()
+                .map(|(index, (account, amount))| airdrop_leaf(index as u64, *account, *amount))
+                .collect(),
+        );
+
+        // ====================== CONTRACT DEPLOYMENT ======================
+        let session_code = PathBuf::from("merkle_airdrop.wasm");
+        let session_args = runtime_args! {
+            "token" => Key::Hash(token.hash),
+            "merkle_root" => tree.root()
+        };
+
+        let hash = token.helper.deploy_contract(
+            session_code,
+            session_args,
+            token.helper.keys[0].clone(),
+            MERKLE_AIRDROP_CONTRACT_KEY_NAME.to_string(),
+        );
+        let package_hash = token
+            .helper
+            .get_contract_hash(ali, MERKLE_AIRDROP_PACKAGE_HASH_KEY_NAME);
+
+        let total = airdrops
+            .iter()
+            .fold(U256::zero(), |total, (_, amount)| total + *amount);
+        token.transfer(Key::Hash(package_hash), total, Sender(ali));
+
+        // ====================== FUNCTION RETURN ======================
+        MerkleAirdrop {
+            token,
+            hash,
+            package_hash,
+            airdrops,
+            tree,
+            ali,
+            bob,
+            joe,
+        }
+    }
+
+    pub fn is_claimed(&self, index: u64) -> bool {
+        let word: U256 = self
+            .token
+            .helper
+            .query_dictionary_value(
+                self.hash,
+                MerkleAirdropLib::CLAIMED_KEY,
+                make_dictionary_item_key(&(index / 256)),
+            )
+            .unwrap_or_default();
+        !(word & (U256::one() << (index % 256))).is_zero()
+    }
+
+    pub fn claim_args(
+        index: u64,
+        account: Key,
+        amount: U256,
+        proof: Vec<MerkleHash>,
+    ) -> RuntimeArgs {
+        runtime_args! {
+            "index" => index,
+            "account" => account,
+            "amount" => amount,
+            "proof" => proof
+        }
+    }
+
+    /// Claims the airdrop at `index` with its proof.
+    pub fn claim(&mut self, index: usize, sender: Sender) {
+        let (account, amount) = self.airdrops[index];
+        let proof = self.tree.proof(index);
+        self.token.helper.call(
+            self.hash,
+            sender,
+            "claim",
+            MerkleAirdrop::claim_args(index as u64, account, amount, proof),
+        );
+    }
+}
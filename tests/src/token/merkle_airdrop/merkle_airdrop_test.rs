@@ -0,0 +1,140 @@
+use casper_types::{account::AccountHash, Key, U256};
+use libs::{merkle::LEAF_PREFIX, token::merkle_airdrop::error::MerkleAirdropError};
+
+use crate::{
+    token::merkle_airdrop::{
+        merkle_airdrop_helper::MerkleAirdrop,
+        merkle_tree::{airdrop_leaf, hash, hash_pair, MerkleTree},
+    },
+    utilities::{to_key, Sender},
+};
+
+// ------------ START - Merkle Airdrop Tests ------------
+
+#[test]
+fn should_build_verifiable_proofs_for_every_leaf() {
+    for leaf_count in 1..=9u64 {
+        let leaves: Vec<_> = (0..leaf_count)
+            .map(|index| {
+                airdrop_leaf(
+                    index,
+                    to_key(AccountHash::new([9u8; 32])),
+                    U256::from(index),
+                )
+            })
+            .collect();
+        let tree = MerkleTree::new(leaves.clone());
+
+        for (index, leaf) in leaves.iter().enumerate() {
+            assert!(tree.verify(&tree.proof(index), *leaf));
+        }
+        assert!(!tree.verify(&tree.proof(0), [0u8; 32]));
+    }
+}
+
+#[test]
+fn should_not_verify_inner_node_preimage_as_leaf() {
+    let leaves: Vec<_> = (0..4u64)
+        .map(|index| {
+            airdrop_leaf(
+                index,
+                to_key(AccountHash::new([9u8; 32])),
+                U256::from(index),
+            )
+        })
+        .collect();
+    let tree = MerkleTree::new(leaves.clone());
+    let inner_node = hash_pair(&leaves[0], &leaves[1]);
+    let inner_proof = &tree.proof(0)[1..];
+    assert!(tree.verify(inner_proof, inner_node));
+
+    // The 64 bytes hashed into the inner node, passed off as the preimage of a leaf.
+    let (first, second) = if leaves[0] <= leaves[1] {
+        (leaves[0], leaves[1])
+    } else {
+        (leaves[1], leaves[0])
+    };
+    let forged_leaf = hash(&[&[LEAF_PREFIX][..], &first[..], &second[..]].concat());
+
+    assert_ne!(forged_leaf, inner_node);
+    assert!(!tree.verify(inner_proof, forged_leaf));
+}
+
+#[test]
+fn should_claim_airdrop() {
+    let mut contract = MerkleAirdrop::deployed();
+    contract.claim(0, Sender(contract.bob));
+
+    assert_eq!(
+        contract.token.balance_of(to_key(contract.bob)),
+        U256::from(100u64)
+    );
+    assert_eq!(
+        contract
+            .token
+            .balance_of(to_key(contract.package_hash.into())),
+        U256::from(1400u64)
+    );
+    assert!(contract.is_claimed(0));
+    assert!(!contract.is_claimed(1));
+}
+
+#[test]
+fn should_claim_airdrop_on_behalf_of_account() {
+    let mut contract = MerkleAirdrop::deployed();
+    contract.claim(4, Sender(contract.joe));
+
+    let (account, amount) = contract.airdrops[4];
+    assert_eq!(contract.token.balance_of(account), amount);
+    assert_eq!(
+        contract.token.balance_of(to_key(contract.joe)),
+        U256::zero()
+    );
+    assert!(contract.is_claimed(4));
+}
+
+#[test]
+fn should_not_claim_airdrop_twice() {
+    let mut contract = MerkleAirdrop::deployed();
+    contract.claim(1, Sender(contract.joe));
+
+    let (account, amount) = contract.airdrops[1];
+    contract.token.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.joe),
+        "claim",
+        MerkleAirdrop::claim_args(1, account, amount, contract.tree.proof(1)),
+        MerkleAirdropError::AlreadyClaimed,
+    );
+    assert_eq!(contract.token.balance_of(account), amount);
+}
+
+#[test]
+fn should_not_claim_airdrop_with_wrong_amount() {
+    let mut contract = MerkleAirdrop::deployed();
+
+    let (account, amount) = contract.airdrops[0];
+    contract.token.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.bob),
+        "claim",
+        MerkleAirdrop::claim_args(0, account, amount * 2, contract.tree.proof(0)),
+        MerkleAirdropError::InvalidProof,
+    );
+    assert!(!contract.is_claimed(0));
+}
+
+#[test]
+fn should_not_claim_airdrop_with_proof_of_another_leaf() {
+    let mut contract = MerkleAirdrop::deployed();
+
+    let (account, amount) = contract.airdrops[0];
+    contract.token.helper.call_expect_error(
+        contract.hash,
+        Sender(contract.bob),
+        "claim",
+        MerkleAirdrop::claim_args(0, account, amount, contract.tree.proof(1)),
+        MerkleAirdropError::InvalidProof,
+    );
+    assert_eq!(contract.token.balance_of(account), U256::zero());
+}
@@ -0,0 +1,79 @@
+use crate::utilities::blake2b256;
+use casper_types::{bytesrepr::ToBytes, Key, U256};
+use libs::merkle::{LEAF_PREFIX, NODE_PREFIX};
+use std::convert::TryInto;
+
+pub type Hash = [u8; 32];
+
+pub fn hash(bytes: &[u8]) -> Hash {
+    blake2b256(bytes).as_ref().try_into().unwrap()
+}
+
+/// Mirrors `libs::merkle::hash_leaf`.
+pub fn hash_leaf<T: ToBytes>(value: &T) -> Hash {
+    hash(&[&[LEAF_PREFIX][..], &value.to_bytes().unwrap()].concat())
+}
+
+/// Mirrors `libs::merkle::hash_pair`.
+pub fn hash_pair(a: &Hash, b: &Hash) -> Hash {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    hash(&[&[NODE_PREFIX][..], &first[..], &second[..]].concat())
+}
+
+/// Mirrors `libs::token::merkle_airdrop::MerkleAirdrop::leaf`.
+pub fn airdrop_leaf(index: u64, account: Key, amount: U256) -> Hash {
+    hash_leaf(&(index, account, amount))
+}
+
+/// Off-chain Merkle tree matching the proofs verified by `libs::merkle`.
+pub struct MerkleTree {
+    layers: Vec<Vec<Hash>>,
+}
+
+impl MerkleTree {
+    pub fn new(leaves: Vec<Hash>) -> MerkleTree {
+        assert!(!leaves.is_empty(), "a Merkle tree needs at least one leaf");
+
+        let mut layers = vec![leaves];
+        while layers.last().unwrap().len() > 1 {
+            let next_layer = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => hash_pair(left, right),
+                    // a node without sibling is promoted as is.
+                    [node] => *node,
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(next_layer);
+        }
+
+        MerkleTree { layers }
+    }
+
+    pub fn root(&self) -> Hash {
+        self.layers.last().unwrap()[0]
+    }
+
+    /// Returns the sibling hashes from the leaf at `index` up to the root.
+    pub fn proof(&self, mut index: usize) -> Vec<Hash> {
+        let mut proof = Vec::new();
+        for layer in &self.layers[..self.layers.len() - 1] {
+            if let Some(sibling) = layer.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            index /= 2;
+        }
+        proof
+    }
+
+    /// Returns whether `proof` proves that `leaf` belongs to this tree.
+    pub fn verify(&self, proof: &[Hash], leaf: Hash) -> bool {
+        proof
+            .iter()
+            .fold(leaf, |node, sibling| hash_pair(&node, sibling))
+            == self.root()
+    }
+}
@@ -0,0 +1,3 @@
+pub mod merkle_airdrop_helper;
+pub mod merkle_airdrop_test;
+pub mod merkle_tree;
@@ -1 +1,5 @@
+#[cfg(feature = "cep18")]
+pub mod cep18;
 pub mod erc20;
+pub mod erc721;
+pub mod merkle_airdrop;
@@ -10,16 +10,21 @@ use casper_engine_test_support::{
     DEFAULT_PROTOCOL_VERSION, DEFAULT_ROUND_SEIGNIORAGE_RATE, DEFAULT_SYSTEM_CONFIG,
     DEFAULT_UNBONDING_DELAY, DEFAULT_VALIDATOR_SLOTS, DEFAULT_WASM_CONFIG,
 };
-use casper_execution_engine::core::engine_state::{
-    genesis::{ExecConfig, GenesisAccount},
-    run_genesis_request::RunGenesisRequest,
+use casper_execution_engine::core::{
+    engine_state::{
+        genesis::{ExecConfig, GenesisAccount},
+        run_genesis_request::RunGenesisRequest,
+        Error as EngineStateError, ExecuteRequest,
+    },
+    execution::Error as ExecError,
 };
 use casper_types::{
     account::AccountHash,
     bytesrepr::{FromBytes, ToBytes},
-    runtime_args, CLTyped, ContractHash, Key, Motes, PublicKey, RuntimeArgs, SecretKey,
-    StoredValue,
+    runtime_args, ApiError, CLType, CLTyped, ContractHash, Key, Motes, PublicKey, RuntimeArgs,
+    SecretKey, StoredValue, URef, U512,
 };
+use libs::error::error_name;
 use rand::Rng;
 use std::path::PathBuf;
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -38,17 +43,20 @@ pub fn blake2b256(item_key_string: &[u8]) -> Box<[u8]> {
     hasher.finalize_boxed()
 }
 
-pub fn key_to_str(key: &Key) -> String {
-    let preimage = key.to_bytes().unwrap();
-    base64::encode(&preimage)
+/// Mirrors `libs::utils::make_dictionary_item_key`.
+pub fn make_dictionary_item_key<T: ToBytes>(value: &T) -> String {
+    let preimage = value.to_bytes().unwrap();
+    hex::encode(blake2b256(&preimage))
 }
 
-pub fn two_key_to_str(key1: Key, key2: Key) -> String {
-    let mut preimage = Vec::new();
-    preimage.append(&mut key1.to_bytes().unwrap());
-    preimage.append(&mut key2.to_bytes().unwrap());
-    let key_bytes = blake2b256(&preimage);
-    hex::encode(&key_bytes)
+/// Mirrors `libs::introspection::interface_id`, for entry points given as their name and the
+/// types of their arguments.
+pub fn interface_id(signatures: &[(&str, Vec<CLType>)]) -> u32 {
+    signatures.iter().fold(0, |id, (name, arg_types)| {
+        let signature = (name.to_string(), arg_types.clone()).to_bytes().unwrap();
+        let hash = blake2b256(&signature);
+        id ^ u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]])
+    })
 }
 
 pub fn to_key(account: AccountHash) -> Key {
@@ -163,6 +171,83 @@ impl CasperHelper {
         deployer: PublicKey,
         contract_hash_key: String,
     ) -> Hash {
+        // pre-assertion before the contract deployment.
+        let contract_hash = self.builder.query(
+            None,
+            Key::Account(deployer.to_account_hash()),
+            &[contract_hash_key.clone()],
+        );
+
+        assert!(contract_hash.is_err());
+
+        // deploy the contract.
+        self.run_session_code(session_code, session_args, deployer.to_account_hash());
+
+        // retrieving hashes & post-assertions after the contract deployment.
+        let contract_hash = self.get_contract_hash(deployer.to_account_hash(), &contract_hash_key);
+
+        assert_ne!(contract_hash, [0u8; 32]);
+
+        contract_hash
+    }
+
+    /// Deploys a new version of an already deployed contract and returns the new `contract_hash`.
+    pub fn upgrade_contract(
+        &mut self,
+        session_code: PathBuf,
+        session_args: RuntimeArgs,
+        deployer: PublicKey,
+        contract_hash_key: String,
+    ) -> Hash {
+        // pre-assertion before the contract upgrade.
+        let previous_contract_hash =
+            self.get_contract_hash(deployer.to_account_hash(), &contract_hash_key);
+
+        // upgrade the contract.
+        self.run_session_code(session_code, session_args, deployer.to_account_hash());
+
+        // retrieving hashes & post-assertions after the contract upgrade.
+        let contract_hash = self.get_contract_hash(deployer.to_account_hash(), &contract_hash_key);
+
+        assert_ne!(contract_hash, previous_contract_hash);
+
+        contract_hash
+    }
+
+    /// Runs the session code installing a contract, expecting it to revert with the `expected`
+    /// error.
+    pub fn deploy_contract_expect_error<E: Into<ApiError>>(
+        &mut self,
+        session_code: PathBuf,
+        session_args: RuntimeArgs,
+        deployer: PublicKey,
+        expected: E,
+    ) {
+        let execute_request =
+            CasperHelper::session_request(session_code, session_args, deployer.to_account_hash());
+
+        // executes the execute_request.
+        self.builder.exec(execute_request).commit();
+        self.assert_reverted_with(expected);
+    }
+
+    /// Executes the given session code on behalf of the `deployer`.
+    fn run_session_code(
+        &mut self,
+        session_code: PathBuf,
+        session_args: RuntimeArgs,
+        deployer: AccountHash,
+    ) {
+        let execute_request = CasperHelper::session_request(session_code, session_args, deployer);
+
+        self.builder.exec(execute_request).commit().expect_success();
+    }
+
+    fn session_request(
+        session_code: PathBuf,
+        session_args: RuntimeArgs,
+        deployer: AccountHash,
+    ) -> ExecuteRequest {
         let mut rng = rand::thread_rng();
 
         let deploy_item = DeployItemBuilder::new()
@@ -172,42 +257,67 @@ impl CasperHelper {
             })
             .with_session_code(session_code, session_args)
             .with_deploy_hash(rng.gen())
-            .with_authorization_keys(&[deployer.to_account_hash()])
-            .with_address(deployer.to_account_hash())
+            .with_authorization_keys(&[deployer])
+            .with_address(deployer)
             .build();
 
         // prepare the execute request.
-        let execute_request = ExecuteRequestBuilder::from_deploy_item(deploy_item)
+        ExecuteRequestBuilder::from_deploy_item(deploy_item)
             .with_block_time(get_current_time())
-            .build();
-
-        // pre-assertion before the contract deployment.
-        let contract_hash = self.builder.query(
-            None,
-            Key::Account(deployer.to_account_hash()),
-            &[contract_hash_key.clone()],
-        );
-
-        assert!(contract_hash.is_err());
-
-        // deploy the contract.
-        self.builder.exec(execute_request).commit().expect_success();
+            .build()
+    }
 
-        // retrieving hashes & post-assertions after the contract deployment.
-        let contract_hash = self
-            .builder
-            .get_account(deployer.to_account_hash())
+    /// Returns the contract hash stored under the `contract_hash_key` named key of the `account`.
+    pub fn get_contract_hash(&self, account: AccountHash, contract_hash_key: &str) -> Hash {
+        self.builder
+            .get_account(account)
             .expect("should have account")
             .named_keys()
-            .get(&contract_hash_key)
+            .get(contract_hash_key)
             .and_then(|key| key.into_hash())
             .map(ContractHash::new)
             .expect("should have contract hash")
-            .value();
+            .value()
+    }
 
-        assert_ne!(contract_hash, [0u8; 32]);
+    /// Returns the key stored under the `name` named key of the contract `hash`.
+    pub fn get_contract_named_key(&self, hash: Hash, name: &str) -> Key {
+        *self
+            .builder
+            .get_contract(ContractHash::new(hash))
+            .expect("should have contract")
+            .named_keys()
+            .get(name)
+            .expect("should have named key")
+    }
 
-        contract_hash
+    /// Returns the CSPR balance of the main purse of the `account`, in motes.
+    pub fn get_balance(&self, account: AccountHash) -> U512 {
+        let main_purse = self
+            .builder
+            .get_account(account)
+            .expect("should have account")
+            .main_purse();
+        self.builder.get_purse_balance(main_purse)
+    }
+
+    /// Transfers `amount` motes from the main purse of the `sender` to the `target` purse.
+    pub fn transfer_to_purse(&mut self, sender: Sender, target: URef, amount: U512) {
+        let Sender(address) = sender;
+        let transfer_request = ExecuteRequestBuilder::transfer(
+            address,
+            runtime_args! {
+                "target" => target,
+                "amount" => amount,
+                "id" => Some(0u64)
+            },
+        )
+        .build();
+
+        self.builder
+            .exec(transfer_request)
+            .commit()
+            .expect_success();
     }
 
     /// query a contract's named key.
@@ -279,8 +389,14 @@ impl CasperHelper {
         }
     }
 
-    /// call a contract's specific entry point.
-    pub fn call(&mut self, hash: Hash, sender: Sender, method: &str, args: RuntimeArgs) {
+    /// prepares the execute request calling a contract's specific entry point.
+    fn call_request(
+        hash: Hash,
+        sender: Sender,
+        method: &str,
+        args: RuntimeArgs,
+        block_time: Option<u64>,
+    ) -> ExecuteRequest {
         let Sender(address) = sender;
 
         // prepare the deploy item.
@@ -295,10 +411,120 @@ impl CasperHelper {
             .build();
 
         // prepare the execute request.
-        // we can use .with_block_time() when setting the execute request.
-        let execute_request = ExecuteRequestBuilder::from_deploy_item(deploy_item).build();
+        let execute_request = ExecuteRequestBuilder::from_deploy_item(deploy_item);
+        match block_time {
+            Some(block_time) => execute_request.with_block_time(block_time),
+            None => execute_request,
+        }
+        .build()
+    }
+
+    /// call a contract's specific entry point.
+    pub fn call(&mut self, hash: Hash, sender: Sender, method: &str, args: RuntimeArgs) {
+        let execute_request = CasperHelper::call_request(hash, sender, method, args, None);
+
+        // executes the execute_request.
+        self.builder.exec(execute_request).commit().expect_success();
+    }
+
+    /// call a contract's specific entry point in a block with the given `block_time`.
+    pub fn call_at(
+        &mut self,
+        hash: Hash,
+        sender: Sender,
+        method: &str,
+        args: RuntimeArgs,
+        block_time: u64,
+    ) {
+        let execute_request =
+            CasperHelper::call_request(hash, sender, method, args, Some(block_time));
 
         // executes the execute_request.
         self.builder.exec(execute_request).commit().expect_success();
     }
+
+    /// call a contract's specific entry point, expecting it to revert with the `expected` error.
+    pub fn call_expect_error<E: Into<ApiError>>(
+        &mut self,
+        hash: Hash,
+        sender: Sender,
+        method: &str,
+        args: RuntimeArgs,
+        expected: E,
+    ) {
+        self.expect_error(hash, sender, method, args, None, expected);
+    }
+
+    /// call a contract's specific entry point in a block with the given `block_time`, expecting
+    /// it to revert with the `expected` error.
+    pub fn call_expect_error_at<E: Into<ApiError>>(
+        &mut self,
+        hash: Hash,
+        sender: Sender,
+        method: &str,
+        args: RuntimeArgs,
+        block_time: u64,
+        expected: E,
+    ) {
+        self.expect_error(hash, sender, method, args, Some(block_time), expected);
+    }
+
+    /// call a contract's specific entry point, expecting the execution engine to reject it with
+    /// the `expected` error instead of running it.
+    pub fn call_expect_exec_error(
+        &mut self,
+        hash: Hash,
+        sender: Sender,
+        method: &str,
+        args: RuntimeArgs,
+        expected: ExecError,
+    ) {
+        let execute_request = CasperHelper::call_request(hash, sender, method, args, None);
+
+        // executes the execute_request.
+        self.builder.exec(execute_request).commit();
+        match self.builder.get_error() {
+            Some(EngineStateError::Exec(error)) => assert_eq!(
+                format!("{:?}", error),
+                format!("{:?}", expected),
+                "expected {} but got {}",
+                expected,
+                error,
+            ),
+            Some(error) => panic!("expected {:?} but got {:?}", expected, error),
+            None => panic!("expected {:?} but the call succeeded", expected),
+        }
+    }
+
+    fn expect_error<E: Into<ApiError>>(
+        &mut self,
+        hash: Hash,
+        sender: Sender,
+        method: &str,
+        args: RuntimeArgs,
+        block_time: Option<u64>,
+        expected: E,
+    ) {
+        let execute_request = CasperHelper::call_request(hash, sender, method, args, block_time);
+
+        // executes the execute_request.
+        self.builder.exec(execute_request).commit();
+        self.assert_reverted_with(expected);
+    }
+
+    /// Asserts that the last execution reverted with the `expected` error.
+    fn assert_reverted_with<E: Into<ApiError>>(&self, expected: E) {
+        let expected: ApiError = expected.into();
+        match self.builder.get_error() {
+            Some(EngineStateError::Exec(ExecError::Revert(error))) => assert_eq!(
+                error,
+                expected,
+                "expected {} but got {}",
+                error_name(expected).unwrap_or("an unknown error"),
+                error_name(error).unwrap_or("an unknown error"),
+            ),
+            Some(error) => panic!("expected {:?} but got {:?}", expected, error),
+            None => panic!("expected {:?} but the call succeeded", expected),
+        }
+    }
 }